@@ -22,11 +22,12 @@ macro_rules! impl_scalar_binary_traits (
 			}
 
 			impl<'a, T, S, R> $Trait<R> for &'a Container<T, S>
-				where T: Element, R: Element, S: Storage<T>, T: $Trait<T, Output=T> + From<R>
+				where T: Element, R: Element, S: Storage<T>, T: $Trait<T, Output=T> + From<R>,
+				      S::Rows: OwnedDims<S::Cols>
 			{
-				type Output = ContainerRM<T, S::Rows, S::Cols>;
+				type Output = ContainerOwnedRM<T, S::Rows, S::Cols>;
 
-				fn $trait_fn(self, rhs: R) -> Self::Output { self.into_slice().$op_fn(rhs).apply().into() }
+				fn $trait_fn(self, rhs: R) -> Self::Output { self.into_slice().clone_owned().$op_fn(rhs).apply() }
 			}
 
 			$(
@@ -71,11 +72,14 @@ macro_rules! impl_storage_binary_traits (
 			impl<'a, T, S, R> $Trait<Container<T, R>> for &'a Container<T, S>
 				where T: Element,
 				      S: Storage<T>, R: Storage<T>,
-				      T: $Trait<T, Output=T>
+				      T: $Trait<T, Output=T>,
+				      S::Rows: OwnedDims<S::Cols>
 			{
-				type Output = ContainerRM<T, S::Rows, S::Cols>;
+				type Output = ContainerOwnedRM<T, S::Rows, S::Cols>;
 
-				fn $trait_fn(self, rhs: Container<T, R>) -> Self::Output { self.into_slice().$op_fn(rhs).apply().into() }
+				fn $trait_fn(self, rhs: Container<T, R>) -> Self::Output {
+					self.into_slice().clone_owned().$op_fn(rhs).apply()
+				}
 			}
 
 			$(
@@ -111,11 +115,11 @@ macro_rules! impl_unary_traits (
 			}
 
 			impl<'a, T, S> $Trait for &'a Container<T, S>
-				where T: Element, S: Storage<T>, T: $Trait<Output=T>
+				where T: Element, S: Storage<T>, T: $Trait<Output=T>, S::Rows: OwnedDims<S::Cols>
 			{
-				type Output = ContainerRM<T, S::Rows, S::Cols>;
+				type Output = ContainerOwnedRM<T, S::Rows, S::Cols>;
 
-				fn $trait_fn(self) -> Self::Output { self.into_slice().$op_fn().apply().into() }
+				fn $trait_fn(self) -> Self::Output { self.into_slice().clone_owned().$op_fn().apply() }
 			}
 		)*
 	}
@@ -150,11 +154,11 @@ impl<T, S, R> Clamp<R> for Container<T, S>
 }
 
 impl<'a, T, S, R> Clamp<R> for &'a Container<T, S>
-	where T: Element, R: Element, S: Storage<T>, T: Clamp<T, Output=T> + From<R>
+	where T: Element, R: Element, S: Storage<T>, T: Clamp<T, Output=T> + From<R>, S::Rows: OwnedDims<S::Cols>
 {
-	type Output = ContainerRM<T, S::Rows, S::Cols>;
+	type Output = ContainerOwnedRM<T, S::Rows, S::Cols>;
 
-	fn clamp(self, min: R, max: R) -> Self::Output { self.into_slice().clamp_op(min, max).apply() }
+	fn clamp(self, min: R, max: R) -> Self::Output { self.into_slice().clone_owned().clamp_op(min, max).apply() }
 }
 
 impl<'a, T, S> Norm for &'a Container<Complex<T>, S>
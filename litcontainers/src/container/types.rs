@@ -1,4 +1,4 @@
-use crate::{Container, VecStorageCM, VecStorageRM, U1};
+use crate::{Container, VecStorageCM, VecStorageRM, ArrayStorageCM, ArrayStorageRM, OwnedDims, U1};
 
 /// Container storing scalar values in a col major order
 pub type ContainerCM<T, R, C> = Container<T, VecStorageCM<T, R, C>>;
@@ -7,4 +7,10 @@ pub type ColVec<T, R> = ContainerCM<T, R, U1>;
 /// Container storing scalar values in a row major order
 pub type ContainerRM<T, R, C> = Container<T, VecStorageRM<T, R, C>>;
 /// Container storing scalar values as a row vector
-pub type RowVec<T, C> = ContainerRM<T, U1, C>;
\ No newline at end of file
+pub type RowVec<T, C> = ContainerRM<T, U1, C>;
+/// Container storing scalar values of a static size inline in a col major order
+pub type ArrayContainerCM<T, R, C> = Container<T, ArrayStorageCM<T, R, C>>;
+/// Container storing scalar values of a static size inline in a row major order
+pub type ArrayContainerRM<T, R, C> = Container<T, ArrayStorageRM<T, R, C>>;
+/// Container owning scalar values in a row major order. Values are stored inline for small static sizes
+pub type ContainerOwnedRM<T, R, C> = Container<T, <R as OwnedDims<C>>::OwnedRM<T>>;
\ No newline at end of file
@@ -1,9 +1,12 @@
 use crate::format::*;
 use crate::storage::*;
 use crate::ops::*;
+use crate::slice::SliceRange;
 use std::marker::PhantomData;
 use std::fmt;
 use std::ops::{Index, IndexMut};
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // Container storing scalar values. Wraps around given storage.
 #[derive(Debug, Storage, StorageSize, Strided, Ownable, new)]
@@ -20,8 +23,21 @@ impl<T, S> Container<T, S> where T: Element, S: Storage<T> {
 	pub fn storage(&self) -> &S { &self.storage}
 
 	pub fn storage_mut(&mut self) -> &mut S { &mut self.storage}
+
+	/// Displays the container with given print options instead of the default ones.
+	pub fn display_with(&self, options: PrintOptions) -> DisplayWith<'_, T, Self> { DisplayWith::new(self, options) }
 }
 
+impl<T, S> Clone for Container<T, S>
+	where T: Element, S: Storage<T> + Clone
+{
+	fn clone(&self) -> Self { Container::new(self.storage.clone()) }
+}
+
+impl<T, S> Copy for Container<T, S>
+	where T: Element, S: Storage<T> + Copy
+{}
+
 impl<T, S> StorageMut<T> for Container<T, S>
 	where T: Element, S: StorageMut<T>
 {
@@ -40,12 +56,56 @@ impl<T, S> DynamicRowStorage<T> for Container<T, S>
 	fn set_rows(&mut self, count: usize) {
 		self.storage.set_rows(count)
 	}
+
+	fn insert_rows<SI: Storage<T>>(&mut self, index: usize, rows: &SI) { self.storage.insert_rows(index, rows) }
+
+	fn remove_rows<RR: SliceRange>(&mut self, range: RR) { self.storage.remove_rows(range) }
 }
 
 impl<T, S> DynamicColStorage<T> for Container<T, S>
 	where T: Element, S: StorageMut<T> + DynamicColStorage<T>
 {
 	fn set_cols(&mut self, count: usize) { self.storage.set_cols(count) }
+
+	fn insert_cols<SI: Storage<T>>(&mut self, index: usize, cols: &SI) { self.storage.insert_cols(index, cols) }
+
+	fn remove_cols<CR: SliceRange>(&mut self, range: CR) { self.storage.remove_cols(range) }
+}
+
+impl<T, R, C> Container<T, VecStorageRM<T, R, C>>
+	where T: Element, R: Dim, C: Dim
+{
+	pub fn capacity(&self) -> usize { self.storage.capacity() }
+
+	pub fn shrink_to_fit(&mut self) { self.storage.shrink_to_fit() }
+}
+
+impl<T, C> Container<T, VecStorageRM<T, Dynamic, C>>
+	where T: Element, C: Dim
+{
+	/// Keeps the first `count` rows.
+	pub fn truncate(&mut self, count: usize) { self.storage.truncate(count) }
+
+	/// Reserves capacity for at least `additional` more rows.
+	pub fn reserve(&mut self, additional: usize) { self.storage.reserve(additional) }
+}
+
+impl<T, R, C> Container<T, VecStorageCM<T, R, C>>
+	where T: Element, R: Dim, C: Dim
+{
+	pub fn capacity(&self) -> usize { self.storage.capacity() }
+
+	pub fn shrink_to_fit(&mut self) { self.storage.shrink_to_fit() }
+}
+
+impl<T, R> Container<T, VecStorageCM<T, R, Dynamic>>
+	where T: Element, R: Dim
+{
+	/// Keeps the first `count` columns.
+	pub fn truncate(&mut self, count: usize) { self.storage.truncate(count) }
+
+	/// Reserves capacity for at least `additional` more columns.
+	pub fn reserve(&mut self, additional: usize) { self.storage.reserve(additional) }
 }
 
 impl<T, S> fmt::Display for Container<T, S>
@@ -56,6 +116,16 @@ impl<T, S> fmt::Display for Container<T, S>
 	}
 }
 
+impl<T, S, SO> PartialEq<Container<T, SO>> for Container<T, S>
+	where T: Element + PartialEq, S: Storage<T>, SO: Storage<T>
+{
+	fn eq(&self, other: &Container<T, SO>) -> bool { self.equals(other) }
+}
+
+impl<T, S> Eq for Container<T, S>
+	where T: Element + Eq, S: Storage<T>
+{}
+
 impl<T: Element, S: Storage<T>> From<S> for Container<T, S> {
 	fn from(s: S) -> Self { Container::new(s) }
 }
@@ -92,4 +162,23 @@ impl<T, S> IndexMut<usize> for Container<T, S>
 	where T: Element, S: StorageMut<T> + IndexMut<usize>
 {
 	fn index_mut(&mut self, index: usize) -> &mut Self::Output { self.storage_mut().index_mut(index) }
+}
+
+/// Serializes the container as owned data. Slices are serialized with their elements, not as a view.
+#[cfg(feature = "serde-serialize")]
+impl<T, S> Serialize for Container<T, S>
+	where T: Element + Serialize, S: Storage<T>
+{
+	fn serialize<SE: Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> {
+		serialize_storage(self, serializer)
+	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, T, S> Deserialize<'de> for Container<T, S>
+	where T: Element + Deserialize<'de>, S: Storage<T> + StorageConstructor<T>
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_storage(deserializer)
+	}
 }
\ No newline at end of file
@@ -1,10 +1,21 @@
 use crate::{Dim};
+use std::fmt;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AxisType {
 	Row,
 	Col,
 }
 
+impl fmt::Display for AxisType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			AxisType::Row => write!(f, "row"),
+			AxisType::Col => write!(f, "col"),
+		}
+	}
+}
+
 pub trait AxisSelector<R, C> {
 	type Result;
 
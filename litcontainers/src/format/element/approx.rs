@@ -0,0 +1,89 @@
+use super::Element;
+use num_complex::Complex;
+
+/// Comparison of elements with a tolerance. Implemented for floats and complex numbers. Complex numbers are compared
+/// by their real and imaginary parts separately.
+pub trait ApproxEq: Element {
+	type Epsilon: Copy;
+
+	fn default_epsilon() -> Self::Epsilon;
+
+	fn default_max_relative() -> Self::Epsilon;
+
+	fn default_max_ulps() -> u32 { 4 }
+
+	/// Elements are equal if their absolute difference is at most `epsilon`.
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+	/// Elements are equal if their absolute difference is at most `epsilon` or at most `max_relative` times the
+	/// larger of their magnitudes.
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+
+	/// Elements are equal if their absolute difference is at most `epsilon` or if there are at most `max_ulps`
+	/// representable values between them.
+	fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+	/// Relative comparison with default tolerances.
+	fn approx_eq(&self, other: &Self) -> bool {
+		self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+	}
+}
+
+macro_rules! impl_approx_float (
+	($($Type: ident => $Bits: ident),* $(,)*) => {$(
+		impl ApproxEq for $Type {
+			type Epsilon = $Type;
+
+			fn default_epsilon() -> Self::Epsilon { $Type::EPSILON }
+
+			fn default_max_relative() -> Self::Epsilon { $Type::EPSILON }
+
+			fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+				self == other || (self - other).abs() <= epsilon
+			}
+
+			fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+				if self == other { return true; }
+				if self.is_infinite() || other.is_infinite() { return false; }
+
+				let diff = (self - other).abs();
+				if diff <= epsilon { return true; }
+				diff <= self.abs().max(other.abs()) * max_relative
+			}
+
+			fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+				if self.abs_diff_eq(other, epsilon) { return true; }
+				if self.is_sign_positive() != other.is_sign_positive() || self.is_nan() || other.is_nan() { return false; }
+
+				let (a, b) = (self.to_bits() as $Bits, other.to_bits() as $Bits);
+				(a - b).abs() <= max_ulps as $Bits
+			}
+		}
+	)*}
+);
+
+impl_approx_float!(f32 => i32, f64 => i64);
+
+impl<T> ApproxEq for Complex<T>
+	where T: ApproxEq, Complex<T>: Element
+{
+	type Epsilon = T::Epsilon;
+
+	fn default_epsilon() -> Self::Epsilon { T::default_epsilon() }
+
+	fn default_max_relative() -> Self::Epsilon { T::default_max_relative() }
+
+	fn default_max_ulps() -> u32 { T::default_max_ulps() }
+
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.re.abs_diff_eq(&other.re, epsilon) && self.im.abs_diff_eq(&other.im, epsilon)
+	}
+
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		self.re.relative_eq(&other.re, epsilon, max_relative) && self.im.relative_eq(&other.im, epsilon, max_relative)
+	}
+
+	fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+		self.re.ulps_eq(&other.re, epsilon, max_ulps) && self.im.ulps_eq(&other.im, epsilon, max_ulps)
+	}
+}
@@ -12,6 +12,16 @@ pub enum ElementType {
 	Complex(ScalarType),
 }
 
+/// Writes the value in scientific notation with a signed exponent of at least two digits, so that values of
+/// different magnitudes have equal width.
+fn fmt_sci(f: &mut fmt::Formatter, precision: usize, v: f64) -> Result<(), fmt::Error> {
+	let s = format!("{:+.*e}", precision, v);
+	match s.find('e').and_then(|i| s[i + 1..].parse::<i32>().ok().map(|e| (i, e))) {
+		Some((i, e)) => write!(f, "{}e{:+03}", &s[..i], e),
+		None => write!(f, "{}", s),
+	}
+}
+
 pub trait Element: Copy + Clone + Debug + Sized + Default + Send + Sync
 {
 	fn element_type() -> ElementType;
@@ -72,7 +82,7 @@ impl Element for u32 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{}", *self)
 		}
@@ -86,7 +96,7 @@ impl Element for i32 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{}", *self)
 		}
@@ -100,7 +110,7 @@ impl Element for u64 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{}", *self)
 		}
@@ -114,7 +124,7 @@ impl Element for i64 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{}", *self)
 		}
@@ -128,7 +138,7 @@ impl Element for u128 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{}", *self)
 		}
@@ -142,7 +152,7 @@ impl Element for i128 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{}", *self)
 		}
@@ -156,7 +166,7 @@ impl Element for f32 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{:.*}", precision, *self as f64)
 		}
@@ -170,7 +180,7 @@ impl Element for f64 {
 
 	fn fmt_elem(&self, f: &mut fmt::Formatter, precision: usize, sci: bool) -> Result<(), fmt::Error> {
 		if sci {
-			write!(f, "{:+.*e}", precision, *self as f64)
+			fmt_sci(f, precision, *self as f64)
 		} else {
 			write!(f, "{:.*}", precision, *self as f64)
 		}
@@ -1,7 +1,9 @@
 pub mod element;
 pub mod numeric;
 pub mod scalar;
+pub mod approx;
 
 pub use element::*;
 pub use numeric::*;
-pub use scalar::*;
\ No newline at end of file
+pub use scalar::*;
+pub use approx::*;
\ No newline at end of file
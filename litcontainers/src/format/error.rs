@@ -0,0 +1,81 @@
+use crate::format::*;
+use std::error;
+use std::fmt;
+
+pub type ShapeResult<T> = Result<T, ShapeError>;
+
+/// Error returned by the fallible `try_*` operations when indices, ranges or sizes don't fit the storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+	/// Index is outside of the axis.
+	IndexOutOfBounds { axis: AxisType, index: usize, len: usize },
+	/// Range ends past the end of the axis.
+	RangeOutOfBounds { axis: AxisType, begin: usize, end: usize, len: usize },
+	/// Storage sizes given as (rows, cols) don't match.
+	SizeMismatch { expected: (usize, usize), actual: (usize, usize) },
+	/// Number of provided elements doesn't match the size.
+	LengthMismatch { expected: usize, actual: usize },
+	/// Size and strides address elements past the end of the storage.
+	TransmuteOutOfBounds { required: usize, len: usize },
+	/// Strides of a mutable view address the same element from different positions.
+	OverlappingStrides { row_stride: usize, col_stride: usize },
+	/// Axis has fewer elements than the requested number of chunks.
+	ChunkCount { axis: AxisType, chunks: usize, len: usize },
+	/// Joined storages differ in the number of elements along the shared axis.
+	JoinMismatch { axis: AxisType, expected: usize, actual: usize },
+	/// Cannot join an empty list of storages.
+	EmptyJoin,
+}
+
+impl ShapeError {
+	/// Creates a `SizeMismatch` error from two sizes.
+	pub fn size_mismatch<E: StorageSize, A: StorageSize>(expected: &E, actual: &A) -> Self {
+		ShapeError::SizeMismatch { expected: (expected.rows(), expected.cols()), actual: (actual.rows(), actual.cols()) }
+	}
+
+	pub(crate) fn check_index(axis: AxisType, index: usize, len: usize) -> ShapeResult<()> {
+		if index < len { Ok(()) } else { Err(ShapeError::IndexOutOfBounds { axis, index, len }) }
+	}
+
+	pub(crate) fn check_range(axis: AxisType, begin: usize, end: usize, len: usize) -> ShapeResult<()> {
+		if begin <= end && end <= len { Ok(()) } else { Err(ShapeError::RangeOutOfBounds { axis, begin, end, len }) }
+	}
+
+	pub(crate) fn check_join(axis: AxisType, expected: usize, actual: usize) -> ShapeResult<()> {
+		if expected == actual { Ok(()) } else { Err(ShapeError::JoinMismatch { axis, expected, actual }) }
+	}
+
+	pub(crate) fn check_size<E: StorageSize, A: StorageSize>(expected: &E, actual: &A) -> ShapeResult<()> {
+		if expected.rows() == actual.rows() && expected.cols() == actual.cols() {
+			Ok(())
+		} else {
+			Err(Self::size_mismatch(expected, actual))
+		}
+	}
+}
+
+impl fmt::Display for ShapeError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ShapeError::IndexOutOfBounds { axis, index, len } =>
+				write!(f, "Out of range {}! Index {} is out of bounds for length {}", axis, index, len),
+			ShapeError::RangeOutOfBounds { axis, begin, end, len } =>
+				write!(f, "Range is out of bounds! {} range {}..{} is out of bounds for length {}", axis, begin, end, len),
+			ShapeError::SizeMismatch { expected, actual } =>
+				write!(f, "Size mismatch! Expected {}x{}, found {}x{}", expected.0, expected.1, actual.0, actual.1),
+			ShapeError::LengthMismatch { expected, actual } =>
+				write!(f, "Length mismatch! Expected {} elements, found {}", expected, actual),
+			ShapeError::TransmuteOutOfBounds { required, len } =>
+				write!(f, "Transmute is out of bounds! Requires {} elements, storage has {}", required, len),
+			ShapeError::OverlappingStrides { row_stride, col_stride } =>
+				write!(f, "Strides overlap! Row stride {} and col stride {} address elements more than once", row_stride, col_stride),
+			ShapeError::ChunkCount { axis, chunks, len } =>
+				write!(f, "Storage dimensions must be larger than the chunk count. Cannot split {} {}s into {} chunks", len, axis, chunks),
+			ShapeError::JoinMismatch { axis, expected, actual } =>
+				write!(f, "Joined storages must have equal number of {}s! Expected {}, found {}", axis, expected, actual),
+			ShapeError::EmptyJoin => write!(f, "Cannot join an empty list of storages!"),
+		}
+	}
+}
+
+impl error::Error for ShapeError {}
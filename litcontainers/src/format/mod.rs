@@ -4,6 +4,7 @@ pub mod size;
 pub mod stride;
 pub mod element;
 pub mod print;
+pub mod error;
 
 #[doc(inline)]
 pub use dimensions::*;
@@ -17,3 +18,5 @@ pub use stride::*;
 pub use element::*;
 #[doc(inline)]
 pub use print::*;
+#[doc(inline)]
+pub use error::*;
\ No newline at end of file
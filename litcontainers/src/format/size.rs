@@ -2,6 +2,13 @@ use super::Dim;
 use std::fmt;
 use crate::{Fmt, Axis};
 
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde-serialize")]
+use serde::de::Error;
+#[cfg(feature = "serde-serialize")]
+use serde::ser::SerializeStruct;
+
 pub type SSize<S> = Size<<S as StorageSize>::Rows, <S as StorageSize>::Cols>;
 
 // TODO: Can we write it more generic for possible 3d sizes?
@@ -22,6 +29,35 @@ impl<R: Dim, C: Dim> StorageSize for Size<R, C> {
 	fn col_dim(&self) -> Self::Cols { self.cols }
 }
 
+#[cfg(feature = "serde-serialize")]
+impl<R: Dim, C: Dim> Serialize for Size<R, C> {
+	fn serialize<SE>(&self, serializer: SE) -> Result<SE::Ok, SE::Error>
+		where SE: Serializer {
+		let mut st = serializer.serialize_struct("Size", 2)?;
+		st.serialize_field("rows", &self.rows())?;
+		st.serialize_field("cols", &self.cols())?;
+		st.end()
+	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, R: Dim, C: Dim> Deserialize<'de> for Size<R, C> {
+	/// Fails if the values don't match the static dimensions.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de> {
+		#[derive(Deserialize)]
+		#[serde(rename = "Size")]
+		struct Def { rows: usize, cols: usize }
+
+		let def = Def::deserialize(deserializer)?;
+		let mismatch = |name: &str, v: usize, expected: Option<usize>|
+			D::Error::custom(format!("Mismatched {} dimension {}, expected {}", name, v, expected.unwrap_or(v)));
+		let rows = R::try_from_usize(def.rows).ok_or_else(|| mismatch("rows", def.rows, R::try_to_usize()))?;
+		let cols = C::try_from_usize(def.cols).ok_or_else(|| mismatch("cols", def.cols, C::try_to_usize()))?;
+		Ok(Size::new(rows, cols))
+	}
+}
+
 pub trait StorageSize {
 	type Rows: Dim;
 	type Cols: Dim;
@@ -2,6 +2,13 @@ use super::Dim;
 use std::fmt;
 use crate::{Fmt, Axis};
 
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde-serialize")]
+use serde::de::Error;
+#[cfg(feature = "serde-serialize")]
+use serde::ser::SerializeStruct;
+
 pub type SStrides<S> = Strides<<S as Strided>::RowStride, <S as Strided>::ColStride>;
 
 #[derive(Debug, PartialEq, Eq, Clone, new)]
@@ -27,6 +34,35 @@ impl<RS: Dim, CS: Dim> Strided for Strides<RS, CS> {
 	fn col_stride_dim(&self) -> Self::ColStride { self.col_stride }
 }
 
+#[cfg(feature = "serde-serialize")]
+impl<RS: Dim, CS: Dim> Serialize for Strides<RS, CS> {
+	fn serialize<SE>(&self, serializer: SE) -> Result<SE::Ok, SE::Error>
+		where SE: Serializer {
+		let mut st = serializer.serialize_struct("Strides", 2)?;
+		st.serialize_field("row_stride", &self.row_stride())?;
+		st.serialize_field("col_stride", &self.col_stride())?;
+		st.end()
+	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, RS: Dim, CS: Dim> Deserialize<'de> for Strides<RS, CS> {
+	/// Fails if the values don't match the static dimensions.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de> {
+		#[derive(Deserialize)]
+		#[serde(rename = "Strides")]
+		struct Def { row_stride: usize, col_stride: usize }
+
+		let def = Def::deserialize(deserializer)?;
+		let mismatch = |name: &str, v: usize, expected: Option<usize>|
+			D::Error::custom(format!("Mismatched {} dimension {}, expected {}", name, v, expected.unwrap_or(v)));
+		let row_stride = RS::try_from_usize(def.row_stride).ok_or_else(|| mismatch("row stride", def.row_stride, RS::try_to_usize()))?;
+		let col_stride = CS::try_from_usize(def.col_stride).ok_or_else(|| mismatch("col stride", def.col_stride, CS::try_to_usize()))?;
+		Ok(Strides::new(row_stride, col_stride))
+	}
+}
+
 pub trait Strided {
 	type RowStride: Dim;
 	type ColStride: Dim;
@@ -28,12 +28,6 @@ impl<T, P, PS, SS> FullIterCore<T, P, PS, SS>
 		}
 	}
 
-	unsafe fn reset(&mut self, ptr: *mut T) {
-		self.cursor = 1;
-		self.ptr = ptr;
-		self.axis = AxisIterRaw::new(self.axis.length, self.axis.stride, 0, ptr);
-	}
-
 	unsafe fn offset(&self, pos: usize) -> *mut T {
 		debug_assert!(
 			pos <= self.length.value(),
@@ -206,7 +200,14 @@ pub struct FullIterOwned<T, P, PS, SS, S>
 	where T: Element, P: Dim, PS: Dim, SS: Dim, S: Storage<T>
 {
 	storage: S,
-	iter: FullIterCore<T, P, PS, SS>
+	prim_size: P,
+	prim_stride: PS,
+	scnd_size: usize,
+	scnd_stride: SS,
+	// Storages keeping their elements inline move with the iterator, so elements are addressed by position from the
+	// current start of the data instead of keeping pointers into it.
+	pos: usize,
+	_phantoms: PhantomData<T>,
 }
 
 impl<T, P, PS, SS, S> FullIterOwned<T, P, PS, SS, S>
@@ -217,18 +218,18 @@ impl<T, P, PS, SS, S> FullIterOwned<T, P, PS, SS, S>
 		      A: Axis<S::Rows, S::Cols, RetType=P> + Axis<S::RowStride, S::ColStride, RetType=PS>,
 		      AxisParallel<A, S::RowStride, S::ColStride>: Axis<S::RowStride, S::ColStride, RetType=SS>
 	{
-		let prim_size = s.get_axis_size::<A>();
-		let prim_stride = s.get_axis_stride::<A>();
-		let scnd_size = s.get_axis_size::<AxisParallel<A, S::Rows, S::Cols>>();
-		let scnd_stride = s.get_axis_stride::<AxisParallel<A, S::RowStride, S::ColStride>>();
-
-		let mut ret = Self {
+		Self {
+			prim_size: s.get_axis_size::<A>(),
+			prim_stride: s.get_axis_stride::<A>(),
+			scnd_size: s.get_axis_size::<AxisParallel<A, S::Rows, S::Cols>>().value(),
+			scnd_stride: s.get_axis_stride::<AxisParallel<A, S::RowStride, S::ColStride>>(),
 			storage: s,
-			iter: FullIterCore::new(std::ptr::null_mut(), prim_size, prim_stride, scnd_size, scnd_stride)
-		};
-		unsafe { ret.iter.reset(ret.storage.as_ptr() as *mut T); }
-		ret
+			pos: 0,
+			_phantoms: PhantomData,
+		}
 	}
+
+	fn total_len(&self) -> usize { self.prim_size.value() * self.scnd_size }
 }
 
 impl<T, P, PS, SS, S> ExactSizeIterator for FullIterOwned<T, P, PS, SS, S>
@@ -241,10 +242,17 @@ impl<T, P, PS, SS, S> Iterator for FullIterOwned<T, P, PS, SS, S>
 	type Item = T;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		self.iter.next().map(|v| unsafe { *v })
+		if self.pos >= self.total_len() { return None; }
+		let (prim, scnd) = (self.pos / self.scnd_size, self.pos % self.scnd_size);
+		self.pos += 1;
+		let offset = prim * self.prim_stride.value() + scnd * self.scnd_stride.value();
+		Some(unsafe { *self.storage.as_ptr().add(offset) })
 	}
 
-	fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.total_len() - self.pos;
+		(len, Some(len))
+	}
 }
 
 pub type FullAxisIter<'a, T, S, A>
@@ -13,6 +13,7 @@ pub mod storage;
 pub mod container;
 pub mod slice;
 pub mod ops;
+pub mod sparse;
 
 pub use format::*;
 pub use iterator::*;
@@ -20,6 +21,7 @@ pub use storage::*;
 pub use container::*;
 pub use slice::*;
 pub use ops::*;
+pub use sparse::*;
 
 // Documentation inlines
 #[doc(inline)] pub use format::{Scalar, StorageSize, Strided};
@@ -0,0 +1,50 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_container_match {
+	($left: expr, $right: expr, $eq: expr, $kind: expr) => {
+		{
+			let (left, right) = (&$left, &$right);
+			if !$crate::StorageSize::equal_size(left, right) {
+				panic!(
+					"assertion failed: `{}`, sizes differ: left is {}x{}, right is {}x{}", $kind,
+					$crate::StorageSize::rows(left), $crate::StorageSize::cols(left),
+					$crate::StorageSize::rows(right), $crate::StorageSize::cols(right)
+				);
+			}
+			if let Some((r, c)) = $crate::Storage::find_mismatch(left, right, $eq) {
+				panic!(
+					"assertion failed: `{}`, first mismatch at ({}, {}): left = {:?}, right = {:?}", $kind, r, c,
+					$crate::Storage::get(left, r, c), $crate::Storage::get(right, r, c)
+				);
+			}
+		}
+	};
+}
+
+/// Asserts that two storages have equal size and elements. Panics with the first mismatching `(row, col)`.
+#[macro_export]
+macro_rules! assert_container_eq {
+	($left: expr, $right: expr) => {
+		$crate::__assert_container_match!($left, $right, |a, b| a == b, "left == right")
+	};
+}
+
+/// Asserts that two storages are approximately equal. Panics with the first mismatching `(row, col)`.
+///
+/// Without tolerances elements are compared relatively with the default tolerances of the element type. Giving only
+/// `epsilon` compares absolute differences, `max_relative` or `max_ulps` can be added for relative or ulps comparison.
+#[macro_export]
+macro_rules! assert_container_approx_eq {
+	($left: expr, $right: expr) => {
+		$crate::__assert_container_match!($left, $right, |a, b| $crate::ApproxEq::approx_eq(a, b), "left ≈ right")
+	};
+	($left: expr, $right: expr, epsilon = $e: expr) => {
+		$crate::__assert_container_match!($left, $right, |a, b| $crate::ApproxEq::abs_diff_eq(a, b, $e), "left ≈ right")
+	};
+	($left: expr, $right: expr, epsilon = $e: expr, max_relative = $m: expr) => {
+		$crate::__assert_container_match!($left, $right, |a, b| $crate::ApproxEq::relative_eq(a, b, $e, $m), "left ≈ right")
+	};
+	($left: expr, $right: expr, epsilon = $e: expr, max_ulps = $u: expr) => {
+		$crate::__assert_container_match!($left, $right, |a, b| $crate::ApproxEq::ulps_eq(a, b, $e, $u), "left ≈ right")
+	};
+}
\ No newline at end of file
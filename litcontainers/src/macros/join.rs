@@ -7,7 +7,8 @@ macro_rules! ichain_by_slices {
 	}
 }
 
-// TODO: these are rowwise joins. Can we make a add colwise variants?
+/// Joins storages horizontally into a container with `Dynamic` columns unless the dimension is given.
+/// See `hstack!` for statically computed dimensions.
 #[macro_export]
 macro_rules! join_cols {
 	($v0: expr $(, $v: expr)*; $d: expr; $S: ident) => {
@@ -27,6 +28,8 @@ macro_rules! join_cols {
 	($v0: expr $(, $v: expr)*) => (join_cols!($v0 $(, $v)*; Dynamic::new($v0.cols() $(+ $v.cols())*); ContainerRM));
 }
 
+/// Joins storages vertically into a container with `Dynamic` rows unless the dimension is given.
+/// See `vstack!` for statically computed dimensions.
 #[macro_export]
 macro_rules! join_rows {
 	($v0: expr $(, $v: expr)*; $d: expr; $S: ident) => {
@@ -44,4 +47,32 @@ macro_rules! join_rows {
 	};
 	($v0: expr $(, $v: expr)*; $d: expr) => (join_rows!($v0 $(, $v)*; $d; ContainerRM));
 	($v0: expr $(, $v: expr)*) => (join_rows!($v0 $(, $v)*; Dynamic::new($v0.rows() $(+ $v.rows())*); ContainerRM));
+}
+
+/// Joins two or more storages horizontally. Output columns are computed with `DimAdd`.
+#[macro_export]
+macro_rules! hstack {
+	($v0: expr, $v1: expr $(,)*) => ($crate::hstack(&$v0, &$v1));
+	($v0: expr, $v1: expr, $($v: expr),+ $(,)*) => (hstack!($crate::hstack(&$v0, &$v1), $($v),+));
+}
+
+/// Joins two or more storages vertically. Output rows are computed with `DimAdd`.
+#[macro_export]
+macro_rules! vstack {
+	($v0: expr, $v1: expr $(,)*) => ($crate::vstack(&$v0, &$v1));
+	($v0: expr, $v1: expr, $($v: expr),+ $(,)*) => (vstack!($crate::vstack(&$v0, &$v1), $($v),+));
+}
+
+/// Fallible `hstack!`. Returns the first `ShapeError::JoinMismatch` instead of panicking.
+#[macro_export]
+macro_rules! try_hstack {
+	($v0: expr, $v1: expr $(,)*) => ($crate::try_hstack(&$v0, &$v1));
+	($v0: expr, $v1: expr, $($v: expr),+ $(,)*) => ($crate::try_hstack(&$v0, &$v1).and_then(|h| try_hstack!(h, $($v),+)));
+}
+
+/// Fallible `vstack!`. Returns the first `ShapeError::JoinMismatch` instead of panicking.
+#[macro_export]
+macro_rules! try_vstack {
+	($v0: expr, $v1: expr $(,)*) => ($crate::try_vstack(&$v0, &$v1));
+	($v0: expr, $v1: expr, $($v: expr),+ $(,)*) => ($crate::try_vstack(&$v0, &$v1).and_then(|v| try_vstack!(v, $($v),+)));
 }
\ No newline at end of file
@@ -2,6 +2,7 @@
 #[macro_use] pub mod constructors;
 #[macro_use] pub mod join;
 #[macro_use] pub mod slice;
+#[macro_use] pub mod compare;
 
 pub use composition::*;
 pub use constructors::*;
@@ -1,43 +1,57 @@
+/// Splits the storage into given number of chunks along an axis. Panics if the axis is shorter than the chunk count.
 #[macro_export]
 macro_rules! split_into {
+	($storage: expr, $chunk_count: expr; $axis: ident) => {
+		$crate::try_split_into!($storage, $chunk_count; $axis).unwrap_or_else(|e| panic!("{}", e))
+	};
+}
+
+/// Splits the storage into given number of chunks along an axis. Returns `ShapeError::ChunkCount` if the axis is
+/// shorter than the chunk count.
+#[macro_export]
+macro_rules! try_split_into {
 	($storage: expr, $chunk_count: expr; RowAxis) => {
 		{
 			let mut storage = $storage.into_slice();
-			assert!(storage.rows() >= $chunk_count, "Storage dimensions must be larger than the chunk count.");
+			if storage.rows() < $chunk_count || $chunk_count == 0 {
+				Err($crate::ShapeError::ChunkCount { axis: $crate::AxisType::Row, chunks: $chunk_count, len: storage.rows() })
+			} else {
+				let storage_size = Size::new(D!(storage.rows()), storage.col_dim());
+				let storage_stride = storage.strides();
+				let mut storage = storage.transmute_dims_inplace(storage_size, storage_stride);
 
-			let storage_size = Size::new(D!(storage.rows()), storage.col_dim());
-			let storage_stride = storage.strides();
-			let mut storage = storage.transmute_dims_inplace(storage_size, storage_stride);
-
-			let mut ret = Vec::new();
-			let mut chunk_size = D!(storage.rows() / $chunk_count);
-			for _ in 0..$chunk_count - 1 {
-				let (l, r) = storage.split_at_row(chunk_size);
-				ret.push(l);
-				storage = r;
+				let mut ret = Vec::new();
+				let mut chunk_size = D!(storage.rows() / $chunk_count);
+				for _ in 0..$chunk_count - 1 {
+					let (l, r) = storage.split_at_row(chunk_size);
+					ret.push(l);
+					storage = r;
+				}
+				ret.push(storage);
+				Ok(ret)
 			}
-			ret.push(storage);
-			ret
 		}
 	};
 	($storage: expr, $chunk_count: expr; ColAxis) => {
 		{
 			let mut storage = $storage.into_slice();
-			assert!(storage.cols() >= $chunk_count, "Storage dimensions must be larger than the chunk count.");
-
-			let storage_size = Size::new(storage.row_dim(), D!(storage.cols()));
-			let storage_stride = storage.strides();
-			let mut storage = storage.transmute_dims_inplace(storage_size, storage_stride);
+			if storage.cols() < $chunk_count || $chunk_count == 0 {
+				Err($crate::ShapeError::ChunkCount { axis: $crate::AxisType::Col, chunks: $chunk_count, len: storage.cols() })
+			} else {
+				let storage_size = Size::new(storage.row_dim(), D!(storage.cols()));
+				let storage_stride = storage.strides();
+				let mut storage = storage.transmute_dims_inplace(storage_size, storage_stride);
 
-			let mut ret = Vec::new();
-			let mut chunk_size = D!(storage.cols() / $chunk_count);
-			for _ in 0..$chunk_count - 1 {
-				let (l, r) = storage.split_at_col(chunk_size);
-				ret.push(l);
-				storage = r;
+				let mut ret = Vec::new();
+				let mut chunk_size = D!(storage.cols() / $chunk_count);
+				for _ in 0..$chunk_count - 1 {
+					let (l, r) = storage.split_at_col(chunk_size);
+					ret.push(l);
+					storage = r;
+				}
+				ret.push(storage);
+				Ok(ret)
 			}
-			ret.push(storage);
-			ret
 		}
 	}
 }
\ No newline at end of file
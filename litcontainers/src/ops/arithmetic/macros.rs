@@ -143,9 +143,24 @@ macro_rules! operation_storage_binary_op (
 			right: R,
 		}
 
+		impl<L, R> $Name<L, R>
+			where L: Operation, L::Result: InplaceMapOrdered<L::Type> + StorageSize,
+			      R: Operation, R::Result: IntoOrderedIterator<R::Type> + StorageSize,
+			      L::Type: $Trait<R::Type, Output=L::Type>
+		{
+			/// Applies the operation. Fails if the operands differ in size.
+			pub fn try_apply(self) -> ShapeResult<L::Result> {
+				let mut l = self.left.apply();
+				let r = self.right.apply();
+				ShapeError::check_size(&l, &r)?;
+				l.mapv_inplace_zip_ordered(r.into_ordered_iter(), |l, r| l.$op_fn(r));
+				Ok(l)
+			}
+		}
+
 		impl<L, R> Operation for $Name<L, R>
-			where L: Operation, L::Result: InplaceMapOrdered<L::Type>,
-			      R: Operation, R::Result: IntoOrderedIterator<R::Type>,
+			where L: Operation, L::Result: InplaceMapOrdered<L::Type> + StorageSize,
+			      R: Operation, R::Result: IntoOrderedIterator<R::Type> + StorageSize,
 			      L::Type: $Trait<R::Type, Output=L::Type>
 		{
 			type Type = L::Type;
@@ -154,10 +169,7 @@ macro_rules! operation_storage_binary_op (
 			type Result = L::Result;
 
 			fn apply(self) -> Self::Result {
-				let mut l = self.left.apply();
-				let r = self.right.apply();
-				l.mapv_inplace_zip_ordered(r.into_ordered_iter(), |l, r| l.$op_fn(r));
-				l
+				self.try_apply().unwrap_or_else(|e| panic!("{}", e))
 			}
 		}
 
@@ -171,9 +183,23 @@ macro_rules! operation_storage_binary_op (
 				right: R,
 			}
 
+			impl<'a, L, R> $NameAssign<'a, L, R>
+				where L: InplaceMapOrdered<R::Type> + StorageSize,
+					  R: Operation, R::Result: IntoOrderedIterator<R::Type> + StorageSize,
+					  R::Type: $Trait<R::Type, Output=R::Type>,
+			{
+				/// Applies the operation. Fails if the operands differ in size.
+				pub fn try_apply(self) -> ShapeResult<()> {
+					let r = self.right.apply();
+					ShapeError::check_size(self.left, &r)?;
+					self.left.mapv_inplace_zip_ordered(r.into_ordered_iter(), |l, r| l.$op_fn(r));
+					Ok(())
+				}
+			}
+
 			impl<'a, L, R> Operation for $NameAssign<'a, L, R>
 				where L: InplaceMapOrdered<R::Type> + StorageSize,
-					  R: Operation, R::Result: IntoOrderedIterator<R::Type>,
+					  R: Operation, R::Result: IntoOrderedIterator<R::Type> + StorageSize,
 					  R::Type: $Trait<R::Type, Output=R::Type>,
 			{
 				type Type = R::Type;
@@ -182,8 +208,7 @@ macro_rules! operation_storage_binary_op (
 				type Result = ();
 
 				fn apply(self) -> Self::Result {
-					let r = self.right.apply();
-					self.left.mapv_inplace_zip_ordered(r.into_ordered_iter(), |l, r| l.$op_fn(r));
+					self.try_apply().unwrap_or_else(|e| panic!("{}", e))
 				}
 			}
 		)?
@@ -200,9 +225,24 @@ macro_rules! operation_storage_binary_rev_op (
 			right: R,
 		}
 
+		impl<L, R, LS, RS> $Name<L, R>
+			where L: Operation<Result=LS>, LS: IntoOrderedIterator<L::Type> + StorageSize,
+			      R: Operation<Result=RS>, RS: InplaceMapOrdered<R::Type> + StorageSize,
+			      L::Type: $Trait<R::Type, Output=R::Type>
+		{
+			/// Applies the operation. Fails if the operands differ in size.
+			pub fn try_apply(self) -> ShapeResult<R::Result> {
+				let l = self.left.apply();
+				let mut r = self.right.apply();
+				ShapeError::check_size(&r, &l)?;
+				r.mapv_inplace_zip_ordered(l.into_ordered_iter(), |r, l| l.$op_fn(r));
+				Ok(r)
+			}
+		}
+
 		impl<L, R, LS, RS> Operation for $Name<L, R>
-			where L: Operation<Result=LS>, LS: IntoOrderedIterator<L::Type>,
-			      R: Operation<Result=RS>, RS: InplaceMapOrdered<R::Type>,
+			where L: Operation<Result=LS>, LS: IntoOrderedIterator<L::Type> + StorageSize,
+			      R: Operation<Result=RS>, RS: InplaceMapOrdered<R::Type> + StorageSize,
 			      L::Type: $Trait<R::Type, Output=R::Type>
 		{
 			type Type = R::Type;
@@ -211,10 +251,7 @@ macro_rules! operation_storage_binary_rev_op (
 			type Result = R::Result;
 
 			fn apply(self) -> Self::Result {
-				let l = self.left.apply();
-				let mut r = self.right.apply();
-				r.mapv_inplace_zip_ordered(l.into_ordered_iter(), |r, l| l.$op_fn(r));
-				r
+				self.try_apply().unwrap_or_else(|e| panic!("{}", e))
 			}
 		}
 
@@ -228,9 +265,23 @@ macro_rules! operation_storage_binary_rev_op (
 				right: R,
 			}
 
+			impl<'a, L, R> $NameAssign<'a, L, R>
+				where L: InplaceMapOrdered<R::Type> + StorageSize,
+					  R: Operation, R::Result: IntoOrderedIterator<R::Type> + StorageSize,
+					  R::Type: $Trait<R::Type, Output=R::Type>,
+			{
+				/// Applies the operation. Fails if the operands differ in size.
+				pub fn try_apply(self) -> ShapeResult<()> {
+					let r = self.right.apply();
+					ShapeError::check_size(self.left, &r)?;
+					self.left.mapv_inplace_zip_ordered(r.into_ordered_iter(), |l, r| r.$op_fn(l));
+					Ok(())
+				}
+			}
+
 			impl<'a, L, R> Operation for $NameAssign<'a, L, R>
 				where L: InplaceMapOrdered<R::Type> + StorageSize,
-					  R: Operation, R::Result: IntoOrderedIterator<R::Type>,
+					  R: Operation, R::Result: IntoOrderedIterator<R::Type> + StorageSize,
 					  R::Type: $Trait<R::Type, Output=R::Type>,
 			{
 				type Type = R::Type;
@@ -239,8 +290,7 @@ macro_rules! operation_storage_binary_rev_op (
 				type Result = ();
 
 				fn apply(self) -> Self::Result {
-					let r = self.right.apply();
-					self.left.mapv_inplace_zip_ordered(r.into_ordered_iter(), |l, r| r.$op_fn(l));
+					self.try_apply().unwrap_or_else(|e| panic!("{}", e))
 				}
 			}
 		)?
@@ -302,7 +352,7 @@ macro_rules! operation_group_scalar_binary_rev (
 );
 
 macro_rules! operation_group_storage_binary (
-	($($Name: ident: $op_fn: ident $(| $NameAssign: ident: $op_fn_assign: ident)? => $Trait: ident),* $(,)*) => {
+	($($Name: ident: $op_fn: ident, $try_op_fn: ident $(| $NameAssign: ident: $op_fn_assign: ident, $try_op_fn_assign: ident)? => $Trait: ident),* $(,)*) => {
 		$(
 			fn $op_fn<O>(self, rhs: O) -> $Name<Self::OpType, O::OpType>
 				where O: IntoOperation, <O::OpType as Operation>::Result: IntoOrderedIterator<<O::OpType as Operation>::Type>,
@@ -311,6 +361,14 @@ macro_rules! operation_group_storage_binary (
 				$Name::new(self.into_operation(), rhs.into_operation())
 			}
 
+			fn $try_op_fn<O>(self, rhs: O) -> ShapeResult<<Self::OpType as Operation>::Result>
+				where O: IntoOperation, <O::OpType as Operation>::Result: IntoOrderedIterator<<O::OpType as Operation>::Type> + StorageSize,
+					  <Self::OpType as Operation>::Result: StorageSize,
+					  <Self::OpType as Operation>::Type: $Trait<<O::OpType as Operation>::Type, Output=<Self::OpType as Operation>::Type>,
+			{
+				self.$op_fn(rhs).try_apply()
+			}
+
 			$(
 				fn $op_fn_assign<O, T>(&mut self, rhs: O) -> $NameAssign<Self, O::OpType>
 					where T: $Trait<T, Output=T> + Element,
@@ -320,13 +378,22 @@ macro_rules! operation_group_storage_binary (
 				{
 					$NameAssign::new(self, rhs.into_operation())
 				}
+
+				fn $try_op_fn_assign<O, T>(&mut self, rhs: O) -> ShapeResult<()>
+					where T: $Trait<T, Output=T> + Element,
+					      O: IntoOperation, <O::OpType as Operation>::Result: IntoOrderedIterator<T> + StorageSize,
+				          O::OpType: Operation<Type=T>,
+					      Self: InplaceMapOrdered<T> + StorageSize
+				{
+					self.$op_fn_assign(rhs).try_apply()
+				}
 			)?
 		)*
 	}
 );
 
 macro_rules! operation_group_storage_binary_rev (
-	($($Name: ident: $op_fn: ident => $Trait: ident),* $(,)*) => {
+	($($Name: ident: $op_fn: ident, $try_op_fn: ident => $Trait: ident),* $(,)*) => {
 		$(
 			fn $op_fn<O>(self, lhs: O) -> $Name<O::OpType, Self::OpType>
 				where O: IntoOperation, <O::OpType as Operation>::Result: IntoOrderedIterator<<O::OpType as Operation>::Type>,
@@ -334,6 +401,14 @@ macro_rules! operation_group_storage_binary_rev (
 			{
 				$Name::new(lhs.into_operation(), self.into_operation())
 			}
+
+			fn $try_op_fn<O>(self, lhs: O) -> ShapeResult<<Self::OpType as Operation>::Result>
+				where O: IntoOperation, <O::OpType as Operation>::Result: IntoOrderedIterator<<O::OpType as Operation>::Type> + StorageSize,
+					  <Self::OpType as Operation>::Result: StorageSize,
+					  <O::OpType as Operation>::Type: $Trait<<Self::OpType as Operation>::Type, Output=<Self::OpType as Operation>::Type>
+			{
+				self.$op_fn(lhs).try_apply()
+			}
 		)*
 	}
 );
\ No newline at end of file
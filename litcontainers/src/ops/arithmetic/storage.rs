@@ -3,7 +3,7 @@ use crate::iterator::*;
 use crate::format::{StorageSize};
 use crate::storage::{InplaceMapOrdered};
 use std::ops::{Add, Sub, Mul, Div, Rem};
-use crate::{Element};
+use crate::{Element, ShapeError, ShapeResult};
 
 operation_storage_binary_op!(
     AddStorage | AddAssignStorage => Add: add,
@@ -24,17 +24,17 @@ pub trait ArithmeticStorageOps: IntoOperation + Sized
 	where <Self::OpType as Operation>::Result: InplaceMapOrdered<<Self::OpType as Operation>::Type>
 {
 	operation_group_storage_binary!(
-		AddStorage: add_storage | AddAssignStorage: add_assign_storage => Add,
-		SubStorage: sub_storage | SubAssignStorage: sub_assign_storage => Sub,
-		MulStorage: mul_storage | MulAssignStorage: mul_assign_storage => Mul,
-		DivStorage: div_storage | DivAssignStorage: div_assign_storage => Div,
-		RemStorage: rem_storage | RemAssignStorage: rem_assign_storage => Rem,
+		AddStorage: add_storage, try_add_storage | AddAssignStorage: add_assign_storage, try_add_assign_storage => Add,
+		SubStorage: sub_storage, try_sub_storage | SubAssignStorage: sub_assign_storage, try_sub_assign_storage => Sub,
+		MulStorage: mul_storage, try_mul_storage | MulAssignStorage: mul_assign_storage, try_mul_assign_storage => Mul,
+		DivStorage: div_storage, try_div_storage | DivAssignStorage: div_assign_storage, try_div_assign_storage => Div,
+		RemStorage: rem_storage, try_rem_storage | RemAssignStorage: rem_assign_storage, try_rem_assign_storage => Rem,
 	);
 
 	operation_group_storage_binary_rev!(
-		SubStorageRev: sub_storage_rev => Sub,
-		DivStorageRev: div_storage_rev => Div,
-		RemStorageRev: rem_storage_rev => Rem,
+		SubStorageRev: sub_storage_rev, try_sub_storage_rev => Sub,
+		DivStorageRev: div_storage_rev, try_div_storage_rev => Div,
+		RemStorageRev: rem_storage_rev, try_rem_storage_rev => Rem,
 	);
 }
 
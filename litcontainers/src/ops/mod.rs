@@ -4,6 +4,7 @@ pub mod input;
 pub mod arithmetic;
 pub mod size;
 pub mod other;
+pub mod signal;
 
 #[doc(inline)] pub use ops_traits::*;
 pub use ops::*;
@@ -11,3 +12,4 @@ pub use input::*;
 pub use arithmetic::*;
 pub use size::*;
 pub use other::*;
+pub use signal::*;
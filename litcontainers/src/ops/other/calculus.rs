@@ -0,0 +1,184 @@
+use crate::*;
+use num_traits::Float;
+
+/// Distance between consecutive samples along the integrated or differentiated axis.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Spacing<T> {
+	/// Equal distance between all samples.
+	Uniform(T),
+	/// Sample coordinates. Must have the length of the lanes along the axis.
+	Points(Vec<T>),
+}
+
+impl<T: Scalar> Spacing<T> {
+	/// Sample coordinates taken from the storage in row major order.
+	pub fn from_storage<S: Storage<T>>(s: &S) -> Self { Spacing::Points(s.as_iter().cloned().collect()) }
+
+	fn coordinates(&self, len: usize) -> Vec<T> {
+		match self {
+			Spacing::Uniform(h) => (0..len).map(|i| T::from_usize(i) * *h).collect(),
+			Spacing::Points(x) => {
+				assert_eq!(x.len(), len, "Number of sample coordinates must match the length of the axis!");
+				x.clone()
+			},
+		}
+	}
+}
+
+fn diff_slice<T: NumericElement>(lane: &[T], n: usize) -> Vec<T> {
+	let mut ret = lane.to_vec();
+	for _ in 0..n.min(lane.len()) {
+		ret = ret.windows(2).map(|w| w[1] - w[0]).collect();
+	}
+	ret
+}
+
+/// Size of the storage after differencing its lanes along an axis. Differencing along `RowAxis` shortens every row
+/// and thus removes columns, differencing along `ColAxis` removes rows. Only the shortened dimension becomes dynamic.
+pub trait DiffAxis<R: Dim, C: Dim>: Axis<R, C> {
+	type Rows: Dim;
+	type Cols: Dim;
+
+	fn diff_size(size: Size<R, C>, n: usize) -> Size<Self::Rows, Self::Cols>;
+}
+
+impl<R: Dim, C: Dim> DiffAxis<R, C> for RowAxis {
+	type Rows = R;
+	type Cols = Dynamic;
+
+	fn diff_size(size: Size<R, C>, n: usize) -> Size<R, Dynamic> {
+		Size::new(size.row_dim(), Dynamic::new(size.cols().saturating_sub(n)))
+	}
+}
+
+impl<R: Dim, C: Dim> DiffAxis<R, C> for ColAxis {
+	type Rows = Dynamic;
+	type Cols = C;
+
+	fn diff_size(size: Size<R, C>, n: usize) -> Size<Dynamic, C> {
+		Size::new(Dynamic::new(size.rows().saturating_sub(n)), size.col_dim())
+	}
+}
+
+/// Container returned by `diff` along axis `A`.
+pub type Differenced<T, A, R, C> = ContainerRM<T, <A as DiffAxis<R, C>>::Rows, <A as DiffAxis<R, C>>::Cols>;
+
+/// `n`-th discrete difference of every lane along given axis. Lanes become `n` elements shorter.
+pub fn diff<T, S, A>(s: &S, n: usize, _: A) -> Differenced<T, A, S::Rows, S::Cols>
+	where T: NumericElement, S: Storage<T>, A: DiffAxis<S::Rows, S::Cols>
+{
+	let mut ret = ContainerRM::zeros(A::diff_size(s.size(), n));
+	for (i, lane) in lanes(s, A::axis_type()).iter().enumerate() {
+		let lane = diff_slice(lane, n);
+		match A::axis_type() {
+			AxisType::Row => for (o, v) in ret.as_row_range_iter_mut(i).zip(lane) { *o = v; },
+			AxisType::Col => for (o, v) in ret.as_col_range_iter_mut(i).zip(lane) { *o = v; },
+		}
+	}
+	ret
+}
+
+fn gradient_slice<T: Scalar + Float>(f: &[T], x: &[T]) -> Vec<T> {
+	let n = f.len();
+	assert!(n >= 2, "Gradient requires at least two elements along the axis!");
+	(0..n).map(|i| {
+		if i == 0 { return (f[1] - f[0]) / (x[1] - x[0]); }
+		if i == n - 1 { return (f[n - 1] - f[n - 2]) / (x[n - 1] - x[n - 2]); }
+		// Second order accurate central difference for nonuniform spacing.
+		let (hs, hd) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+		(hs * hs * f[i + 1] + (hd * hd - hs * hs) * f[i] - hd * hd * f[i - 1]) / (hs * hd * (hd + hs))
+	}).collect()
+}
+
+/// Gradient of every lane along given axis. Interior elements use central differences and the edges use one sided
+/// differences, so the output has the size of the input.
+pub fn gradient<T, S, A>(s: &S, _: A, spacing: &Spacing<T>) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let x = spacing.coordinates(lane_len(s, A::axis_type()));
+	map_lanes(s, A::axis_type(), |_, lane| gradient_slice(lane, &x))
+}
+
+fn trapz_slice<T: Scalar + Float>(f: &[T], x: &[T]) -> T {
+	let two = T::from_usize(2);
+	f.windows(2).zip(x.windows(2)).fold(T::zero(), |acc, (f, x)| acc + (x[1] - x[0]) * (f[0] + f[1]) / two)
+}
+
+fn simpson_slice<T: Scalar + Float>(f: &[T], x: &[T]) -> T {
+	let n = f.len();
+	if n < 3 { return trapz_slice(f, x); }
+	let six = T::from_usize(6);
+	let two = T::from_usize(2);
+
+	// Parabolas through pairs of intervals.
+	let pairs = (n - 1) / 2;
+	let mut ret = (0..pairs).map(|p| 2 * p).fold(T::zero(), |acc, i| {
+		let (h0, h1) = (x[i + 1] - x[i], x[i + 2] - x[i + 1]);
+		let h = h0 + h1;
+		acc + h / six * ((two - h1 / h0) * f[i] + h * h / (h0 * h1) * f[i + 1] + (two - h0 / h1) * f[i + 2])
+	});
+
+	// Odd number of intervals. Last interval is integrated with the parabola through the last three samples.
+	if (n - 1) % 2 == 1 {
+		let (h0, h1) = (x[n - 2] - x[n - 3], x[n - 1] - x[n - 2]);
+		let three = T::from_usize(3);
+		let alpha = (two * h1 * h1 + three * h0 * h1) / (six * (h0 + h1));
+		let beta = (h1 * h1 + three * h0 * h1) / (six * h0);
+		let eta = h1 * h1 * h1 / (six * h0 * (h0 + h1));
+		ret = ret + alpha * f[n - 1] + beta * f[n - 2] - eta * f[n - 3];
+	}
+	ret
+}
+
+fn integrate<T, S, A, F>(s: &S, spacing: &Spacing<T>, f: F) -> RowVec<T, AxisRes<A, S::Rows, S::Cols>>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>, F: Fn(&[T], &[T]) -> T
+{
+	let x = spacing.coordinates(lane_len(s, A::axis_type()));
+	let mut ret = rvec_zeros![A::get_axis(s.row_dim(), s.col_dim())];
+	for (out, lane) in ret.as_iter_mut().zip(lanes(s, A::axis_type())) {
+		*out = f(&lane, &x);
+	}
+	ret
+}
+
+/// Integrates every lane along given axis with the trapezoidal rule.
+pub fn trapz<T, S, A>(s: &S, _: A, spacing: &Spacing<T>) -> RowVec<T, AxisRes<A, S::Rows, S::Cols>>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	integrate::<T, S, A, _>(s, spacing, trapz_slice)
+}
+
+/// Integrates every lane along given axis with the composite Simpson's rule. Nonuniform spacing is supported and
+/// an odd number of intervals is handled by integrating the last interval with the parabola through the last three
+/// samples.
+pub fn simpson<T, S, A>(s: &S, _: A, spacing: &Spacing<T>) -> RowVec<T, AxisRes<A, S::Rows, S::Cols>>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	integrate::<T, S, A, _>(s, spacing, simpson_slice)
+}
+
+pub trait CalculusOperations<T: NumericElement>: Storage<T> {
+	fn diff<A: DiffAxis<Self::Rows, Self::Cols>>(&self, n: usize, a: A) -> Differenced<T, A, Self::Rows, Self::Cols> {
+		diff(self, n, a)
+	}
+
+	fn gradient<A: Axis<Self::Rows, Self::Cols>>(&self, a: A, spacing: &Spacing<T>) -> ContainerRM<T, Self::Rows, Self::Cols>
+		where T: Scalar + Float
+	{
+		gradient(self, a, spacing)
+	}
+
+	fn trapz<A: Axis<Self::Rows, Self::Cols>>(&self, a: A, spacing: &Spacing<T>) -> RowVec<T, AxisRes<A, Self::Rows, Self::Cols>>
+		where T: Scalar + Float
+	{
+		trapz(self, a, spacing)
+	}
+
+	fn simpson<A: Axis<Self::Rows, Self::Cols>>(&self, a: A, spacing: &Spacing<T>) -> RowVec<T, AxisRes<A, Self::Rows, Self::Cols>>
+		where T: Scalar + Float
+	{
+		simpson(self, a, spacing)
+	}
+}
+
+impl<T: NumericElement, S: Storage<T>> CalculusOperations<T> for S {}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::*;
+
+/// Approximate comparison of storages of equal size. Elements are compared in row major order regardless of layout.
+pub trait ApproxOperations<T: ApproxEq>: Storage<T>
+{
+	fn abs_diff_eq<SO: Storage<T>>(&self, other: &SO, epsilon: T::Epsilon) -> bool {
+		self.equal_size(other) && self.find_mismatch(other, |a, b| a.abs_diff_eq(b, epsilon)).is_none()
+	}
+
+	fn relative_eq<SO: Storage<T>>(&self, other: &SO, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+		self.equal_size(other) && self.find_mismatch(other, |a, b| a.relative_eq(b, epsilon, max_relative)).is_none()
+	}
+
+	fn ulps_eq<SO: Storage<T>>(&self, other: &SO, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+		self.equal_size(other) && self.find_mismatch(other, |a, b| a.ulps_eq(b, epsilon, max_ulps)).is_none()
+	}
+
+	/// Relative comparison with default tolerances of the element type.
+	fn approx_eq<SO: Storage<T>>(&self, other: &SO) -> bool {
+		self.equal_size(other) && self.find_mismatch(other, |a, b| a.approx_eq(b)).is_none()
+	}
+}
+
+impl<T: ApproxEq, S: Storage<T>> ApproxOperations<T> for S {}
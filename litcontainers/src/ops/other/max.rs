@@ -50,11 +50,27 @@ impl<L> Operation for Minimum<L>
 	}
 }
 
+pub fn cummax<T, S, A>(s: &S, a: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Scalar, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	accumulate(s, a, |acc, v| if v > acc { v } else { acc })
+}
+
+pub fn cummin<T, S, A>(s: &S, a: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Scalar, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	accumulate(s, a, |acc, v| if v < acc { v } else { acc })
+}
+
 pub trait MaxOperations<T: Scalar>: Storage<T>
 {
 	fn maximum(&self) -> T { Maximum::new(BorrowedProvider::new(self)).apply() }
 
 	fn minimum(&self) -> T { Minimum::new(BorrowedProvider::new(self)).apply() }
+
+	fn cummax<A: Axis<Self::Rows, Self::Cols>>(&self, a: A) -> ContainerRM<T, Self::Rows, Self::Cols> { cummax(self, a) }
+
+	fn cummin<A: Axis<Self::Rows, Self::Cols>>(&self, a: A) -> ContainerRM<T, Self::Rows, Self::Cols> { cummin(self, a) }
 }
 
 impl<T: Scalar, S: Storage<T>> MaxOperations<T> for S {}
\ No newline at end of file
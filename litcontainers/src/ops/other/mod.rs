@@ -3,9 +3,15 @@ pub mod argmax;
 pub mod find;
 pub mod normalization;
 pub mod max;
+pub mod compare;
+pub mod rolling;
+pub mod calculus;
 
 pub use sum::*;
 pub use argmax::*;
 pub use find::*;
 pub use normalization::*;
-pub use max::*;
\ No newline at end of file
+pub use max::*;
+pub use compare::*;
+pub use rolling::*;
+pub use calculus::*;
\ No newline at end of file
@@ -0,0 +1,258 @@
+use crate::*;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use num_traits::Float;
+
+/// Position of the window relative to the output element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollingAlign {
+	/// Window ends at the output element.
+	Trailing,
+	/// Window is centered at the output element. Even windows extend one element further before it than after it.
+	Centered,
+}
+
+/// Output at positions where the window does not fit completely into the input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RollingEdge<T> {
+	/// Reduces over the part of the window which lies inside the input.
+	Partial,
+	/// Outputs the given value.
+	Fill(T),
+}
+
+/// Incrementally updated reduction over a sliding window. Elements enter and leave the window in lane order.
+trait WindowReducer<T> {
+	fn push(&mut self, lane: &[T], i: usize);
+
+	fn pop(&mut self, lane: &[T], i: usize);
+
+	fn value(&self, lane: &[T], count: usize) -> T;
+}
+
+struct SumReducer<T> {
+	sum: T,
+	mean: bool,
+}
+
+impl<T: Scalar> WindowReducer<T> for SumReducer<T> {
+	fn push(&mut self, lane: &[T], i: usize) { self.sum += lane[i]; }
+
+	fn pop(&mut self, lane: &[T], i: usize) { self.sum -= lane[i]; }
+
+	fn value(&self, _: &[T], count: usize) -> T {
+		if self.mean { self.sum / T::from_usize(count) } else { self.sum }
+	}
+}
+
+/// Welford's running mean and sum of squared deviations, updated when elements enter and leave the window.
+struct StdReducer<T> {
+	count: usize,
+	mean: T,
+	m2: T,
+	ddof: usize,
+}
+
+impl<T: Scalar + Float> WindowReducer<T> for StdReducer<T> {
+	fn push(&mut self, lane: &[T], i: usize) {
+		self.count += 1;
+		let delta = lane[i] - self.mean;
+		self.mean += delta / T::from_usize(self.count);
+		self.m2 += delta * (lane[i] - self.mean);
+	}
+
+	fn pop(&mut self, lane: &[T], i: usize) {
+		self.count -= 1;
+		if self.count == 0 {
+			self.mean = T::zero();
+			self.m2 = T::zero();
+			return;
+		}
+		let delta = lane[i] - self.mean;
+		self.mean -= delta / T::from_usize(self.count);
+		self.m2 -= delta * (lane[i] - self.mean);
+	}
+
+	fn value(&self, _: &[T], count: usize) -> T {
+		if count <= self.ddof { return T::nan(); }
+		// Removing elements may leave a tiny negative rounding residue for constant windows.
+		(Float::max(self.m2, T::zero()) / T::from_usize(count - self.ddof)).sqrt()
+	}
+}
+
+/// Monotonic deque of indices. The front holds the index of the window extreme.
+struct ExtremeReducer {
+	indices: VecDeque<usize>,
+	max: bool,
+}
+
+impl<T: Scalar> WindowReducer<T> for ExtremeReducer {
+	fn push(&mut self, lane: &[T], i: usize) {
+		while let Some(&back) = self.indices.back() {
+			let dominated = if self.max { lane[back] <= lane[i] } else { lane[back] >= lane[i] };
+			if !dominated { break; }
+			self.indices.pop_back();
+		}
+		self.indices.push_back(i);
+	}
+
+	fn pop(&mut self, _: &[T], i: usize) {
+		if self.indices.front() == Some(&i) { self.indices.pop_front(); }
+	}
+
+	fn value(&self, lane: &[T], _: usize) -> T { lane[self.indices[0]] }
+}
+
+/// Keeps the window sorted, so insertion and removal are a binary search and a shift of up to `window` elements.
+struct MedianReducer<T> {
+	sorted: Vec<T>,
+}
+
+impl<T: Scalar> WindowReducer<T> for MedianReducer<T> {
+	fn push(&mut self, lane: &[T], i: usize) {
+		let pos = self.sorted.partition_point(|v| *v < lane[i]);
+		self.sorted.insert(pos, lane[i]);
+	}
+
+	fn pop(&mut self, lane: &[T], i: usize) {
+		let pos = self.sorted.partition_point(|v| *v < lane[i]);
+		self.sorted.remove(pos);
+	}
+
+	fn value(&self, _: &[T], count: usize) -> T {
+		if count % 2 == 1 {
+			self.sorted[count / 2]
+		} else {
+			(self.sorted[count / 2 - 1] + self.sorted[count / 2]) / T::from_usize(2)
+		}
+	}
+}
+
+/// Rolling window over the lanes of a storage along an axis. Created by `RollingOperations::rolling`.
+/// Every reduction returns a container of the input size and visits each element a constant number of times,
+/// except `median` which keeps a sorted copy of the window and costs `O(window)` per element.
+pub struct Rolling<'a, T, S, A>
+	where T: Scalar, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	storage: &'a S,
+	window: usize,
+	align: RollingAlign,
+	edge: RollingEdge<T>,
+	_phantoms: PhantomData<A>,
+}
+
+impl<'a, T, S, A> Rolling<'a, T, S, A>
+	where T: Scalar, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	/// Trailing window of given size with partial windows at the edges.
+	pub fn new(storage: &'a S, window: usize) -> Self {
+		assert!(window > 0, "Rolling window must contain at least one element!");
+		Self { storage, window, align: RollingAlign::Trailing, edge: RollingEdge::Partial, _phantoms: PhantomData }
+	}
+
+	pub fn with_align(mut self, align: RollingAlign) -> Self {
+		self.align = align;
+		self
+	}
+
+	pub fn with_edge(mut self, edge: RollingEdge<T>) -> Self {
+		self.edge = edge;
+		self
+	}
+
+	pub fn window(&self) -> usize { self.window }
+
+	/// Range of lane indices covered by the window of the output element `i`.
+	fn bounds(&self, i: usize, len: usize) -> (usize, usize) {
+		let start = match self.align {
+			RollingAlign::Trailing => i as isize + 1 - self.window as isize,
+			RollingAlign::Centered => i as isize - (self.window / 2) as isize,
+		};
+		let end = (start + self.window as isize).min(len as isize) as usize;
+		(start.max(0) as usize, end)
+	}
+
+	fn reduce_lane<R: WindowReducer<T>>(&self, lane: &[T], mut reducer: R) -> Vec<T> {
+		let (mut lo, mut hi) = (0, 0);
+		(0..lane.len()).map(|i| {
+			let (start, end) = self.bounds(i, lane.len());
+			while hi < end {
+				reducer.push(lane, hi);
+				hi += 1;
+			}
+			while lo < start {
+				reducer.pop(lane, lo);
+				lo += 1;
+			}
+			match self.edge {
+				RollingEdge::Fill(v) if end - start < self.window => v,
+				_ => reducer.value(lane, end - start),
+			}
+		}).collect()
+	}
+
+	fn reduce<R, F>(&self, new_reducer: F) -> ContainerRM<T, S::Rows, S::Cols>
+		where R: WindowReducer<T>, F: Fn() -> R
+	{
+		let mut ret = ContainerRM::zeros(self.storage.size());
+		match A::axis_type() {
+			AxisType::Row => {
+				for (row_in, mut row_out) in self.storage.as_row_slice_iter().zip(ret.as_row_slice_iter_mut()) {
+					let lane: Vec<T> = row_in.as_row_iter().cloned().collect();
+					for (v_out, v) in row_out.as_row_iter_mut().zip(self.reduce_lane(&lane, new_reducer())) {
+						*v_out = v;
+					}
+				}
+			},
+			AxisType::Col => {
+				for (col_in, mut col_out) in self.storage.as_col_slice_iter().zip(ret.as_col_slice_iter_mut()) {
+					let lane: Vec<T> = col_in.as_col_iter().cloned().collect();
+					for (v_out, v) in col_out.as_col_iter_mut().zip(self.reduce_lane(&lane, new_reducer())) {
+						*v_out = v;
+					}
+				}
+			},
+		}
+		ret
+	}
+
+	pub fn sum(&self) -> ContainerRM<T, S::Rows, S::Cols> {
+		self.reduce(|| SumReducer { sum: T::default(), mean: false })
+	}
+
+	/// Mean over the elements inside the window. Partial windows are averaged over their actual length.
+	pub fn mean(&self) -> ContainerRM<T, S::Rows, S::Cols> {
+		self.reduce(|| SumReducer { sum: T::default(), mean: true })
+	}
+
+	pub fn min(&self) -> ContainerRM<T, S::Rows, S::Cols> {
+		self.reduce(|| ExtremeReducer { indices: VecDeque::with_capacity(self.window), max: false })
+	}
+
+	pub fn max(&self) -> ContainerRM<T, S::Rows, S::Cols> {
+		self.reduce(|| ExtremeReducer { indices: VecDeque::with_capacity(self.window), max: true })
+	}
+
+	/// Standard deviation with `ddof` delta degrees of freedom. Windows with at most `ddof` elements yield NaN.
+	pub fn std(&self, ddof: usize) -> ContainerRM<T, S::Rows, S::Cols>
+		where T: Float
+	{
+		self.reduce(|| StdReducer { count: 0, mean: T::zero(), m2: T::zero(), ddof })
+	}
+
+	/// Median of the window. Even windows yield the mean of the two middle elements.
+	/// Each step shifts the sorted window, so a lane of length `n` costs `O(n * window)`.
+	pub fn median(&self) -> ContainerRM<T, S::Rows, S::Cols> {
+		self.reduce(|| MedianReducer { sorted: Vec::with_capacity(self.window) })
+	}
+}
+
+pub trait RollingOperations<T: Scalar>: Storage<T> {
+	fn rolling<A: Axis<Self::Rows, Self::Cols>>(&self, window: usize, _: A) -> Rolling<'_, T, Self, A>
+		where Self: Sized
+	{
+		Rolling::new(self, window)
+	}
+}
+
+impl<T: Scalar, S: Storage<T>> RollingOperations<T> for S {}
\ No newline at end of file
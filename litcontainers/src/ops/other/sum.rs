@@ -23,26 +23,29 @@ impl<L> Operation for Sum<L>
 	}
 }
 
-pub fn cumsum<T, S, A>(s: &S, _: A) -> ContainerRM<T, S::Rows, S::Cols>
-	where T: NumericElement, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+/// Running reduction of the lanes along given axis. Every output element combines the previous output with the input.
+pub fn accumulate<T, S, A, F>(s: &S, _: A, f: F) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Element, S: Storage<T>, A: Axis<S::Rows, S::Cols>, F: Fn(T, T) -> T
 {
 	let mut ret = ContainerRM::zeros(s.size());
 	match A::axis_type() {
 		AxisType::Row => {
 			for (row_in, mut row_out) in s.as_row_slice_iter().zip(ret.as_row_slice_iter_mut()) {
-				let mut acc = T::default();
+				let mut acc = None;
 				for (v_in, v_out) in row_in.as_row_iter().zip(row_out.as_row_iter_mut()) {
-					acc += *v_in;
-					*v_out = acc;
+					let v = acc.map_or(*v_in, |acc| f(acc, *v_in));
+					acc = Some(v);
+					*v_out = v;
 				}
 			}
 		},
 		AxisType::Col => {
 			for (col_in, mut col_out) in s.as_col_slice_iter().zip(ret.as_col_slice_iter_mut()) {
-				let mut acc = T::default();
+				let mut acc = None;
 				for (v_in, v_out) in col_in.as_col_iter().zip(col_out.as_col_iter_mut()) {
-					acc += *v_in;
-					*v_out = acc;
+					let v = acc.map_or(*v_in, |acc| f(acc, *v_in));
+					acc = Some(v);
+					*v_out = v;
 				}
 			}
 		},
@@ -50,6 +53,18 @@ pub fn cumsum<T, S, A>(s: &S, _: A) -> ContainerRM<T, S::Rows, S::Cols>
 	ret
 }
 
+pub fn cumsum<T, S, A>(s: &S, a: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: NumericElement, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	accumulate(s, a, |acc, v| acc + v)
+}
+
+pub fn cumprod<T, S, A>(s: &S, a: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: NumericElement, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	accumulate(s, a, |acc, v| acc * v)
+}
+
 pub fn sum_rows<T, S>(s: &S) -> ColVec<T, S::Rows>
 	where T: NumericElement, S: Storage<T>
 {
@@ -118,6 +133,8 @@ pub trait SumOperations<T: NumericElement>: Storage<T> {
 	fn mean_cols(&self) -> RowVec<T, Self::Cols> { mean_cols(self) }
 
 	fn cumsum<A: Axis<Self::Rows, Self::Cols>>(&self, a: A) -> ContainerRM<T, Self::Rows, Self::Cols> { cumsum(self, a) }
+
+	fn cumprod<A: Axis<Self::Rows, Self::Cols>>(&self, a: A) -> ContainerRM<T, Self::Rows, Self::Cols> { cumprod(self, a) }
 }
 
 impl<T: NumericElement, S: Storage<T>> SumOperations<T> for S {}
\ No newline at end of file
@@ -0,0 +1,218 @@
+use crate::format::*;
+use crate::storage::*;
+use crate::container::*;
+use super::fft::fft;
+use super::{SignalElement, lanes, lane_len, from_lanes};
+use num_traits::Zero;
+
+/// Kernels longer than this are convolved with FFT based overlap-add instead of the direct sum.
+pub const OVERLAP_ADD_THRESHOLD: usize = 64;
+
+/// Size of the convolution output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvolutionMode {
+	/// Every position where the signal and the kernel overlap. Length is `n + m - 1`.
+	Full,
+	/// Output centered with respect to the full output. Length is the signal length `n`.
+	Same,
+	/// Only positions where the signal and the kernel overlap completely. Length is `max(n, m) - min(n, m) + 1`.
+	Valid,
+}
+
+/// Values assumed outside of the input in 2D convolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+	Zero,
+	/// Mirrors the input without repeating the edge value.
+	Reflect,
+	/// Repeats the input periodically.
+	Wrap,
+}
+
+/// Start and length of the output along an axis in coordinates of the full output.
+fn output_range(n: usize, m: usize, mode: ConvolutionMode) -> (usize, usize) {
+	if n == 0 || m == 0 { return (0, 0); }
+	match mode {
+		ConvolutionMode::Full => (0, n + m - 1),
+		ConvolutionMode::Same => ((m - 1) / 2, n),
+		ConvolutionMode::Valid => (n.min(m) - 1, n.max(m) - n.min(m) + 1),
+	}
+}
+
+fn boundary_index(i: isize, n: usize, boundary: Boundary) -> Option<usize> {
+	if i >= 0 && (i as usize) < n { return Some(i as usize); }
+	match boundary {
+		Boundary::Zero => None,
+		Boundary::Wrap => Some(i.rem_euclid(n as isize) as usize),
+		Boundary::Reflect => {
+			if n == 1 { return Some(0); }
+			let period = 2 * n as isize - 2;
+			let i = i.rem_euclid(period) as usize;
+			Some(if i >= n { period as usize - i } else { i })
+		},
+	}
+}
+
+fn convolve_full_direct<T: SignalElement>(x: &[T], k: &[T]) -> Vec<T> {
+	let mut ret = vec![c64::zero(); x.len() + k.len() - 1];
+	for (i, xv) in x.iter().enumerate() {
+		let xv = xv.to_c64();
+		for (o, kv) in ret[i..].iter_mut().zip(k) {
+			*o += xv * kv.to_c64();
+		}
+	}
+	ret.into_iter().map(T::from_c64).collect()
+}
+
+/// Overlap-add convolution. Signal is split into blocks which are convolved with the kernel in the frequency domain.
+fn convolve_full_overlap_add<T: SignalElement>(x: &[T], k: &[T]) -> Vec<T> {
+	let fft_len = (2 * k.len()).next_power_of_two();
+	let block_len = fft_len - k.len() + 1;
+
+	let mut kernel = vec![c64::zero(); fft_len];
+	for (o, v) in kernel.iter_mut().zip(k) { *o = v.to_c64(); }
+	fft(&mut kernel, false);
+
+	let mut ret = vec![c64::zero(); x.len() + k.len() - 1];
+	let mut buffer = vec![c64::zero(); fft_len];
+	for (block_idx, block) in x.chunks(block_len).enumerate() {
+		for v in buffer.iter_mut() { *v = c64::zero(); }
+		for (o, v) in buffer.iter_mut().zip(block) { *o = v.to_c64(); }
+		fft(&mut buffer, false);
+		for (v, kv) in buffer.iter_mut().zip(&kernel) { *v *= kv; }
+		fft(&mut buffer, true);
+
+		let offset = block_idx * block_len;
+		for (o, v) in ret[offset..].iter_mut().zip(&buffer[..block.len() + k.len() - 1]) {
+			*o += v;
+		}
+	}
+	ret.into_iter().map(T::from_c64).collect()
+}
+
+/// Convolves two sequences. Overlap-add is used when the shorter sequence is longer than `OVERLAP_ADD_THRESHOLD`.
+pub fn convolve_slice<T: SignalElement>(x: &[T], k: &[T], mode: ConvolutionMode) -> Vec<T> {
+	if x.is_empty() || k.is_empty() { return Vec::new(); }
+
+	let (long, short) = if x.len() >= k.len() { (x, k) } else { (k, x) };
+	let full = if short.len() > OVERLAP_ADD_THRESHOLD {
+		convolve_full_overlap_add(long, short)
+	} else {
+		convolve_full_direct(long, short)
+	};
+
+	let (start, len) = output_range(x.len(), k.len(), mode);
+	full[start..start + len].to_vec()
+}
+
+/// Cross-correlates two sequences. The kernel is conjugated for complex elements.
+pub fn correlate_slice<T: SignalElement>(x: &[T], k: &[T], mode: ConvolutionMode) -> Vec<T> {
+	let k: Vec<T> = k.iter().rev().map(|v| v.conj()).collect();
+	convolve_slice(x, &k, mode)
+}
+
+/// Convolves a row vector with a kernel. Kernel elements are taken in row major order.
+pub fn convolve<T, S, K>(x: &S, kernel: &K, mode: ConvolutionMode) -> RowVec<T, Dynamic>
+	where T: SignalElement, S: Storage<T>, K: Storage<T>
+{
+	assert_eq!(x.rows(), 1, "Convolution input must be a row vector!");
+	let x: Vec<T> = x.as_iter().cloned().collect();
+	let k: Vec<T> = kernel.as_iter().cloned().collect();
+	let ret = convolve_slice(&x, &k, mode);
+	RowVec::from_vec(Size::new(U1, Dynamic::new(ret.len())), &ret)
+}
+
+/// Cross-correlates a row vector with a kernel. Kernel elements are taken in row major order.
+pub fn correlate<T, S, K>(x: &S, kernel: &K, mode: ConvolutionMode) -> RowVec<T, Dynamic>
+	where T: SignalElement, S: Storage<T>, K: Storage<T>
+{
+	assert_eq!(x.rows(), 1, "Correlation input must be a row vector!");
+	let x: Vec<T> = x.as_iter().cloned().collect();
+	let k: Vec<T> = kernel.as_iter().cloned().collect();
+	let ret = correlate_slice(&x, &k, mode);
+	RowVec::from_vec(Size::new(U1, Dynamic::new(ret.len())), &ret)
+}
+
+fn map_axis<T, S, A, F>(s: &S, len: usize, f: F) -> ContainerRM<T, Dynamic, Dynamic>
+	where T: SignalElement, S: Storage<T>, A: Axis<S::Rows, S::Cols>, F: Fn(&[T]) -> Vec<T>
+{
+	let lanes: Vec<Vec<T>> = lanes(s, A::axis_type()).iter().map(|l| f(l)).collect();
+	from_lanes(&lanes, len, A::axis_type())
+}
+
+/// Convolves every lane of the storage along given axis with the kernel. Along `RowAxis` each row is convolved.
+pub fn convolve_axis<T, S, K, A>(s: &S, kernel: &K, _: A, mode: ConvolutionMode) -> ContainerRM<T, Dynamic, Dynamic>
+	where T: SignalElement, S: Storage<T>, K: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let k: Vec<T> = kernel.as_iter().cloned().collect();
+	let len = output_range(lane_len(s, A::axis_type()), k.len(), mode).1;
+	map_axis::<T, S, A, _>(s, len, |l| convolve_slice(l, &k, mode))
+}
+
+/// Cross-correlates every lane of the storage along given axis with the kernel.
+pub fn correlate_axis<T, S, K, A>(s: &S, kernel: &K, _: A, mode: ConvolutionMode) -> ContainerRM<T, Dynamic, Dynamic>
+	where T: SignalElement, S: Storage<T>, K: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let k: Vec<T> = kernel.as_iter().cloned().collect();
+	let len = output_range(lane_len(s, A::axis_type()), k.len(), mode).1;
+	map_axis::<T, S, A, _>(s, len, |l| correlate_slice(l, &k, mode))
+}
+
+/// 2D convolution of a matrix with a kernel matrix. Values outside of the input are given by the boundary mode.
+pub fn convolve2d<T, S, K>(s: &S, kernel: &K, mode: ConvolutionMode, boundary: Boundary) -> ContainerRM<T, Dynamic, Dynamic>
+	where T: SignalElement, S: Storage<T>, K: Storage<T>
+{
+	let (row_start, rows) = output_range(s.rows(), kernel.rows(), mode);
+	let (col_start, cols) = output_range(s.cols(), kernel.cols(), mode);
+	let input: Vec<T> = s.as_iter().cloned().collect();
+	let k: Vec<T> = kernel.as_iter().cloned().collect();
+
+	let mut ret = ContainerRM::zeros(Size::new(Dynamic::new(rows), Dynamic::new(cols)));
+	for r in 0..rows {
+		for c in 0..cols {
+			let (fr, fc) = ((row_start + r) as isize, (col_start + c) as isize);
+			let mut acc = c64::zero();
+			for kr in 0..kernel.rows() {
+				let ir = match boundary_index(fr - kr as isize, s.rows(), boundary) {
+					Some(ir) => ir,
+					None => continue,
+				};
+				for kc in 0..kernel.cols() {
+					if let Some(ic) = boundary_index(fc - kc as isize, s.cols(), boundary) {
+						acc += input[ir * s.cols() + ic].to_c64() * k[kr * kernel.cols() + kc].to_c64();
+					}
+				}
+			}
+			*ret.get_mut(r, c) = T::from_c64(acc);
+		}
+	}
+	ret
+}
+
+pub trait ConvolutionOperations<T: SignalElement>: Storage<T> {
+	fn convolve<K: Storage<T>>(&self, kernel: &K, mode: ConvolutionMode) -> RowVec<T, Dynamic> {
+		convolve(self, kernel, mode)
+	}
+
+	fn correlate<K: Storage<T>>(&self, kernel: &K, mode: ConvolutionMode) -> RowVec<T, Dynamic> {
+		correlate(self, kernel, mode)
+	}
+
+	fn convolve_axis<K: Storage<T>, A: Axis<Self::Rows, Self::Cols>>(&self, kernel: &K, axis: A, mode: ConvolutionMode)
+		-> ContainerRM<T, Dynamic, Dynamic>
+	{
+		convolve_axis(self, kernel, axis, mode)
+	}
+
+	fn correlate_axis<K: Storage<T>, A: Axis<Self::Rows, Self::Cols>>(&self, kernel: &K, axis: A, mode: ConvolutionMode)
+		-> ContainerRM<T, Dynamic, Dynamic>
+	{
+		correlate_axis(self, kernel, axis, mode)
+	}
+
+	fn convolve2d<K: Storage<T>>(&self, kernel: &K, mode: ConvolutionMode, boundary: Boundary) -> ContainerRM<T, Dynamic, Dynamic> {
+		convolve2d(self, kernel, mode, boundary)
+	}
+}
+
+impl<T: SignalElement, S: Storage<T>> ConvolutionOperations<T> for S {}
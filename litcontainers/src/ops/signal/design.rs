@@ -0,0 +1,290 @@
+use crate::format::*;
+use crate::storage::*;
+use crate::container::*;
+use super::Biquad;
+use num_traits::{Float, One, Zero, cast};
+use std::f64::consts::PI;
+
+/// Pass band of a designed filter. Frequencies are normalized to the Nyquist frequency, so they lie in `(0, 1)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterBand<T> {
+	LowPass(T),
+	HighPass(T),
+	BandPass(T, T),
+}
+
+impl<T: Float> FilterBand<T> {
+	fn to_f64(self) -> FilterBand<f64> {
+		let check = |f: T| {
+			let f = f.to_f64().unwrap();
+			assert!(f > 0. && f < 1., "Frequency must be normalized to the Nyquist frequency and lie in (0, 1)!");
+			f
+		};
+		match self {
+			FilterBand::LowPass(f) => FilterBand::LowPass(check(f)),
+			FilterBand::HighPass(f) => FilterBand::HighPass(check(f)),
+			FilterBand::BandPass(lo, hi) => {
+				let (lo, hi) = (check(lo), check(hi));
+				assert!(lo < hi, "Lower band edge must be below the upper band edge!");
+				FilterBand::BandPass(lo, hi)
+			},
+		}
+	}
+}
+
+/// Window applied to the ideal impulse response of a FIR filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Window {
+	Rectangular,
+	Hann,
+	Hamming,
+	Blackman,
+	/// Kaiser window with given shape parameter `beta`.
+	Kaiser(f64),
+}
+
+/// Modified Bessel function of the first kind of order zero.
+fn bessel_i0(x: f64) -> f64 {
+	let (mut sum, mut term, mut k) = (1., 1., 1.);
+	while term > sum * 1e-17 {
+		term *= (x / (2. * k)) * (x / (2. * k));
+		sum += term;
+		k += 1.;
+	}
+	sum
+}
+
+fn window_f64(window: Window, len: usize) -> Vec<f64> {
+	if len == 1 { return vec![1.]; }
+	let m = (len - 1) as f64;
+	(0..len).map(|n| {
+		let x = 2. * PI * n as f64 / m;
+		match window {
+			Window::Rectangular => 1.,
+			Window::Hann => 0.5 - 0.5 * x.cos(),
+			Window::Hamming => 0.54 - 0.46 * x.cos(),
+			Window::Blackman => 0.42 - 0.5 * x.cos() + 0.08 * (2. * x).cos(),
+			Window::Kaiser(beta) => {
+				let r = 2. * n as f64 / m - 1.;
+				bessel_i0(beta * (1. - r * r).max(0.).sqrt()) / bessel_i0(beta)
+			},
+		}
+	}).collect()
+}
+
+fn to_row_vec<T: Scalar + Float>(v: &[f64]) -> RowVec<T, Dynamic> {
+	let v: Vec<T> = v.iter().map(|x| cast(*x).unwrap()).collect();
+	RowVec::from_vec(Size::new(U1, Dynamic::new(v.len())), &v)
+}
+
+/// Symmetric window of given length.
+pub fn window<T: Scalar + Float>(window: Window, len: usize) -> RowVec<T, Dynamic> {
+	to_row_vec(&window_f64(window, len))
+}
+
+/// Designs a linear phase FIR filter with the windowed-sinc method. Taps are scaled to unit gain at DC for low-pass,
+/// at Nyquist for high-pass and at the band center for band-pass filters.
+pub fn firwin<T: Scalar + Float>(num_taps: usize, band: FilterBand<T>, window: Window) -> RowVec<T, Dynamic> {
+	assert!(num_taps > 0, "Filter must have at least one tap!");
+	let band = band.to_f64();
+	if let FilterBand::HighPass(_) = band {
+		assert!(num_taps % 2 == 1, "High-pass FIR filter must have an odd number of taps!");
+	}
+
+	let alpha = (num_taps - 1) as f64 / 2.;
+	let sinc = |x: f64| if x == 0. { 1. } else { (PI * x).sin() / (PI * x) };
+	// Ideal response as a sum of low-pass filters given by (sign, cutoff) pairs.
+	let (bands, scale_freq): (Vec<(f64, f64)>, f64) = match band {
+		FilterBand::LowPass(f) => (vec![(1., f)], 0.),
+		FilterBand::HighPass(f) => (vec![(1., 1.), (-1., f)], 1.),
+		FilterBand::BandPass(lo, hi) => (vec![(1., hi), (-1., lo)], (lo + hi) / 2.),
+	};
+
+	let win = window_f64(window, num_taps);
+	let taps: Vec<f64> = win.iter().enumerate().map(|(n, w)| {
+		let m = n as f64 - alpha;
+		w * bands.iter().map(|(sign, f)| sign * f * sinc(f * m)).sum::<f64>()
+	}).collect();
+
+	let gain: f64 = taps.iter().enumerate()
+		.map(|(n, t)| t * (PI * scale_freq * (n as f64 - alpha)).cos())
+		.sum();
+	let taps: Vec<f64> = taps.iter().map(|t| t / gain).collect();
+	to_row_vec(&taps)
+}
+
+fn poly_from_roots(r1: c64, r2: Option<c64>) -> [f64; 3] {
+	match r2 {
+		Some(r2) => [1., -(r1 + r2).re, (r1 * r2).re],
+		None => [1., -r1.re, 0.],
+	}
+}
+
+/// Groups digital zeros and poles into second order sections. Complex poles are paired with their conjugates.
+fn zpk_to_sos<T: Float>(zeros: &[c64], poles: &[c64], gain: f64) -> Vec<Biquad<T>> {
+	const EPS: f64 = 1e-10;
+	let mut complex: Vec<c64> = poles.iter().filter(|p| p.im > EPS).cloned().collect();
+	complex.sort_by(|a, b| a.norm().total_cmp(&b.norm()));
+	let reals: Vec<c64> = poles.iter().filter(|p| p.im.abs() <= EPS).map(|p| c64::new(p.re, 0.)).collect();
+
+	let mut pole_pairs: Vec<(c64, Option<c64>)> = reals.chunks(2).map(|c| (c[0], c.get(1).cloned())).collect();
+	pole_pairs.extend(complex.iter().map(|p| (*p, Some(p.conj()))));
+
+	let mut zeros = zeros.iter();
+	pole_pairs.iter().enumerate().map(|(i, (p1, p2))| {
+		let z1 = *zeros.next().unwrap();
+		let z2 = p2.and_then(|_| zeros.next().cloned());
+		let mut b = poly_from_roots(z1, z2);
+		let a = poly_from_roots(*p1, *p2);
+		if i == 0 { for v in b.iter_mut() { *v *= gain; } }
+		let c = |v: f64| -> T { cast(v).unwrap() };
+		Biquad::new([c(b[0]), c(b[1]), c(b[2])], [c(a[0]), c(a[1]), c(a[2])])
+	}).collect()
+}
+
+/// Designs a digital Butterworth filter of given order as a cascade of biquad sections. Band-pass filters have
+/// twice as many poles as the order.
+pub fn butter<T: Float>(order: usize, band: FilterBand<T>) -> Vec<Biquad<T>> {
+	assert!(order > 0, "Filter order must be at least one!");
+	const FS2: f64 = 4.;
+	let prewarp = |f: f64| FS2 * (PI * f / 2.).tan();
+	let n = order as f64;
+	let prototype: Vec<c64> = (0..order)
+		.map(|k| -(c64::i() * PI * (2. * k as f64 - n + 1.) / (2. * n)).exp())
+		.collect();
+
+	// Analog poles, analog zeros, analog gain and the number of digital zeros at Nyquist.
+	let (poles, zeros, gain, nyquist_zeros): (Vec<c64>, Vec<c64>, f64, usize) = match band.to_f64() {
+		FilterBand::LowPass(f) => {
+			let w = prewarp(f);
+			(prototype.iter().map(|p| p * w).collect(), Vec::new(), w.powi(order as i32), order)
+		},
+		FilterBand::HighPass(f) => {
+			let w = prewarp(f);
+			let gain = (c64::one() / prototype.iter().fold(c64::one(), |acc, p| acc * -p)).re;
+			(prototype.iter().map(|p| w / p).collect(), vec![c64::zero(); order], gain, 0)
+		},
+		FilterBand::BandPass(lo, hi) => {
+			let (lo, hi) = (prewarp(lo), prewarp(hi));
+			let (bw, w0) = (hi - lo, (lo * hi).sqrt());
+			let poles = prototype.iter().flat_map(|p| {
+				let p = p * bw / 2.;
+				let d = (p * p - w0 * w0).sqrt();
+				vec![p + d, p - d]
+			}).collect();
+			(poles, vec![c64::zero(); order], bw.powi(order as i32), order)
+		},
+	};
+
+	let bilinear = |s: &c64| (FS2 + s) / (FS2 - s);
+	let num = zeros.iter().fold(c64::one(), |acc, z| acc * (FS2 - z));
+	let den = poles.iter().fold(c64::one(), |acc, p| acc * (FS2 - p));
+	let gain = gain * (num / den).re;
+	let poles: Vec<c64> = poles.iter().map(bilinear).collect();
+
+	// Interleave zeros at DC and Nyquist so band-pass sections get one of each.
+	let dc_zeros = zeros.len();
+	let mut digital_zeros = Vec::with_capacity(dc_zeros + nyquist_zeros);
+	for i in 0..dc_zeros.max(nyquist_zeros) {
+		if i < dc_zeros { digital_zeros.push(c64::one()); }
+		if i < nyquist_zeros { digital_zeros.push(-c64::one()); }
+	}
+	zpk_to_sos(&digital_zeros, &poles, gain)
+}
+
+/// Multiplies the sections into a single transfer function `(b, a)`.
+pub fn sos_to_tf<T: Scalar + Float>(sections: &[Biquad<T>]) -> (RowVec<T, Dynamic>, RowVec<T, Dynamic>) {
+	let polymul = |p: &[T], q: &[T; 3]| {
+		let mut ret = vec![T::zero(); p.len() + 2];
+		for (i, pv) in p.iter().enumerate() {
+			for (j, qv) in q.iter().enumerate() {
+				ret[i + j] += *pv * *qv;
+			}
+		}
+		ret
+	};
+	let (mut b, mut a) = (vec![T::one()], vec![T::one()]);
+	for s in sections {
+		b = polymul(&b, &s.numerator());
+		a = polymul(&a, &s.denominator());
+	}
+	(
+		RowVec::from_vec(Size::new(U1, Dynamic::new(b.len())), &b),
+		RowVec::from_vec(Size::new(U1, Dynamic::new(a.len())), &a),
+	)
+}
+
+/// Biquad designs from the Audio EQ Cookbook by Robert Bristow-Johnson. Frequencies are normalized to the Nyquist
+/// frequency and gains are given in decibels.
+impl<T: Float> Biquad<T> {
+	fn cookbook(freq: T, q: T) -> (T, T) {
+		let w0 = T::from(PI).unwrap() * freq;
+		(w0.cos(), w0.sin() / (q + q))
+	}
+
+	fn shelf_gain(gain_db: T) -> T { T::from(10.).unwrap().powf(gain_db / T::from(40.).unwrap()) }
+
+	pub fn lowpass(freq: T, q: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let one = T::one();
+		let b1 = one - cos;
+		Self::new([b1 / (one + one), b1, b1 / (one + one)], [one + alpha, -(cos + cos), one - alpha])
+	}
+
+	pub fn highpass(freq: T, q: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let one = T::one();
+		let b1 = one + cos;
+		Self::new([b1 / (one + one), -b1, b1 / (one + one)], [one + alpha, -(cos + cos), one - alpha])
+	}
+
+	/// Band-pass with unit gain at the center frequency.
+	pub fn bandpass(freq: T, q: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let one = T::one();
+		Self::new([alpha, T::zero(), -alpha], [one + alpha, -(cos + cos), one - alpha])
+	}
+
+	pub fn notch(freq: T, q: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let one = T::one();
+		Self::new([one, -(cos + cos), one], [one + alpha, -(cos + cos), one - alpha])
+	}
+
+	pub fn allpass(freq: T, q: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let one = T::one();
+		Self::new([one - alpha, -(cos + cos), one + alpha], [one + alpha, -(cos + cos), one - alpha])
+	}
+
+	pub fn peaking(freq: T, q: T, gain_db: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let (one, a) = (T::one(), Self::shelf_gain(gain_db));
+		Self::new(
+			[one + alpha * a, -(cos + cos), one - alpha * a],
+			[one + alpha / a, -(cos + cos), one - alpha / a]
+		)
+	}
+
+	pub fn lowshelf(freq: T, q: T, gain_db: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let (one, a) = (T::one(), Self::shelf_gain(gain_db));
+		let two = one + one;
+		let k = two * a.sqrt() * alpha;
+		Self::new(
+			[a * ((a + one) - (a - one) * cos + k), two * a * ((a - one) - (a + one) * cos), a * ((a + one) - (a - one) * cos - k)],
+			[(a + one) + (a - one) * cos + k, -two * ((a - one) + (a + one) * cos), (a + one) + (a - one) * cos - k]
+		)
+	}
+
+	pub fn highshelf(freq: T, q: T, gain_db: T) -> Self {
+		let (cos, alpha) = Self::cookbook(freq, q);
+		let (one, a) = (T::one(), Self::shelf_gain(gain_db));
+		let two = one + one;
+		let k = two * a.sqrt() * alpha;
+		Self::new(
+			[a * ((a + one) + (a - one) * cos + k), -two * a * ((a - one) + (a + one) * cos), a * ((a + one) + (a - one) * cos - k)],
+			[(a + one) - (a - one) * cos + k, two * ((a - one) - (a + one) * cos), (a + one) - (a - one) * cos - k]
+		)
+	}
+}
@@ -0,0 +1,43 @@
+use crate::format::*;
+use num_complex::Complex;
+
+/// In-place iterative radix-2 FFT. Length of the data must be a power of two. The inverse transform is normalized.
+pub(crate) fn fft(data: &mut [c64], inverse: bool) {
+	let n = data.len();
+	if n <= 1 { return; }
+	debug_assert!(n.is_power_of_two(), "FFT length must be a power of two!");
+
+	let mut j = 0;
+	for i in 1..n {
+		let mut bit = n >> 1;
+		while j & bit != 0 {
+			j ^= bit;
+			bit >>= 1;
+		}
+		j |= bit;
+		if i < j { data.swap(i, j); }
+	}
+
+	let sign = if inverse { 1. } else { -1. };
+	let mut len = 2;
+	while len <= n {
+		let angle = sign * 2. * std::f64::consts::PI / len as f64;
+		let w_len = Complex::new(angle.cos(), angle.sin());
+		for start in (0..n).step_by(len) {
+			let mut w = Complex::new(1., 0.);
+			for k in 0..len / 2 {
+				let u = data[start + k];
+				let v = data[start + k + len / 2] * w;
+				data[start + k] = u + v;
+				data[start + k + len / 2] = u - v;
+				w *= w_len;
+			}
+		}
+		len <<= 1;
+	}
+
+	if inverse {
+		let scale = 1. / n as f64;
+		for v in data.iter_mut() { *v *= scale; }
+	}
+}
\ No newline at end of file
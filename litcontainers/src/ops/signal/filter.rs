@@ -0,0 +1,212 @@
+use crate::format::*;
+use crate::storage::*;
+use crate::container::*;
+use super::map_lanes;
+use num_traits::Float;
+
+/// Second order IIR section with coefficients normalized so that `a0 = 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Biquad<T> {
+	pub b0: T,
+	pub b1: T,
+	pub b2: T,
+	pub a1: T,
+	pub a2: T,
+}
+
+impl<T: Float> Biquad<T> {
+	/// Creates a section from numerator and denominator coefficients. Coefficients are normalized by `a[0]`.
+	pub fn new(b: [T; 3], a: [T; 3]) -> Self {
+		assert!(a[0] != T::zero(), "Leading denominator coefficient must be non zero!");
+		Self { b0: b[0] / a[0], b1: b[1] / a[0], b2: b[2] / a[0], a1: a[1] / a[0], a2: a[2] / a[0] }
+	}
+
+	pub fn numerator(&self) -> [T; 3] { [self.b0, self.b1, self.b2] }
+
+	pub fn denominator(&self) -> [T; 3] { [T::one(), self.a1, self.a2] }
+
+	/// Filters a single sample. The state is stored in transposed direct form II.
+	#[inline]
+	pub fn tick(&self, x: T, z: &mut [T; 2]) -> T {
+		let y = self.b0 * x + z[0];
+		z[0] = self.b1 * x - self.a1 * y + z[1];
+		z[1] = self.b2 * x - self.a2 * y;
+		y
+	}
+}
+
+/// Pads the coefficients to equal length and normalizes them by `a[0]`.
+fn normalize<T: Float>(b: &[T], a: &[T]) -> (Vec<T>, Vec<T>) {
+	assert!(!a.is_empty() && a[0] != T::zero(), "Leading denominator coefficient must be non zero!");
+	let n = b.len().max(a.len());
+	let mut bn = vec![T::zero(); n];
+	let mut an = vec![T::zero(); n];
+	for (o, v) in bn.iter_mut().zip(b) { *o = *v / a[0]; }
+	for (o, v) in an.iter_mut().zip(a) { *o = *v / a[0]; }
+	(bn, an)
+}
+
+/// Filters the samples with normalized coefficients of equal length. `z` holds the `n - 1` delays and is updated.
+fn lfilter_normalized<T: Float>(b: &[T], a: &[T], x: &[T], z: &mut [T]) -> Vec<T> {
+	let n = b.len();
+	x.iter().map(|&xv| {
+		if n == 1 { return b[0] * xv; }
+		let y = b[0] * xv + z[0];
+		for i in 0..n - 2 {
+			z[i] = b[i + 1] * xv + z[i + 1] - a[i + 1] * y;
+		}
+		z[n - 2] = b[n - 1] * xv - a[n - 1] * y;
+		y
+	}).collect()
+}
+
+/// Solves the linear system using Gaussian elimination with partial pivoting. NaN coefficients propagate to the result.
+fn solve<T: Float>(mut m: Vec<Vec<T>>, mut rhs: Vec<T>) -> Vec<T> {
+	let n = rhs.len();
+	for col in 0..n {
+		let pivot = (col + 1..n).fold(col, |p, i| if m[i][col].abs() > m[p][col].abs() { i } else { p });
+		m.swap(col, pivot);
+		rhs.swap(col, pivot);
+		let (top, bottom) = m.split_at_mut(col + 1);
+		let pivot_row = &top[col];
+		for (i, row) in bottom.iter_mut().enumerate() {
+			let f = row[col] / pivot_row[col];
+			for (v, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+				*v = *v - f * *p;
+			}
+			rhs[col + 1 + i] = rhs[col + 1 + i] - f * rhs[col];
+		}
+	}
+	let mut ret = vec![T::zero(); n];
+	for row in (0..n).rev() {
+		let acc = (row + 1..n).fold(rhs[row], |acc, k| acc - m[row][k] * ret[k]);
+		ret[row] = acc / m[row][row];
+	}
+	ret
+}
+
+/// Initial delays of `lfilter` corresponding to the steady state of a unit step response.
+pub fn lfilter_zi<T: Float>(b: &[T], a: &[T]) -> Vec<T> {
+	let (b, a) = normalize(b, a);
+	let n = b.len();
+	if n < 2 { return Vec::new(); }
+
+	let mut m = vec![vec![T::zero(); n - 1]; n - 1];
+	for i in 0..n - 1 {
+		m[i][i] = T::one();
+		m[i][0] = m[i][0] + a[i + 1];
+		if i + 1 < n - 1 { m[i][i + 1] = m[i][i + 1] - T::one(); }
+	}
+	let rhs = (1..n).map(|i| b[i] - a[i] * b[0]).collect();
+	solve(m, rhs)
+}
+
+/// Filters the samples with the rational transfer function `b / a`.
+pub fn lfilter_slice<T: Float>(b: &[T], a: &[T], x: &[T]) -> Vec<T> {
+	let (b, a) = normalize(b, a);
+	let mut z = vec![T::zero(); b.len() - 1];
+	lfilter_normalized(&b, &a, x, &mut z)
+}
+
+/// Filters the samples forward and backward, which results in zero phase shift. Signal edges are extended with an odd
+/// reflection of `3 * max(len(a), len(b))` samples and the filter starts in the steady state of the edge value.
+pub fn filtfilt_slice<T: Float>(b: &[T], a: &[T], x: &[T]) -> Vec<T> {
+	if x.is_empty() { return Vec::new(); }
+	let zi = lfilter_zi(b, a);
+	let (b, a) = normalize(b, a);
+	let len = x.len();
+	let edge = (3 * b.len()).min(len - 1);
+	let two = T::one() + T::one();
+
+	let mut ext = Vec::with_capacity(len + 2 * edge);
+	ext.extend((1..=edge).rev().map(|i| two * x[0] - x[i]));
+	ext.extend_from_slice(x);
+	ext.extend((1..=edge).map(|i| two * x[len - 1] - x[len - 1 - i]));
+
+	let mut z: Vec<T> = zi.iter().map(|v| *v * ext[0]).collect();
+	let mut y = lfilter_normalized(&b, &a, &ext, &mut z);
+	y.reverse();
+	let mut z: Vec<T> = zi.iter().map(|v| *v * y[0]).collect();
+	let mut y = lfilter_normalized(&b, &a, &y, &mut z);
+	y.reverse();
+	y[edge..edge + len].to_vec()
+}
+
+/// Cascade of biquad sections. Delays are kept per channel between calls, so a long signal can be filtered in blocks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SosFilter<T> {
+	sections: Vec<Biquad<T>>,
+	state: Vec<Vec<[T; 2]>>,
+}
+
+impl<T: Float> SosFilter<T> {
+	pub fn new(sections: Vec<Biquad<T>>) -> Self { Self { sections, state: Vec::new() } }
+
+	pub fn sections(&self) -> &[Biquad<T>] { &self.sections }
+
+	/// Clears the delays of all channels.
+	pub fn reset(&mut self) { self.state.clear() }
+
+	/// Filters the next block of samples of given channel.
+	pub fn process_slice(&mut self, channel: usize, x: &[T]) -> Vec<T> {
+		if self.state.len() <= channel {
+			self.state.resize(channel + 1, vec![[T::zero(); 2]; self.sections.len()]);
+		}
+		let (sections, state) = (&self.sections, &mut self.state[channel]);
+		x.iter().map(|&v| {
+			sections.iter().zip(state.iter_mut()).fold(v, |v, (s, z)| s.tick(v, z))
+		}).collect()
+	}
+
+	/// Filters the next block of every lane along given axis. Every lane is a separate channel.
+	pub fn process<S, A>(&mut self, x: &S, _: A) -> ContainerRM<T, S::Rows, S::Cols>
+		where T: Scalar, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+	{
+		map_lanes(x, A::axis_type(), |i, lane| self.process_slice(i, lane))
+	}
+}
+
+fn coefficients<T: Element, S: Storage<T>>(s: &S) -> Vec<T> { s.as_iter().cloned().collect() }
+
+/// Filters every lane along given axis with the rational transfer function `b / a`.
+pub fn lfilter<T, B, AS, S, A>(b: &B, a: &AS, x: &S, _: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Scalar + Float, B: Storage<T>, AS: Storage<T>, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let (b, a) = (coefficients(b), coefficients(a));
+	map_lanes(x, A::axis_type(), |_, lane| lfilter_slice(&b, &a, lane))
+}
+
+/// Zero phase filtering of every lane along given axis. See `filtfilt_slice`.
+pub fn filtfilt<T, B, AS, S, A>(b: &B, a: &AS, x: &S, _: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Scalar + Float, B: Storage<T>, AS: Storage<T>, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let (b, a) = (coefficients(b), coefficients(a));
+	map_lanes(x, A::axis_type(), |_, lane| filtfilt_slice(&b, &a, lane))
+}
+
+/// Filters every lane along given axis with a cascade of biquad sections starting from zero state.
+pub fn sosfilt<T, S, A>(sections: &[Biquad<T>], x: &S, axis: A) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	SosFilter::new(sections.to_vec()).process(x, axis)
+}
+
+pub trait FilterOperations<T: Scalar + Float>: Storage<T> {
+	fn lfilter<B: Storage<T>, AS: Storage<T>, A: Axis<Self::Rows, Self::Cols>>(&self, b: &B, a: &AS, axis: A)
+		-> ContainerRM<T, Self::Rows, Self::Cols>
+	{
+		lfilter(b, a, self, axis)
+	}
+
+	fn filtfilt<B: Storage<T>, AS: Storage<T>, A: Axis<Self::Rows, Self::Cols>>(&self, b: &B, a: &AS, axis: A)
+		-> ContainerRM<T, Self::Rows, Self::Cols>
+	{
+		filtfilt(b, a, self, axis)
+	}
+
+	fn sosfilt<A: Axis<Self::Rows, Self::Cols>>(&self, sections: &[Biquad<T>], axis: A) -> ContainerRM<T, Self::Rows, Self::Cols> {
+		sosfilt(sections, self, axis)
+	}
+}
+
+impl<T: Scalar + Float, S: Storage<T>> FilterOperations<T> for S {}
@@ -0,0 +1,83 @@
+use crate::format::*;
+use crate::storage::*;
+use crate::container::*;
+use num_traits::Float;
+
+/// Method used to interpolate between the given samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+	/// Value of the closest sample.
+	Nearest,
+	Linear,
+	/// Natural cubic spline, which has zero second derivative at both ends.
+	CubicSpline,
+}
+
+/// Second derivatives of the natural cubic spline through the samples. Solves the tridiagonal system with the
+/// Thomas algorithm.
+fn spline_derivatives<T: Float>(x: &[T], y: &[T]) -> Vec<T> {
+	let n = x.len();
+	let mut ret = vec![T::zero(); n];
+	if n < 3 { return ret; }
+
+	let (two, six) = (T::from(2).unwrap(), T::from(6).unwrap());
+	let mut diag = vec![T::zero(); n];
+	let mut rhs = vec![T::zero(); n];
+	for i in 1..n - 1 {
+		let (h0, h1) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+		diag[i] = two * (h0 + h1);
+		rhs[i] = six * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+		if i > 1 {
+			let f = h0 / diag[i - 1];
+			diag[i] = diag[i] - f * h0;
+			rhs[i] = rhs[i] - f * rhs[i - 1];
+		}
+	}
+	for i in (1..n - 1).rev() {
+		ret[i] = (rhs[i] - (x[i + 1] - x[i]) * ret[i + 1]) / diag[i];
+	}
+	ret
+}
+
+/// Interpolates the samples `(x, y)` at given positions. `x` must be strictly increasing. Positions outside of the
+/// sampled range take the value of the first or the last sample. NaN positions yield NaN.
+pub fn interp1d_slice<T: Float>(x: &[T], y: &[T], positions: &[T], kind: Interpolation) -> Vec<T> {
+	assert_eq!(x.len(), y.len(), "Sample positions and values must be of equal length!");
+	assert!(!x.is_empty(), "At least one sample is required for interpolation!");
+	assert!(x.windows(2).all(|w| w[0] < w[1]), "Sample positions must be strictly increasing!");
+
+	let n = x.len();
+	let m = if kind == Interpolation::CubicSpline { spline_derivatives(x, y) } else { Vec::new() };
+	let (two, six) = (T::from(2).unwrap(), T::from(6).unwrap());
+	positions.iter().map(|&p| {
+		if p.is_nan() { return p; }
+		if p <= x[0] { return y[0]; }
+		if p >= x[n - 1] { return y[n - 1]; }
+
+		// Index of the interval which contains the position.
+		let i = x.partition_point(|v| *v <= p) - 1;
+		let h = x[i + 1] - x[i];
+		let t = (p - x[i]) / h;
+		match kind {
+			Interpolation::Nearest => if t * two < T::one() { y[i] } else { y[i + 1] },
+			Interpolation::Linear => y[i] + (y[i + 1] - y[i]) * t,
+			Interpolation::CubicSpline => {
+				let u = T::one() - t;
+				u * y[i] + t * y[i + 1]
+					+ ((u * u * u - u) * m[i] + (t * t * t - t) * m[i + 1]) * h * h / six
+			},
+		}
+	}).collect()
+}
+
+/// Interpolates the samples `(x, y)` at given positions. Elements of the storages are taken in row major order and the
+/// result has the shape of `positions`. See `interp1d_slice`.
+pub fn interp1d<T, X, Y, P>(x: &X, y: &Y, positions: &P, kind: Interpolation) -> ContainerRM<T, P::Rows, P::Cols>
+	where T: Scalar + Float, X: Storage<T>, Y: Storage<T>, P: Storage<T>
+{
+	let x: Vec<T> = x.as_iter().cloned().collect();
+	let y: Vec<T> = y.as_iter().cloned().collect();
+	let values: Vec<T> = positions.as_iter().cloned().collect();
+	let ret = interp1d_slice(&x, &y, &values, kind);
+	ContainerRM::from_vec(positions.size(), &ret)
+}
\ No newline at end of file
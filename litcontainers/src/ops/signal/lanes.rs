@@ -0,0 +1,57 @@
+use crate::format::*;
+use crate::storage::*;
+use crate::container::*;
+
+/// Collects the lanes of the storage along given axis. Lanes along the row axis are the rows, lanes along the col
+/// axis are the columns.
+pub fn lanes<T: Element, S: Storage<T>>(s: &S, axis: AxisType) -> Vec<Vec<T>> {
+	match axis {
+		AxisType::Row => (0..s.rows()).map(|r| s.as_row_range_iter(r).cloned().collect()).collect(),
+		AxisType::Col => (0..s.cols()).map(|c| s.as_col_range_iter(c).cloned().collect()).collect(),
+	}
+}
+
+/// Length of the lanes along given axis.
+pub fn lane_len<S: StorageSize>(s: &S, axis: AxisType) -> usize {
+	match axis {
+		AxisType::Row => s.cols(),
+		AxisType::Col => s.rows(),
+	}
+}
+
+/// Creates a container from lanes of length `len` along given axis. Inverse of `lanes`.
+pub fn from_lanes<T: Element>(lanes: &[Vec<T>], len: usize, axis: AxisType) -> ContainerRM<T, Dynamic, Dynamic> {
+	match axis {
+		AxisType::Row => {
+			let data: Vec<T> = lanes.iter().flat_map(|l| l.iter().cloned()).collect();
+			ContainerRM::from_vec(Size::new(Dynamic::new(lanes.len()), Dynamic::new(len)), &data)
+		},
+		AxisType::Col => {
+			let mut ret = ContainerRM::zeros(Size::new(Dynamic::new(len), Dynamic::new(lanes.len())));
+			for (c, lane) in lanes.iter().enumerate() {
+				for (v_out, v_in) in ret.as_col_range_iter_mut(c).zip(lane) {
+					*v_out = *v_in;
+				}
+			}
+			ret
+		},
+	}
+}
+
+/// Maps every lane along given axis to a lane of equal length. The closure receives the lane index and the lane.
+pub fn map_lanes<T, S, F>(s: &S, axis: AxisType, mut f: F) -> ContainerRM<T, S::Rows, S::Cols>
+	where T: Element, S: Storage<T>, F: FnMut(usize, &[T]) -> Vec<T>
+{
+	let mut ret = ContainerRM::zeros(s.size());
+	match axis {
+		AxisType::Row => for r in 0..s.rows() {
+			let lane: Vec<T> = s.as_row_range_iter(r).cloned().collect();
+			for (o, v) in ret.as_row_range_iter_mut(r).zip(f(r, &lane)) { *o = v; }
+		},
+		AxisType::Col => for c in 0..s.cols() {
+			let lane: Vec<T> = s.as_col_range_iter(c).cloned().collect();
+			for (o, v) in ret.as_col_range_iter_mut(c).zip(f(c, &lane)) { *o = v; }
+		},
+	}
+	ret
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+pub mod signal_element;
+pub mod lanes;
+pub(crate) mod fft;
+pub mod convolve;
+pub mod filter;
+pub mod design;
+pub mod interpolate;
+pub mod resample;
+
+pub use signal_element::*;
+pub use lanes::*;
+pub use convolve::*;
+pub use filter::*;
+pub use design::*;
+pub use interpolate::*;
+pub use resample::*;
\ No newline at end of file
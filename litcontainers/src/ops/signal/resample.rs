@@ -0,0 +1,80 @@
+use crate::format::*;
+use crate::storage::*;
+use crate::container::*;
+use super::{firwin, FilterBand, Window, lanes, lane_len, from_lanes};
+use num_traits::{Float, cast};
+
+/// Number of zero crossings of the anti-aliasing filter on each side of its center.
+pub const RESAMPLE_ZERO_CROSSINGS: usize = 10;
+/// Shape parameter of the Kaiser window used for the anti-aliasing filter.
+pub const RESAMPLE_KAISER_BETA: f64 = 5.;
+
+fn gcd(a: usize, b: usize) -> usize { if b == 0 { a } else { gcd(b, a % b) } }
+
+/// Reduces the rate conversion to a ratio `(up, down)` of coprime factors.
+pub fn resample_ratio(from_rate: usize, to_rate: usize) -> (usize, usize) {
+	assert!(from_rate > 0 && to_rate > 0, "Sample rates must be positive!");
+	let g = gcd(from_rate, to_rate);
+	(to_rate / g, from_rate / g)
+}
+
+/// Length of a signal of `len` samples after upsampling by `up` and downsampling by `down`.
+pub fn resampled_len(len: usize, up: usize, down: usize) -> usize { (len * up).div_ceil(down) }
+
+/// Resamples by a rational factor `up / down` with a polyphase windowed-sinc filter. The signal is conceptually
+/// upsampled by inserting zeros, low-pass filtered and downsampled, but only the filter taps which hit nonzero
+/// samples are evaluated. The filter delay is compensated, so the output is aligned with the input.
+pub fn resample_poly_slice<T: Float>(x: &[T], up: usize, down: usize) -> Vec<T> {
+	assert!(up > 0 && down > 0, "Resampling factors must be positive!");
+	if up == down { return x.to_vec(); }
+
+	let max_rate = up.max(down);
+	let half_len = RESAMPLE_ZERO_CROSSINGS * max_rate;
+	let taps = firwin::<f64>(2 * half_len + 1, FilterBand::LowPass(1. / max_rate as f64), Window::Kaiser(RESAMPLE_KAISER_BETA));
+	let taps: Vec<f64> = taps.as_slice().iter().map(|v| v * up as f64).collect();
+	let x: Vec<f64> = x.iter().map(|v| v.to_f64().unwrap()).collect();
+
+	(0..resampled_len(x.len(), up, down)).map(|m| {
+		// Position in the upsampled signal delayed by the filter.
+		let t = m * down + half_len;
+		let first = (t + 1).saturating_sub(taps.len()).div_ceil(up);
+		let last = (t / up).min(x.len() - 1);
+		let acc: f64 = (first..=last).map(|n| taps[t - n * up] * x[n]).sum();
+		cast(acc).unwrap()
+	}).collect()
+}
+
+/// Resamples a signal sampled at `from_rate` to `to_rate`. See `resample_poly_slice`.
+pub fn resample_slice<T: Float>(x: &[T], from_rate: usize, to_rate: usize) -> Vec<T> {
+	let (up, down) = resample_ratio(from_rate, to_rate);
+	resample_poly_slice(x, up, down)
+}
+
+/// Resamples every lane along given axis by a rational factor `up / down`.
+pub fn resample_poly<T, S, A>(s: &S, up: usize, down: usize, _: A) -> ContainerRM<T, Dynamic, Dynamic>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let len = resampled_len(lane_len(s, A::axis_type()), up, down);
+	let lanes: Vec<Vec<T>> = lanes(s, A::axis_type()).iter().map(|l| resample_poly_slice(l, up, down)).collect();
+	from_lanes(&lanes, len, A::axis_type())
+}
+
+/// Resamples every lane along given axis from `from_rate` to `to_rate`. Along `ColAxis` each column is a channel.
+pub fn resample<T, S, A>(s: &S, from_rate: usize, to_rate: usize, axis: A) -> ContainerRM<T, Dynamic, Dynamic>
+	where T: Scalar + Float, S: Storage<T>, A: Axis<S::Rows, S::Cols>
+{
+	let (up, down) = resample_ratio(from_rate, to_rate);
+	resample_poly(s, up, down, axis)
+}
+
+pub trait ResampleOperations<T: Scalar + Float>: Storage<T> {
+	fn resample<A: Axis<Self::Rows, Self::Cols>>(&self, from_rate: usize, to_rate: usize, axis: A) -> ContainerRM<T, Dynamic, Dynamic> {
+		resample(self, from_rate, to_rate, axis)
+	}
+
+	fn resample_poly<A: Axis<Self::Rows, Self::Cols>>(&self, up: usize, down: usize, axis: A) -> ContainerRM<T, Dynamic, Dynamic> {
+		resample_poly(self, up, down, axis)
+	}
+}
+
+impl<T: Scalar + Float, S: Storage<T>> ResampleOperations<T> for S {}
\ No newline at end of file
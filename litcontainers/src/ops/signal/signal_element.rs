@@ -0,0 +1,58 @@
+use crate::format::*;
+use num_complex::Complex;
+
+/// Element supported by the signal processing operations. Elements are converted to `c64` when computing in the
+/// frequency domain and sums are accumulated as `c64`. Integer results are rounded and saturated to the range of the type.
+pub trait SignalElement: NumericElement {
+	fn to_c64(self) -> c64;
+
+	fn from_c64(v: c64) -> Self;
+
+	/// Complex conjugate. Real elements are returned unchanged.
+	fn conj(self) -> Self { self }
+}
+
+macro_rules! impl_signal_element_int (
+	($($Type: ty),* $(,)*) => {$(
+		impl SignalElement for $Type {
+			fn to_c64(self) -> c64 { Complex::new(self as f64, 0.) }
+
+			fn from_c64(v: c64) -> Self {
+				let v = v.re.round();
+				if v >= <$Type>::MAX as f64 { <$Type>::MAX }
+				else if v <= <$Type>::MIN as f64 { <$Type>::MIN }
+				else { num_traits::cast(v).unwrap_or_default() }
+			}
+		}
+	)*}
+);
+
+impl_signal_element_int!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+impl SignalElement for f32 {
+	fn to_c64(self) -> c64 { Complex::new(self as f64, 0.) }
+
+	fn from_c64(v: c64) -> Self { v.re as f32 }
+}
+
+impl SignalElement for f64 {
+	fn to_c64(self) -> c64 { Complex::new(self, 0.) }
+
+	fn from_c64(v: c64) -> Self { v.re }
+}
+
+impl SignalElement for c32 {
+	fn to_c64(self) -> c64 { Complex::new(self.re as f64, self.im as f64) }
+
+	fn from_c64(v: c64) -> Self { Complex::new(v.re as f32, v.im as f32) }
+
+	fn conj(self) -> Self { Complex::conj(&self) }
+}
+
+impl SignalElement for c64 {
+	fn to_c64(self) -> c64 { self }
+
+	fn from_c64(v: c64) -> Self { v }
+
+	fn conj(self) -> Self { Complex::conj(&self) }
+}
\ No newline at end of file
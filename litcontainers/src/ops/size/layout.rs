@@ -0,0 +1,133 @@
+use crate::*;
+use std::cmp::min;
+
+/// Side of the square blocks in which elements are copied and transposed. Keeps both the source and the destination
+/// rows of a block in cache when they are far apart in memory.
+pub const LAYOUT_BLOCK_SIZE: usize = 32;
+
+/// Copies all elements of equally sized storages. Elements are visited block by block, so storages with different
+/// layouts are not traversed with long strides.
+pub fn copy_blocked<T, S, D>(src: &S, dst: &mut D)
+	where T: Element, S: Storage<T>, D: StorageMut<T>
+{
+	assert!(src.equal_size(dst), "Source and destination must be of equal size!");
+	for rb in (0..src.rows()).step_by(LAYOUT_BLOCK_SIZE) {
+		for cb in (0..src.cols()).step_by(LAYOUT_BLOCK_SIZE) {
+			for r in rb..min(rb + LAYOUT_BLOCK_SIZE, src.rows()) {
+				for c in cb..min(cb + LAYOUT_BLOCK_SIZE, src.cols()) {
+					unsafe { *dst.get_ptr_mut_unchecked(r, c) = src.get_unchecked(r, c); }
+				}
+			}
+		}
+	}
+}
+
+/// Transposes a square row major matrix of side `n` in place. Blocks above the diagonal are swapped with their mirrored
+/// blocks below it.
+pub fn transpose_square_slice<T>(data: &mut [T], n: usize) {
+	assert_eq!(data.len(), n * n, "Data length must match the size of the square matrix!");
+	for rb in (0..n).step_by(LAYOUT_BLOCK_SIZE) {
+		for cb in (rb..n).step_by(LAYOUT_BLOCK_SIZE) {
+			for r in rb..min(rb + LAYOUT_BLOCK_SIZE, n) {
+				for c in cb.max(r + 1)..min(cb + LAYOUT_BLOCK_SIZE, n) {
+					data.swap(r * n + c, c * n + r);
+				}
+			}
+		}
+	}
+}
+
+/// Transposes a row major `rows x cols` matrix in place into a row major `cols x rows` matrix. Elements are moved
+/// along the cycles of the transposition permutation, which needs one bit of extra memory per element.
+pub fn transpose_slice<T: Copy>(data: &mut [T], rows: usize, cols: usize) {
+	assert_eq!(data.len(), rows * cols, "Data length must match the size of the matrix!");
+	if rows == cols { return transpose_square_slice(data, rows); }
+	let len = data.len();
+	if rows <= 1 || cols <= 1 { return; }
+
+	// Element at index `i` moves to `i * rows mod (len - 1)`. First and last element stay in place.
+	let mut visited = vec![0u64; len.div_ceil(64)];
+	for start in 1..len - 1 {
+		if visited[start / 64] & (1 << (start % 64)) != 0 { continue; }
+		let mut value = data[start];
+		let mut cur = start;
+		loop {
+			let next = cur * rows % (len - 1);
+			std::mem::swap(&mut value, &mut data[next]);
+			visited[next / 64] |= 1 << (next % 64);
+			cur = next;
+			if cur == start { break; }
+		}
+	}
+}
+
+pub trait LayoutOperations<T: Element>: Storage<T> {
+	/// Copies the elements into a row major container.
+	fn to_row_major(&self) -> ContainerRM<T, Self::Rows, Self::Cols> {
+		let mut ret = ContainerRM::zeros(self.size());
+		copy_blocked(self, &mut ret);
+		ret
+	}
+
+	/// Copies the elements into a column major container.
+	fn to_col_major(&self) -> ContainerCM<T, Self::Rows, Self::Cols> {
+		let mut ret = ContainerCM::zeros(self.size());
+		copy_blocked(self, &mut ret);
+		ret
+	}
+
+	/// Copies the transposed elements into a row major container.
+	fn transpose_owned(&self) -> ContainerRM<T, Self::Cols, Self::Rows> {
+		self.t().to_row_major()
+	}
+}
+
+impl<T: Element, S: Storage<T>> LayoutOperations<T> for S {}
+
+impl<T, R, C> Container<T, VecStorageRM<T, R, C>>
+	where T: Element, R: Dim, C: Dim
+{
+	/// Transposes the container reusing its data. Square containers swap elements in blocks, rectangular containers
+	/// follow the cycles of the permutation.
+	pub fn transpose_inplace(self) -> ContainerRM<T, C, R> {
+		let size = self.size().transpose();
+		let mut data = self.into_storage().into_data();
+		transpose_slice(&mut data, size.cols(), size.rows());
+		VecStorageRM::from_data(size, data).into()
+	}
+}
+
+impl<T, R, C> Container<T, VecStorageCM<T, R, C>>
+	where T: Element, R: Dim, C: Dim
+{
+	/// Transposes the container reusing its data. See `ContainerRM::transpose_inplace`.
+	pub fn transpose_inplace(self) -> ContainerCM<T, C, R> {
+		let size = self.size().transpose();
+		let mut data = self.into_storage().into_data();
+		// Column major data of a `rows x cols` container is row major data of a `cols x rows` matrix.
+		transpose_slice(&mut data, size.rows(), size.cols());
+		VecStorageCM::from_data(size, data).into()
+	}
+}
+
+impl<T, D> Container<T, VecStorageRM<T, D, D>>
+	where T: Element, D: Dim
+{
+	/// Transposes a square container in place.
+	pub fn transpose_square_inplace(&mut self) {
+		assert_eq!(self.rows(), self.cols(), "Container must be square!");
+		let n = self.rows();
+		transpose_square_slice(self.as_slice_mut(), n)
+	}
+}
+
+impl<T, D> Container<T, VecStorageCM<T, D, D>>
+	where T: Element, D: Dim
+{
+	/// Transposes a square container in place.
+	pub fn transpose_square_inplace(&mut self) {
+		assert_eq!(self.rows(), self.cols(), "Container must be square!");
+		let n = self.rows();
+		transpose_square_slice(self.as_slice_mut(), n)
+	}
+}
\ No newline at end of file
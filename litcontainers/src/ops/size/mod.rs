@@ -1,5 +1,15 @@
 pub mod transmute;
 pub mod flip;
+pub mod pad;
+pub mod stack;
+pub mod reshape;
+pub mod layout;
+pub mod shuffle;
 
 pub use transmute::*;
-pub use flip::*;
\ No newline at end of file
+pub use flip::*;
+pub use pad::*;
+pub use stack::*;
+pub use reshape::*;
+pub use layout::*;
+pub use shuffle::*;
\ No newline at end of file
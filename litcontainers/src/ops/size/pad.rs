@@ -0,0 +1,141 @@
+use crate::*;
+
+/// Values of the padded elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<T> {
+	/// Pads with the given value.
+	Constant(T),
+	/// Repeats the edge element.
+	Edge,
+	/// Mirrors the input without repeating the edge element. `[1, 2, 3]` is extended to `.. 3, 2, 1, 2, 3, 2, 1 ..`.
+	Reflect,
+	/// Mirrors the input including the edge element. `[1, 2, 3]` is extended to `.. 2, 1, 1, 2, 3, 3, 2 ..`.
+	Symmetric,
+	/// Repeats the input periodically.
+	Wrap,
+}
+
+/// Maps an index of the padded axis to the input index. `None` marks the constant padding.
+fn pad_index<T>(i: isize, len: usize, mode: &PadMode<T>) -> Option<usize> {
+	if i >= 0 && (i as usize) < len { return Some(i as usize); }
+	if let PadMode::Constant(_) = mode { return None; }
+	assert!(len > 0, "Only constant padding is possible for an empty axis!");
+	let n = len as isize;
+	Some(match mode {
+		PadMode::Constant(_) => unreachable!(),
+		PadMode::Edge => i.max(0).min(n - 1) as usize,
+		PadMode::Wrap => i.rem_euclid(n) as usize,
+		PadMode::Reflect => {
+			if n == 1 { return Some(0); }
+			let i = i.rem_euclid(2 * n - 2);
+			(if i >= n { 2 * n - 2 - i } else { i }) as usize
+		},
+		PadMode::Symmetric => {
+			let i = i.rem_euclid(2 * n);
+			(if i >= n { 2 * n - 1 - i } else { i }) as usize
+		},
+	})
+}
+
+/// Creates a container of given size with the input placed at `(top, left)` and the rest filled according to mode.
+fn pad_into<T, S, R, C>(s: &S, size: Size<R, C>, top: usize, left: usize, mode: PadMode<T>) -> ContainerRM<T, R, C>
+	where T: Element, S: Storage<T>, R: Dim, C: Dim
+{
+	let cols: Vec<Option<usize>> = (0..size.cols())
+		.map(|c| pad_index(c as isize - left as isize, s.cols(), &mode))
+		.collect();
+	let mut ret = ContainerRM::zeros(size);
+	for (r, mut row) in ret.as_row_slice_iter_mut().enumerate() {
+		let ri = pad_index(r as isize - top as isize, s.rows(), &mode);
+		for (v, ci) in row.as_row_iter_mut().zip(&cols) {
+			*v = match (ri, ci, mode) {
+				(Some(ri), Some(ci), _) => s.get(ri, *ci),
+				(_, _, PadMode::Constant(value)) => value,
+				_ => unreachable!(),
+			};
+		}
+	}
+	ret
+}
+
+/// Size of the storage after padding its lanes along an axis. Padding along `RowAxis` pads every row and thus adds
+/// columns, padding along `ColAxis` adds rows. Dimensions stay static when the input and the padding are static.
+pub trait PadAxis<R: Dim, C: Dim, B: Dim, E: Dim>: Axis<R, C> {
+	type Rows: Dim;
+	type Cols: Dim;
+
+	fn padded_size(size: Size<R, C>, before: B, after: E) -> Size<Self::Rows, Self::Cols>;
+}
+
+impl<R, C, B, E> PadAxis<R, C, B, E> for RowAxis
+	where R: Dim, C: Dim + DimAdd<B>, B: Dim, E: Dim, DimSum<C, B>: DimAdd<E>
+{
+	type Rows = R;
+	type Cols = PaddedDim<C, B, E>;
+
+	fn padded_size(size: Size<R, C>, before: B, after: E) -> Size<R, Self::Cols> {
+		Size::new(size.row_dim(), size.col_dim().add(before).add(after))
+	}
+}
+
+impl<R, C, B, E> PadAxis<R, C, B, E> for ColAxis
+	where R: Dim + DimAdd<B>, C: Dim, B: Dim, E: Dim, DimSum<R, B>: DimAdd<E>
+{
+	type Rows = PaddedDim<R, B, E>;
+	type Cols = C;
+
+	fn padded_size(size: Size<R, C>, before: B, after: E) -> Size<Self::Rows, C> {
+		Size::new(size.row_dim().add(before).add(after), size.col_dim())
+	}
+}
+
+pub type PaddedDim<D, B, E> = DimSum<DimSum<D, B>, E>;
+pub type PadRows<A, R, C, B, E> = <A as PadAxis<R, C, B, E>>::Rows;
+pub type PadCols<A, R, C, B, E> = <A as PadAxis<R, C, B, E>>::Cols;
+/// Container returned by `pad` along axis `A`.
+pub type Padded<T, A, R, C, B, E> = ContainerRM<T, PadRows<A, R, C, B, E>, PadCols<A, R, C, B, E>>;
+/// Container returned by `pad2d`.
+pub type Padded2d<T, R, C, PT, PB, PL, PR> = ContainerRM<T, PaddedDim<R, PT, PB>, PaddedDim<C, PL, PR>>;
+
+/// Pads every lane along given axis with `before` elements at the start and `after` elements at the end.
+pub fn pad<T, S, B, E, A>(s: &S, before: B, after: E, _: A, mode: PadMode<T>)
+	-> Padded<T, A, S::Rows, S::Cols, B, E>
+	where T: Element, S: Storage<T>, B: Dim, E: Dim, A: PadAxis<S::Rows, S::Cols, B, E>
+{
+	let offset = before.value();
+	let size = A::padded_size(s.size(), before, after);
+	match A::axis_type() {
+		AxisType::Row => pad_into(s, size, 0, offset, mode),
+		AxisType::Col => pad_into(s, size, offset, 0, mode),
+	}
+}
+
+/// Pads the storage with `top` and `bottom` rows and `left` and `right` columns.
+pub fn pad2d<T, S, PT, PB, PL, PR>(s: &S, top: PT, bottom: PB, left: PL, right: PR, mode: PadMode<T>)
+	-> Padded2d<T, S::Rows, S::Cols, PT, PB, PL, PR>
+	where T: Element, S: Storage<T>, PT: Dim, PB: Dim, PL: Dim, PR: Dim,
+	      S::Rows: DimAdd<PT>, DimSum<S::Rows, PT>: DimAdd<PB>,
+	      S::Cols: DimAdd<PL>, DimSum<S::Cols, PL>: DimAdd<PR>
+{
+	let (top_len, left_len) = (top.value(), left.value());
+	let size = Size::new(s.row_dim().add(top).add(bottom), s.col_dim().add(left).add(right));
+	pad_into(s, size, top_len, left_len, mode)
+}
+
+pub trait PadOperations<T: Element>: Storage<T> {
+	fn pad<B: Dim, E: Dim, A: PadAxis<Self::Rows, Self::Cols, B, E>>(&self, before: B, after: E, axis: A, mode: PadMode<T>)
+		-> Padded<T, A, Self::Rows, Self::Cols, B, E>
+	{
+		pad(self, before, after, axis, mode)
+	}
+
+	fn pad2d<PT: Dim, PB: Dim, PL: Dim, PR: Dim>(&self, top: PT, bottom: PB, left: PL, right: PR, mode: PadMode<T>)
+		-> Padded2d<T, Self::Rows, Self::Cols, PT, PB, PL, PR>
+		where Self::Rows: DimAdd<PT>, DimSum<Self::Rows, PT>: DimAdd<PB>,
+		      Self::Cols: DimAdd<PL>, DimSum<Self::Cols, PL>: DimAdd<PR>
+	{
+		pad2d(self, top, bottom, left, right, mode)
+	}
+}
+
+impl<T: Element, S: Storage<T>> PadOperations<T> for S {}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use crate::*;
+
+/// Order in which elements are taken from the storage and placed into the reshaped storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+	/// Row after row, like C arrays.
+	RowMajor,
+	/// Column after column, like Fortran arrays.
+	ColMajor,
+}
+
+/// Reshaped storage. Borrows the input when it is contiguous in the requested order, otherwise holds a row major copy.
+#[derive(Debug)]
+pub enum Reshaped<'a, T, R, C>
+	where T: Element, R: Dim, C: Dim
+{
+	View(Slice<'a, T, R, Dynamic, C, Dynamic>),
+	Owned(ContainerRM<T, R, C>),
+}
+
+impl<'a, T, R, C> Reshaped<'a, T, R, C>
+	where T: Element, R: Dim, C: Dim
+{
+	pub fn is_view(&self) -> bool {
+		match self {
+			Reshaped::View(_) => true,
+			Reshaped::Owned(_) => false,
+		}
+	}
+
+	/// View of the reshaped elements. Strided column major if a column major contiguous storage was reshaped.
+	pub fn view(&self) -> Slice<'_, T, R, Dynamic, C, Dynamic> {
+		match self {
+			Reshaped::View(v) => v.into_slice(),
+			Reshaped::Owned(o) => o.transmute_stride_dims(Strides::new(Dynamic::new(o.row_stride()), Dynamic::new(1))),
+		}
+	}
+
+	/// Converts into an owned row major container. Copies only if the reshape was a view.
+	pub fn into_owned(self) -> ContainerRM<T, R, C> {
+		match self {
+			Reshaped::View(v) => v.to_row_major(),
+			Reshaped::Owned(o) => o,
+		}
+	}
+}
+
+fn check_len<S: StorageSize>(s: &S, len: usize) -> ShapeResult<()> {
+	if s.len() == len { Ok(()) } else { Err(ShapeError::LengthMismatch { expected: s.len(), actual: len }) }
+}
+
+/// Whether the elements are laid out one after another in row major order.
+pub fn is_row_major_contiguous<S: StorageSize + Strided>(s: &S) -> bool {
+	(s.cols() <= 1 || s.col_stride() == 1) && (s.rows() <= 1 || s.row_stride() == s.cols())
+}
+
+/// Whether the elements are laid out one after another in column major order.
+pub fn is_col_major_contiguous<S: StorageSize + Strided>(s: &S) -> bool {
+	(s.rows() <= 1 || s.row_stride() == 1) && (s.cols() <= 1 || s.col_stride() == s.rows())
+}
+
+pub trait ReshapeOperations<T: Element>: Storage<T> {
+	/// Reshapes the storage taking and placing the elements in given order. Returns a view when the storage is
+	/// contiguous in that order, otherwise copies. Panics if the number of elements differs.
+	fn reshape<R: Dim, C: Dim>(&self, size: Size<R, C>, order: Order) -> Reshaped<'_, T, R, C> {
+		self.try_reshape(size, order).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	fn try_reshape<R: Dim, C: Dim>(&self, size: Size<R, C>, order: Order) -> ShapeResult<Reshaped<'_, T, R, C>> {
+		check_len(&size, self.len())?;
+		match order {
+			Order::RowMajor if is_row_major_contiguous(self) => {
+				let strides = Strides::new(Dynamic::new(size.cols()), Dynamic::new(1));
+				Ok(Reshaped::View(self.try_transmute_dims(size, strides)?))
+			},
+			Order::ColMajor if is_col_major_contiguous(self) => {
+				let strides = Strides::new(Dynamic::new(1), Dynamic::new(size.rows()));
+				Ok(Reshaped::View(self.try_transmute_dims(size, strides)?))
+			},
+			Order::RowMajor => {
+				let data: Vec<T> = self.as_row_iter().cloned().collect();
+				Ok(Reshaped::Owned(ContainerRM::from_vec(size, &data)))
+			},
+			Order::ColMajor => {
+				let mut ret = ContainerRM::zeros(size);
+				for (o, v) in ret.as_col_iter_mut().zip(self.as_col_iter()) { *o = *v; }
+				Ok(Reshaped::Owned(ret))
+			},
+		}
+	}
+
+	/// Copies the elements in row major order into a row vector.
+	fn flatten(&self) -> RowVec<T, Dynamic> {
+		let data: Vec<T> = self.as_iter().cloned().collect();
+		RowVec::from_vec(Size::new(U1, Dynamic::new(data.len())), &data)
+	}
+
+	/// Elements in row major order as a row vector. Unlike `flatten` it doesn't copy contiguous storages.
+	fn ravel(&self) -> Reshaped<'_, T, U1, Dynamic> {
+		self.reshape(Size::new(U1, Dynamic::new(self.len())), Order::RowMajor)
+	}
+}
+
+impl<T: Element, S: Storage<T>> ReshapeOperations<T> for S {}
+
+impl<T, R, C> Container<T, VecStorageRM<T, R, C>>
+	where T: Element, R: Dim, C: Dim
+{
+	/// Reshapes the container reusing its data without reallocation. Elements are taken and placed in given order,
+	/// so the result matches `reshape`. Column major order transposes the data in place twice.
+	pub fn into_reshaped<R2: Dim, C2: Dim>(self, size: Size<R2, C2>, order: Order) -> ContainerRM<T, R2, C2> {
+		check_len(&size, self.len()).unwrap_or_else(|e| panic!("{}", e));
+		let (rows, cols) = (self.rows(), self.cols());
+		let mut data = self.into_storage().into_data();
+		if order == Order::ColMajor {
+			// Column major elements of the input are the column major elements of the output
+			transpose_slice(&mut data, rows, cols);
+			transpose_slice(&mut data, size.cols(), size.rows());
+		}
+		VecStorageRM::from_data(size, data).into()
+	}
+}
+
+impl<T, R, C> Container<T, VecStorageCM<T, R, C>>
+	where T: Element, R: Dim, C: Dim
+{
+	/// Reshapes the container reusing its data without reallocation. See `ContainerRM::into_reshaped`, here row major
+	/// order transposes the data in place twice.
+	pub fn into_reshaped<R2: Dim, C2: Dim>(self, size: Size<R2, C2>, order: Order) -> ContainerCM<T, R2, C2> {
+		check_len(&size, self.len()).unwrap_or_else(|e| panic!("{}", e));
+		let (rows, cols) = (self.rows(), self.cols());
+		let mut data = self.into_storage().into_data();
+		if order == Order::RowMajor {
+			// Column major data of a `rows x cols` container is row major data of a `cols x rows` matrix
+			transpose_slice(&mut data, cols, rows);
+			transpose_slice(&mut data, size.rows(), size.cols());
+		}
+		VecStorageCM::from_data(size, data).into()
+	}
+}
\ No newline at end of file
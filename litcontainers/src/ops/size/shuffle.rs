@@ -0,0 +1,82 @@
+use crate::{Element, Storage, StorageMut, Container};
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// Returns the indices `0..n` in random order.
+pub fn permutation<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Vec<usize> {
+	let mut ret: Vec<usize> = (0..n).collect();
+	ret.shuffle(rng);
+	ret
+}
+
+pub trait Permute<T: Element>: Storage<T> {
+	/// Returns a copy with row `i` taken from row `order[i]`.
+	fn permute_rows(&self, order: &[usize]) -> Container<T, Self::OwnedType> {
+		assert_eq!(order.len(), self.rows(), "Permutation must contain an index for each row!");
+		let mut ret = self.clone_owned();
+		for (r, &src) in order.iter().enumerate() {
+			for (o, i) in ret.as_row_range_iter_mut(r).zip(self.as_row_range_iter(src)) { *o = *i; }
+		}
+		ret
+	}
+
+	/// Returns a copy with col `i` taken from col `order[i]`.
+	fn permute_cols(&self, order: &[usize]) -> Container<T, Self::OwnedType> {
+		assert_eq!(order.len(), self.cols(), "Permutation must contain an index for each col!");
+		let mut ret = self.clone_owned();
+		for (c, &src) in order.iter().enumerate() {
+			for (o, i) in ret.as_col_range_iter_mut(c).zip(self.as_col_range_iter(src)) { *o = *i; }
+		}
+		ret
+	}
+
+	/// Returns a copy with rows in random order.
+	fn permutation_rows<R: Rng + ?Sized>(&self, rng: &mut R) -> Container<T, Self::OwnedType> {
+		self.permute_rows(&permutation(self.rows(), rng))
+	}
+
+	/// Returns a copy with cols in random order.
+	fn permutation_cols<R: Rng + ?Sized>(&self, rng: &mut R) -> Container<T, Self::OwnedType> {
+		self.permute_cols(&permutation(self.cols(), rng))
+	}
+}
+
+impl<T: Element, S: Storage<T>> Permute<T> for S {}
+
+pub trait Shuffle<T: Element>: StorageMut<T> {
+	fn swap_rows(&mut self, a: usize, b: usize) {
+		if a == b { return; }
+		for c in 0..self.cols() {
+			let tmp = self.get(a, c);
+			*self.get_mut(a, c) = self.get(b, c);
+			*self.get_mut(b, c) = tmp;
+		}
+	}
+
+	fn swap_cols(&mut self, a: usize, b: usize) {
+		if a == b { return; }
+		for r in 0..self.rows() {
+			let tmp = self.get(r, a);
+			*self.get_mut(r, a) = self.get(r, b);
+			*self.get_mut(r, b) = tmp;
+		}
+	}
+
+	/// Shuffles the rows in place.
+	fn shuffle_rows<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+		for i in (1..self.rows()).rev() {
+			let j = rng.gen_range(0, i + 1);
+			self.swap_rows(i, j);
+		}
+	}
+
+	/// Shuffles the cols in place.
+	fn shuffle_cols<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+		for i in (1..self.cols()).rev() {
+			let j = rng.gen_range(0, i + 1);
+			self.swap_cols(i, j);
+		}
+	}
+}
+
+impl<T: Element, S: StorageMut<T>> Shuffle<T> for S {}
\ No newline at end of file
@@ -0,0 +1,152 @@
+use crate::*;
+
+/// Output of `hstack` joining storages of size `A` and `B`.
+pub type HStacked<T, A, B> =
+	ContainerRM<T, <A as StorageSize>::Rows, DimSum<<A as StorageSize>::Cols, <B as StorageSize>::Cols>>;
+
+/// Output of `vstack` joining storages of size `A` and `B`.
+pub type VStacked<T, A, B> =
+	ContainerRM<T, DimSum<<A as StorageSize>::Rows, <B as StorageSize>::Rows>, <A as StorageSize>::Cols>;
+
+/// Joins two storages horizontally. Both must have the same number of rows. Output columns are the sum of the input
+/// columns, which stays static when both inputs have static columns. Use `hstack!` to join more than two storages.
+pub fn hstack<T, SA, SB>(a: &SA, b: &SB) -> HStacked<T, SA, SB>
+	where T: Element, SA: Storage<T>, SB: Storage<T>, SA::Cols: DimAdd<SB::Cols>
+{
+	try_hstack(a, b).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_hstack<T, SA, SB>(a: &SA, b: &SB) -> ShapeResult<HStacked<T, SA, SB>>
+	where T: Element, SA: Storage<T>, SB: Storage<T>, SA::Cols: DimAdd<SB::Cols>
+{
+	ShapeError::check_join(AxisType::Row, a.rows(), b.rows())?;
+	let mut ret = ContainerRM::zeros(Size::new(a.row_dim(), a.col_dim().add(b.col_dim())));
+	for (r, mut row) in ret.as_row_slice_iter_mut().enumerate() {
+		for (o, v) in row.as_row_iter_mut().zip(a.as_row_range_iter(r).chain(b.as_row_range_iter(r))) {
+			*o = *v;
+		}
+	}
+	Ok(ret)
+}
+
+/// Joins two storages vertically. Both must have the same number of columns. Output rows are the sum of the input
+/// rows, which stays static when both inputs have static rows. Use `vstack!` to join more than two storages.
+pub fn vstack<T, SA, SB>(a: &SA, b: &SB) -> VStacked<T, SA, SB>
+	where T: Element, SA: Storage<T>, SB: Storage<T>, SA::Rows: DimAdd<SB::Rows>
+{
+	try_vstack(a, b).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_vstack<T, SA, SB>(a: &SA, b: &SB) -> ShapeResult<VStacked<T, SA, SB>>
+	where T: Element, SA: Storage<T>, SB: Storage<T>, SA::Rows: DimAdd<SB::Rows>
+{
+	ShapeError::check_join(AxisType::Col, a.cols(), b.cols())?;
+	let mut ret = ContainerRM::zeros(Size::new(a.row_dim().add(b.row_dim()), a.col_dim()));
+	for (o, v) in ret.as_iter_mut().zip(a.as_iter().chain(b.as_iter())) {
+		*o = *v;
+	}
+	Ok(ret)
+}
+
+/// Object safe view of a storage. Allows joining a list of storages of different types and layouts.
+pub trait DynStorage<T: Element> {
+	fn dyn_rows(&self) -> usize;
+
+	fn dyn_cols(&self) -> usize;
+
+	fn dyn_get(&self, row: usize, col: usize) -> T;
+}
+
+impl<T: Element, S: Storage<T>> DynStorage<T> for S {
+	fn dyn_rows(&self) -> usize { self.rows() }
+
+	fn dyn_cols(&self) -> usize { self.cols() }
+
+	fn dyn_get(&self, row: usize, col: usize) -> T { self.get(row, col) }
+}
+
+/// Concatenates a runtime number of storages. Along `RowAxis` the rows are extended like in `hstack`, along `ColAxis`
+/// the columns are extended like in `vstack`.
+pub fn concat<T: Element, A: Axis<Dynamic, Dynamic>>(parts: &[&dyn DynStorage<T>], axis: A) -> ContainerRM<T, Dynamic, Dynamic> {
+	try_concat(parts, axis).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_concat<T: Element, A: Axis<Dynamic, Dynamic>>(parts: &[&dyn DynStorage<T>], _: A)
+	-> ShapeResult<ContainerRM<T, Dynamic, Dynamic>>
+{
+	let first = parts.first().ok_or(ShapeError::EmptyJoin)?;
+	let (rows, cols) = match A::axis_type() {
+		AxisType::Row => {
+			for p in parts { ShapeError::check_join(AxisType::Row, first.dyn_rows(), p.dyn_rows())?; }
+			(first.dyn_rows(), parts.iter().map(|p| p.dyn_cols()).sum())
+		},
+		AxisType::Col => {
+			for p in parts { ShapeError::check_join(AxisType::Col, first.dyn_cols(), p.dyn_cols())?; }
+			(parts.iter().map(|p| p.dyn_rows()).sum(), first.dyn_cols())
+		},
+	};
+
+	let mut ret = ContainerRM::zeros(Size::new(Dynamic::new(rows), Dynamic::new(cols)));
+	let mut offset = 0;
+	for p in parts {
+		for r in 0..p.dyn_rows() {
+			for c in 0..p.dyn_cols() {
+				let (ro, co) = match A::axis_type() {
+					AxisType::Row => (r, offset + c),
+					AxisType::Col => (offset + r, c),
+				};
+				*ret.get_mut(ro, co) = p.dyn_get(r, c);
+			}
+		}
+		offset += match A::axis_type() {
+			AxisType::Row => p.dyn_cols(),
+			AxisType::Col => p.dyn_rows(),
+		};
+	}
+	Ok(ret)
+}
+
+/// Builds a container whose rows are the given row vectors.
+pub fn stack<T: Element, S: RowVecStorage<T>>(rows: &[S]) -> ContainerRM<T, Dynamic, S::Cols> {
+	try_stack(rows).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn try_stack<T: Element, S: RowVecStorage<T>>(rows: &[S]) -> ShapeResult<ContainerRM<T, Dynamic, S::Cols>> {
+	let first = rows.first().ok_or(ShapeError::EmptyJoin)?;
+	for r in rows { ShapeError::check_join(AxisType::Col, first.cols(), r.cols())?; }
+	let mut ret = ContainerRM::zeros(Size::new(Dynamic::new(rows.len()), first.col_dim()));
+	for (mut out, row) in ret.as_row_slice_iter_mut().zip(rows) {
+		for (o, v) in out.as_row_iter_mut().zip(row.as_iter()) {
+			*o = *v;
+		}
+	}
+	Ok(ret)
+}
+
+pub trait JoinOperations<T: Element>: Storage<T> {
+	fn hstack<S: Storage<T>>(&self, other: &S) -> HStacked<T, Self, S>
+		where Self::Cols: DimAdd<S::Cols>
+	{
+		hstack(self, other)
+	}
+
+	fn try_hstack<S: Storage<T>>(&self, other: &S) -> ShapeResult<HStacked<T, Self, S>>
+		where Self::Cols: DimAdd<S::Cols>
+	{
+		try_hstack(self, other)
+	}
+
+	fn vstack<S: Storage<T>>(&self, other: &S) -> VStacked<T, Self, S>
+		where Self::Rows: DimAdd<S::Rows>
+	{
+		vstack(self, other)
+	}
+
+	fn try_vstack<S: Storage<T>>(&self, other: &S) -> ShapeResult<VStacked<T, Self, S>>
+		where Self::Rows: DimAdd<S::Rows>
+	{
+		try_vstack(self, other)
+	}
+}
+
+impl<T: Element, S: Storage<T>> JoinOperations<T> for S {}
\ No newline at end of file
@@ -1,4 +1,33 @@
-use crate::{Storage, Element, Slice, PtrStorage, Strided, StorageSize, SliceBase, PtrStorageMut, StorageMut, SliceMut, Dim};
+use crate::{Storage, Element, Slice, ShapedSlice, ShapedSliceMut, PtrStorage, Strided, StorageSize, SliceBase, PtrStorageMut, StorageMut, SliceMut, Dim, ShapeError, ShapeResult};
+
+/// Checks whether the size and strides address only elements within storage of given length.
+pub(crate) fn check_transmute<SZ: StorageSize, ST: Strided>(size: &SZ, stride: &ST, len: usize) -> ShapeResult<()> {
+	if size.rows() == 0 || size.cols() == 0 { return Ok(()); }
+	let required = (size.rows() - 1).checked_mul(stride.row_stride())
+		.and_then(|r| (size.cols() - 1).checked_mul(stride.col_stride()).and_then(|c| r.checked_add(c)))
+		.and_then(|o| o.checked_add(1));
+	match required {
+		Some(required) if required <= len => Ok(()),
+		// Overflowing offsets can never fit, report the largest representable requirement
+		Some(required) => Err(ShapeError::TransmuteOutOfBounds { required, len }),
+		None => Err(ShapeError::TransmuteOutOfBounds { required: usize::MAX, len }),
+	}
+}
+
+/// Checks whether the size and strides address every element at most once, so a mutable view never aliases itself.
+/// Axes are ordered by stride and each must step over all elements of the smaller one. Length-1 axes are ignored.
+pub(crate) fn check_unique_strides<SZ: StorageSize, ST: Strided>(size: &SZ, stride: &ST) -> ShapeResult<()> {
+	let error = || ShapeError::OverlappingStrides { row_stride: stride.row_stride(), col_stride: stride.col_stride() };
+	if size.rows() == 0 || size.cols() == 0 { return Ok(()); }
+	let mut axes = [(size.rows(), stride.row_stride()), (size.cols(), stride.col_stride())];
+	axes.sort_by_key(|(_, stride)| *stride);
+	let mut extent = 1;
+	for (len, stride) in axes.iter().cloned().filter(|(len, _)| *len > 1) {
+		if stride < extent { return Err(error()); }
+		extent = stride.checked_mul(len).ok_or_else(error)?;
+	}
+	Ok(())
+}
 
 pub trait Transposable<T: Element>: Storage<T>
 {
@@ -13,11 +42,16 @@ pub trait Transposable<T: Element>: Storage<T>
 	}
 
 	fn transmute_dims<SZ: StorageSize, ST: Strided>(&self, size: SZ, stride: ST)
-		-> Slice<T, SZ::Rows, ST::RowStride, SZ::Cols, ST::ColStride>
+		-> ShapedSlice<T, SZ, ST>
 	{
-		let new_size = (size.rows() - 1) * stride.row_stride() + (size.cols() - 1) * stride.col_stride();
-		assert!(new_size < self.len(), "Transmute is out of bounds!");
-		SliceBase::new(unsafe { PtrStorage::new(self.as_ptr(), size.size(), stride.strides()) }).into()
+		self.try_transmute_dims(size, stride).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	fn try_transmute_dims<SZ: StorageSize, ST: Strided>(&self, size: SZ, stride: ST)
+		-> ShapeResult<ShapedSlice<'_, T, SZ, ST>>
+	{
+		check_transmute(&size, &stride, self.len())?;
+		Ok(SliceBase::new(unsafe { PtrStorage::new(self.as_ptr(), size.size(), stride.strides()) }).into())
 	}
 
 	fn transmute_stride_dims<ST: Strided>(&self, stride: ST)
@@ -40,11 +74,17 @@ pub trait TransposableMut<T: Element>: StorageMut<T>
 	}
 
 	fn transmute_dims_mut<SZ: StorageSize, ST: Strided>(&mut self, size: SZ, stride: ST)
-		-> SliceMut<T, SZ::Rows, ST::RowStride, SZ::Cols, ST::ColStride>
+		-> ShapedSliceMut<T, SZ, ST>
+	{
+		self.try_transmute_dims_mut(size, stride).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	fn try_transmute_dims_mut<SZ: StorageSize, ST: Strided>(&mut self, size: SZ, stride: ST)
+		-> ShapeResult<ShapedSliceMut<'_, T, SZ, ST>>
 	{
-		let new_size = (size.rows() - 1) * stride.row_stride() + (size.cols() - 1) * stride.col_stride();
-		assert!(new_size < self.len(), "Transmute is out of bounds!");
-		SliceBase::new(unsafe { PtrStorageMut::new(self.as_ptr_mut(), size.size(), stride.strides()) }).into()
+		check_transmute(&size, &stride, self.len())?;
+		check_unique_strides(&size, &stride)?;
+		Ok(SliceBase::new(unsafe { PtrStorageMut::new(self.as_ptr_mut(), size.size(), stride.strides()) }).into())
 	}
 
 	fn transmute_stride_dims_mut<ST: Strided>(&mut self, stride: ST)
@@ -57,11 +97,16 @@ impl<T: Element, S: StorageMut<T>> TransposableMut<T> for S {}
 pub trait TransposableOwned<'a, T: Element>: Storage<T>
 {
 	fn transmute_dims_inplace<SZ: StorageSize, ST: Strided>(self, size: SZ, stride: ST)
-		-> Slice<'a, T, SZ::Rows, ST::RowStride, SZ::Cols, ST::ColStride>
+		-> ShapedSlice<'a, T, SZ, ST>
+	{
+		self.try_transmute_dims_inplace(size, stride).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	fn try_transmute_dims_inplace<SZ: StorageSize, ST: Strided>(self, size: SZ, stride: ST)
+		-> ShapeResult<ShapedSlice<'a, T, SZ, ST>>
 	{
-		let new_size = (size.rows() - 1) * stride.row_stride() + (size.cols() - 1) * stride.col_stride();
-		assert!(new_size < self.len(), "Transmute is out of bounds!");
-		SliceBase::new(unsafe { PtrStorage::new(self.as_ptr(), size.size(), stride.strides()) }).into()
+		check_transmute(&size, &stride, self.len())?;
+		Ok(SliceBase::new(unsafe { PtrStorage::new(self.as_ptr(), size.size(), stride.strides()) }).into())
 	}
 
 	fn transmute_stride_dims_inplace<ST: Strided>(self, stride: ST)
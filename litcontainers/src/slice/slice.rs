@@ -1,7 +1,7 @@
 use crate::format::*;
 use crate::storage::*;
 use std::marker::PhantomData;
-use crate::Container;
+use crate::{Container, ContainerOwnedRM, ShapeResult, check_transmute, check_unique_strides, SliceRange};
 use std::ops::{Index, IndexMut};
 
 
@@ -10,6 +10,27 @@ pub type Slice<'a, T, R, RS, C, CS> = Container<T, SliceBase<'a, T, PtrStorage<'
 /// Slice containing mutable references to scalar values.
 pub type SliceMut<'a, T, R, RS, C, CS> = Container<T, SliceBase<'a, T, PtrStorageMut<'a, T, R, RS, C, CS>>>;
 
+/// Slice over all of storage `S`.
+pub type SSlice<'a, T, S> = Slice<'a, T, <S as StorageSize>::Rows, <S as Strided>::RowStride, <S as StorageSize>::Cols, <S as Strided>::ColStride>;
+/// Mutable slice over all of storage `S`.
+pub type SSliceMut<'a, T, S> = SliceMut<'a, T, <S as StorageSize>::Rows, <S as Strided>::RowStride, <S as StorageSize>::Cols, <S as Strided>::ColStride>;
+/// Slice of storage `S` selecting range `R` along axis `A`.
+pub type AxisSlice<'a, T, S, A, R> = Slice<'a, T,
+	<A as AxisSelector<<R as SliceRange>::Size, <S as StorageSize>::Rows>>::Result, <S as Strided>::RowStride,
+	<A as AxisSelector<<S as StorageSize>::Cols, <R as SliceRange>::Size>>::Result, <S as Strided>::ColStride>;
+/// Mutable slice of storage `S` selecting range `R` along axis `A`.
+pub type AxisSliceMut<'a, T, S, A, R> = SliceMut<'a, T,
+	<A as AxisSelector<<R as SliceRange>::Size, <S as StorageSize>::Rows>>::Result, <S as Strided>::RowStride,
+	<A as AxisSelector<<S as StorageSize>::Cols, <R as SliceRange>::Size>>::Result, <S as Strided>::ColStride>;
+/// Slice of storage `S` selecting rows `RR` and cols `CR`.
+pub type RangeSlice<'a, T, S, RR, CR> = Slice<'a, T, <RR as SliceRange>::Size, <S as Strided>::RowStride, <CR as SliceRange>::Size, <S as Strided>::ColStride>;
+/// Mutable slice of storage `S` selecting rows `RR` and cols `CR`.
+pub type RangeSliceMut<'a, T, S, RR, CR> = SliceMut<'a, T, <RR as SliceRange>::Size, <S as Strided>::RowStride, <CR as SliceRange>::Size, <S as Strided>::ColStride>;
+/// Slice with the size of `SZ` and strides of `ST`.
+pub type ShapedSlice<'a, T, SZ, ST> = Slice<'a, T, <SZ as StorageSize>::Rows, <ST as Strided>::RowStride, <SZ as StorageSize>::Cols, <ST as Strided>::ColStride>;
+/// Mutable slice with the size of `SZ` and strides of `ST`.
+pub type ShapedSliceMut<'a, T, SZ, ST> = SliceMut<'a, T, <SZ as StorageSize>::Rows, <ST as Strided>::RowStride, <SZ as StorageSize>::Cols, <ST as Strided>::ColStride>;
+
 /// Container containing references to scalar values.
 #[repr(C)]
 #[derive(Debug, Storage, StorageSize, Strided, Ownable, new)]
@@ -54,9 +75,30 @@ impl<'a, T, S> IndexMut<usize> for SliceBase<'a, T, S>
 	fn index_mut(&mut self, index: usize) -> &mut Self::Output { self.storage_mut().index_mut(index) }
 }
 
+impl<'a, T, S, SO> PartialEq<SO> for SliceBase<'a, T, S>
+	where T: Element + PartialEq, S: Storage<T>, SO: Storage<T>
+{
+	fn eq(&self, other: &SO) -> bool { self.equals(other) }
+}
+
+impl<'a, T, S> Eq for SliceBase<'a, T, S>
+	where T: Element + Eq, S: Storage<T>
+{}
+
 impl<'a, T, R, RS, C, CS> Slice<'a, T, R, RS, C, CS>
 	where T: Element, R: Dim, RS: Dim, C: Dim, CS: Dim
 {
+	/// Creates a slice viewing external data with given size and strides without copying.
+	/// Panics if the strided elements do not fit into the data.
+	pub fn from_slice(data: &'a [T], size: Size<R, C>, strides: Strides<RS, CS>) -> Self {
+		Self::try_from_slice(data, size, strides).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	pub fn try_from_slice(data: &'a [T], size: Size<R, C>, strides: Strides<RS, CS>) -> ShapeResult<Self> {
+		check_transmute(&size, &strides, data.len())?;
+		Ok(SliceBase::new(unsafe { PtrStorage::new(data.as_ptr(), size, strides) }).into())
+	}
+
 	pub fn split_at_row<P: Dim>(self, pos: P)
 		-> (
 			Slice<'a, T, P, RS, C, CS>,
@@ -80,9 +122,31 @@ impl<'a, T, R, RS, C, CS> Slice<'a, T, R, RS, C, CS>
 	}
 }
 
+impl<'a, T, R, RS, C, CS> Slice<'a, T, R, RS, C, CS>
+	where T: Element, R: OwnedDims<C>, RS: Dim, C: Dim, CS: Dim
+{
+	/// Copies the slice into a row major container which keeps small static sizes inline. Takes precedence over
+	/// `Ownable::owned`, which is available for any dimensions and always allocates.
+	pub fn owned(self) -> ContainerOwnedRM<T, R, C> { clone_owned_rm(&self) }
+
+	pub fn clone_owned(&self) -> ContainerOwnedRM<T, R, C> { clone_owned_rm(self) }
+}
+
 impl<'a, T, R, RS, C, CS> SliceMut<'a, T, R, RS, C, CS>
 	where T: Element, R: Dim, RS: Dim, C: Dim, CS: Dim
 {
+	/// Creates a mutable slice viewing external data with given size and strides without copying.
+	/// Panics if the strided elements do not fit into the data or if the strides overlap.
+	pub fn from_slice_mut(data: &'a mut [T], size: Size<R, C>, strides: Strides<RS, CS>) -> Self {
+		Self::try_from_slice_mut(data, size, strides).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	pub fn try_from_slice_mut(data: &'a mut [T], size: Size<R, C>, strides: Strides<RS, CS>) -> ShapeResult<Self> {
+		check_transmute(&size, &strides, data.len())?;
+		check_unique_strides(&size, &strides)?;
+		Ok(SliceBase::new(unsafe { PtrStorageMut::new(data.as_mut_ptr(), size, strides) }).into())
+	}
+
 	pub fn split_at_row<P: Dim>(self, pos: P)
 		-> (
 			SliceMut<'a, T, P, RS, C, CS>,
@@ -104,4 +168,14 @@ impl<'a, T, R, RS, C, CS> SliceMut<'a, T, R, RS, C, CS>
 		let (l, r) = self.into_storage().into_storage().split_at_col(pos);
 		(l.into(), r.into())
 	}
+}
+
+impl<'a, T, R, RS, C, CS> SliceMut<'a, T, R, RS, C, CS>
+	where T: Element, R: OwnedDims<C>, RS: Dim, C: Dim, CS: Dim
+{
+	/// Copies the slice into a row major container which keeps small static sizes inline. Takes precedence over
+	/// `Ownable::owned`, which is available for any dimensions and always allocates.
+	pub fn owned(self) -> ContainerOwnedRM<T, R, C> { clone_owned_rm(&self) }
+
+	pub fn clone_owned(&self) -> ContainerOwnedRM<T, R, C> { clone_owned_rm(self) }
 }
\ No newline at end of file
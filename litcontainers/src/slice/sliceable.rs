@@ -1,22 +1,34 @@
 use crate::format::*;
 use crate::storage::*;
-use crate::{Slice, SliceRange, SliceMut};
+use crate::{SliceRange, AxisSlice, AxisSliceMut, RangeSlice, RangeSliceMut, SSlice, SSliceMut};
 
 pub trait Sliceable<T: Element>: Storage<T> {
 	#[inline]
-	fn slice_axis<A, R>(&self, range: R, _: A)
-		-> Slice<T, <A as AxisSelector<R::Size, Self::Rows>>::Result, Self::RowStride, <A as AxisSelector<Self::Cols, R::Size>>::Result, Self::ColStride>
+	fn slice_axis<A, R>(&self, range: R, axis: A)
+		-> AxisSlice<T, Self, A, R>
 		where A: Axis<Self::Rows, Self::Cols> + AxisSelector<R::Size, Self::Rows> + AxisSelector<Self::Cols, R::Size>,
 		      <A as AxisSelector<R::Size, Self::Rows>>::Result: Dim,
 		      <A as AxisSelector<Self::Cols, R::Size>>::Result: Dim,
 		      R: SliceRange,
 	{
-		assert!(range.end() <= self.size().get_axis_size::<A>().value(), "Range is out of bounds!");
-		unsafe {
+		self.try_slice_axis(range, axis).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Slices the storage along given axis. Fails if the range is out of bounds.
+	#[inline]
+	fn try_slice_axis<A, R>(&self, range: R, _: A)
+		-> ShapeResult<AxisSlice<'_, T, Self, A, R>>
+		where A: Axis<Self::Rows, Self::Cols> + AxisSelector<R::Size, Self::Rows> + AxisSelector<Self::Cols, R::Size>,
+		      <A as AxisSelector<R::Size, Self::Rows>>::Result: Dim,
+		      <A as AxisSelector<Self::Cols, R::Size>>::Result: Dim,
+		      R: SliceRange,
+	{
+		ShapeError::check_range(A::axis_type(), range.begin(), range.end(), self.size().get_axis_size::<A>().value())?;
+		Ok(unsafe {
 			PtrStorage::new(
 				match A::axis_type() {
-					AxisType::Row => self.as_row_ptr(range.begin()),
-					AxisType::Col => self.as_col_ptr(range.begin()),
+					AxisType::Row => self.as_row_ptr_unchecked(range.begin()),
+					AxisType::Col => self.as_col_ptr_unchecked(range.begin()),
 				},
 				Size::new(
 					<A as AxisSelector<R::Size, Self::Rows>>::select(range.size(), self.row_dim()),
@@ -24,35 +36,54 @@ pub trait Sliceable<T: Element>: Storage<T> {
 				),
 				self.strides()
 			).into()
-		}
+		})
 	}
 
 	#[inline]
 	fn slice_rows<R: SliceRange>(&self, range: R)
-		-> Slice<T, R::Size, Self::RowStride, Self::Cols, Self::ColStride>
+		-> AxisSlice<T, Self, RowAxis, R>
 	{ self.slice_axis(range, RowAxis) }
 
+	#[inline]
+	fn try_slice_rows<R: SliceRange>(&self, range: R)
+		-> ShapeResult<AxisSlice<'_, T, Self, RowAxis, R>>
+	{ self.try_slice_axis(range, RowAxis) }
+
 	#[inline]
 	fn slice_cols<R: SliceRange>(&self, range: R)
-		-> Slice<T, Self::Rows, Self::RowStride, R::Size, Self::ColStride>
+		-> AxisSlice<T, Self, ColAxis, R>
 	{ self.slice_axis(range, ColAxis) }
 
+	#[inline]
+	fn try_slice_cols<R: SliceRange>(&self, range: R)
+		-> ShapeResult<AxisSlice<'_, T, Self, ColAxis, R>>
+	{ self.try_slice_axis(range, ColAxis) }
+
 
 	#[inline]
 	fn slice<RR: SliceRange, CR: SliceRange>(&self, range_rows: RR, range_cols: CR)
-		-> Slice<T, RR::Size, Self::RowStride, CR::Size, Self::ColStride>
+		-> RangeSlice<T, Self, RR, CR>
 	{
-		assert!(range_cols.end() <= self.cols() && range_rows.end() <= self.rows(), "Range is out of bounds!");
-		unsafe {
+		self.try_slice(range_rows, range_cols).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Slices the storage along both axes. Fails if any of the ranges is out of bounds.
+	#[inline]
+	fn try_slice<RR: SliceRange, CR: SliceRange>(&self, range_rows: RR, range_cols: CR)
+		-> ShapeResult<RangeSlice<'_, T, Self, RR, CR>>
+	{
+		ShapeError::check_range(AxisType::Row, range_rows.begin(), range_rows.end(), self.rows())?;
+		ShapeError::check_range(AxisType::Col, range_cols.begin(), range_cols.end(), self.cols())?;
+		Ok(unsafe {
 			PtrStorage::new(
-				self.get_ptr(range_rows.begin(), range_cols.begin()),
+				self.get_ptr_unchecked(range_rows.begin(), range_cols.begin()),
 				Size::new(range_rows.size(), range_cols.size()),
 				self.strides()
 			).into()
-		}
+		})
 	}
 
-	fn into_slice(&self) -> Slice<T, Self::Rows, Self::RowStride, Self::Cols, Self::ColStride> {
+	fn into_slice(&self) -> SSlice<T, Self> {
 		unsafe {
 			PtrStorage::new(
 				self.as_ptr(),
@@ -65,19 +96,31 @@ pub trait Sliceable<T: Element>: Storage<T> {
 
 pub trait SliceableMut<T: Element>: StorageMut<T> {
 	#[inline]
-	fn slice_axis_mut<A, R>(&mut self, range: R, _: A)
-		-> SliceMut<T, <A as AxisSelector<R::Size, Self::Rows>>::Result, Self::RowStride, <A as AxisSelector<Self::Cols, R::Size>>::Result, Self::ColStride>
+	fn slice_axis_mut<A, R>(&mut self, range: R, axis: A)
+		-> AxisSliceMut<T, Self, A, R>
 		where A: Axis<Self::Rows, Self::Cols> + AxisSelector<R::Size, Self::Rows> + AxisSelector<Self::Cols, R::Size>,
 		      <A as AxisSelector<R::Size, Self::Rows>>::Result: Dim,
 		      <A as AxisSelector<Self::Cols, R::Size>>::Result: Dim,
 		      R: SliceRange,
 	{
-		assert!(range.end() <= self.size().get_axis_size::<A>().value(), "Range is out of bounds!");
-		unsafe {
+		self.try_slice_axis_mut(range, axis).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Slices the storage along given axis. Fails if the range is out of bounds.
+	#[inline]
+	fn try_slice_axis_mut<A, R>(&mut self, range: R, _: A)
+		-> ShapeResult<AxisSliceMut<'_, T, Self, A, R>>
+		where A: Axis<Self::Rows, Self::Cols> + AxisSelector<R::Size, Self::Rows> + AxisSelector<Self::Cols, R::Size>,
+		      <A as AxisSelector<R::Size, Self::Rows>>::Result: Dim,
+		      <A as AxisSelector<Self::Cols, R::Size>>::Result: Dim,
+		      R: SliceRange,
+	{
+		ShapeError::check_range(A::axis_type(), range.begin(), range.end(), self.size().get_axis_size::<A>().value())?;
+		Ok(unsafe {
 			PtrStorageMut::new(
 				match A::axis_type() {
-					AxisType::Row => self.as_row_ptr_mut(range.begin()),
-					AxisType::Col => self.as_col_ptr_mut(range.begin()),
+					AxisType::Row => self.as_row_ptr_mut_unchecked(range.begin()),
+					AxisType::Col => self.as_col_ptr_mut_unchecked(range.begin()),
 				},
 				Size::new(
 					<A as AxisSelector<R::Size, Self::Rows>>::select(range.size(), self.row_dim()),
@@ -85,38 +128,57 @@ pub trait SliceableMut<T: Element>: StorageMut<T> {
 				),
 				self.strides()
 			).into()
-		}
+		})
 	}
 
 	#[inline]
 	fn slice_rows_mut<R: SliceRange>(&mut self, range: R)
-		-> SliceMut<T, R::Size, Self::RowStride, Self::Cols, Self::ColStride>
+		-> AxisSliceMut<T, Self, RowAxis, R>
 	{ self.slice_axis_mut(range, RowAxis) }
 
+	#[inline]
+	fn try_slice_rows_mut<R: SliceRange>(&mut self, range: R)
+		-> ShapeResult<AxisSliceMut<'_, T, Self, RowAxis, R>>
+	{ self.try_slice_axis_mut(range, RowAxis) }
+
 
 	#[inline]
 	fn slice_cols_mut<R: SliceRange>(&mut self, range: R)
-		-> SliceMut<T, Self::Rows, Self::RowStride, R::Size, Self::ColStride>
+		-> AxisSliceMut<T, Self, ColAxis, R>
 	{ self.slice_axis_mut(range, ColAxis) }
 
+	#[inline]
+	fn try_slice_cols_mut<R: SliceRange>(&mut self, range: R)
+		-> ShapeResult<AxisSliceMut<'_, T, Self, ColAxis, R>>
+	{ self.try_slice_axis_mut(range, ColAxis) }
+
 
 	#[inline]
 	fn slice_mut<RR: SliceRange, CR: SliceRange>(&mut self, range_rows: RR, range_cols: CR)
-		-> SliceMut<T, RR::Size, Self::RowStride, CR::Size, Self::ColStride>
+		-> RangeSliceMut<T, Self, RR, CR>
 	{
-		assert!(range_cols.end() <= self.cols() && range_rows.end() <= self.rows(), "Range is out of bounds!");
-		unsafe {
+		self.try_slice_mut(range_rows, range_cols).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Slices the storage along both axes. Fails if any of the ranges is out of bounds.
+	#[inline]
+	fn try_slice_mut<RR: SliceRange, CR: SliceRange>(&mut self, range_rows: RR, range_cols: CR)
+		-> ShapeResult<RangeSliceMut<'_, T, Self, RR, CR>>
+	{
+		ShapeError::check_range(AxisType::Row, range_rows.begin(), range_rows.end(), self.rows())?;
+		ShapeError::check_range(AxisType::Col, range_cols.begin(), range_cols.end(), self.cols())?;
+		Ok(unsafe {
 			PtrStorageMut::new(
-				self.get_ptr_mut(range_rows.begin(), range_cols.begin()),
+				self.get_ptr_mut_unchecked(range_rows.begin(), range_cols.begin()),
 				Size::new(range_rows.size(), range_cols.size()),
 				self.strides()
 			).into()
-		}
+		})
 	}
 
 	#[inline]
 	fn into_slice_mut(&mut self)
-		-> SliceMut<T, Self::Rows, Self::RowStride, Self::Cols, Self::ColStride>
+		-> SSliceMut<T, Self>
 	{
 		unsafe {
 			PtrStorageMut::new(
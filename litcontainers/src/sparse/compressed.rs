@@ -0,0 +1,214 @@
+use crate::*;
+use std::ops::Range;
+
+/// Sparse elements compressed along lanes. Lane `i` stores the entries `indptr[i]..indptr[i + 1]` of `indices` and
+/// `data`, sorted by their index within the lane. Rows are lanes for CSR and columns are lanes for CSC storages.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Compressed<T> {
+	pub lanes: usize,
+	pub lane_len: usize,
+	pub indptr: Vec<usize>,
+	pub indices: Vec<usize>,
+	pub data: Vec<T>,
+}
+
+impl<T: NumericElement> Compressed<T> {
+	pub fn from_raw_parts(lanes: usize, lane_len: usize, indptr: Vec<usize>, indices: Vec<usize>, data: Vec<T>) -> Self {
+		assert_eq!(indptr.len(), lanes + 1, "Index pointer must have an entry per lane and a terminating entry!");
+		assert_eq!(indices.len(), data.len(), "Every stored element must have an index!");
+		assert!(indptr[0] == 0 && indptr[lanes] == data.len(), "Index pointer must span all stored elements!");
+		for lane in 0..lanes {
+			assert!(indptr[lane] <= indptr[lane + 1], "Index pointer must not decrease!");
+			let idx = &indices[indptr[lane]..indptr[lane + 1]];
+			assert!(
+				idx.windows(2).all(|w| w[0] < w[1]) && idx.iter().all(|&i| i < lane_len),
+				"Indices must be sorted, unique and inside the lane!"
+			);
+		}
+		Self { lanes, lane_len, indptr, indices, data }
+	}
+
+	/// Compresses `(lane, index, value)` entries. Duplicate entries are summed.
+	pub fn from_entries<I>(lanes: usize, lane_len: usize, entries: I) -> Self
+		where I: Iterator<Item=(usize, usize, T)>
+	{
+		let mut entries: Vec<_> = entries.collect();
+		assert!(entries.iter().all(|&(l, i, _)| l < lanes && i < lane_len), "Sparse entry out of bounds!");
+		entries.sort_by_key(|&(l, i, _)| (l, i));
+
+		let mut indptr = vec![0; lanes + 1];
+		let mut indices = Vec::with_capacity(entries.len());
+		let mut data: Vec<T> = Vec::with_capacity(entries.len());
+		let mut last = None;
+		for (l, i, v) in entries {
+			if last == Some((l, i)) {
+				*data.last_mut().unwrap() += v;
+				continue;
+			}
+			last = Some((l, i));
+			indptr[l + 1] += 1;
+			indices.push(i);
+			data.push(v);
+		}
+		for l in 0..lanes { indptr[l + 1] += indptr[l]; }
+		Self { lanes, lane_len, indptr, indices, data }
+	}
+
+	pub fn nnz(&self) -> usize { self.data.len() }
+
+	pub fn lane_range(&self, lane: usize) -> Range<usize> {
+		assert!(lane < self.lanes, "Lane index out of bounds!");
+		self.indptr[lane]..self.indptr[lane + 1]
+	}
+
+	pub fn lane(&self, lane: usize) -> SparseLane<'_, T> {
+		let range = self.lane_range(lane);
+		SparseLane { indices: &self.indices[range.clone()], values: &self.data[range] }
+	}
+
+	pub fn get(&self, lane: usize, index: usize) -> T {
+		assert!(index < self.lane_len, "Index out of bounds!");
+		self.lane(lane).get(index)
+	}
+
+	/// Same elements compressed along the other axis. A counting sort by index keeps the new indices sorted.
+	pub fn recompress(&self) -> Self {
+		let mut indptr = vec![0; self.lane_len + 1];
+		for &i in &self.indices { indptr[i + 1] += 1; }
+		for i in 0..self.lane_len { indptr[i + 1] += indptr[i]; }
+
+		let mut next = indptr.clone();
+		let mut indices = vec![0; self.nnz()];
+		let mut data = vec![T::zero(); self.nnz()];
+		for lane in 0..self.lanes {
+			for p in self.lane_range(lane) {
+				let q = &mut next[self.indices[p]];
+				indices[*q] = lane;
+				data[*q] = self.data[p];
+				*q += 1;
+			}
+		}
+		Self { lanes: self.lane_len, lane_len: self.lanes, indptr, indices, data }
+	}
+
+	/// Element-wise sum. Lanes are merged by index, so elements cancelling out remain stored as zeros.
+	pub fn plus(&self, o: &Self) -> Self {
+		assert!(self.lanes == o.lanes && self.lane_len == o.lane_len, "Sparse storages must be of equal size!");
+		let mut indptr = Vec::with_capacity(self.lanes + 1);
+		let mut indices = Vec::with_capacity(self.nnz() + o.nnz());
+		let mut data = Vec::with_capacity(self.nnz() + o.nnz());
+		indptr.push(0);
+		for lane in 0..self.lanes {
+			let (a, b) = (self.lane(lane), o.lane(lane));
+			let (mut i, mut j) = (0, 0);
+			while i < a.nnz() || j < b.nnz() {
+				let (index, value) = if j == b.nnz() || (i < a.nnz() && a.indices[i] < b.indices[j]) {
+					i += 1;
+					(a.indices[i - 1], a.values[i - 1])
+				} else if i == a.nnz() || b.indices[j] < a.indices[i] {
+					j += 1;
+					(b.indices[j - 1], b.values[j - 1])
+				} else {
+					i += 1;
+					j += 1;
+					(a.indices[i - 1], a.values[i - 1] + b.values[j - 1])
+				};
+				indices.push(index);
+				data.push(value);
+			}
+			indptr.push(data.len());
+		}
+		Self { lanes: self.lanes, lane_len: self.lane_len, indptr, indices, data }
+	}
+
+	pub fn scale(&mut self, factor: T) {
+		for v in &mut self.data { *v *= factor; }
+	}
+}
+
+/// Stored elements of a single row or column.
+#[derive(Debug, Clone, Copy)]
+pub struct SparseLane<'a, T> {
+	/// Indices of the stored elements within the lane in increasing order.
+	pub indices: &'a [usize],
+	pub values: &'a [T],
+}
+
+impl<'a, T: NumericElement> SparseLane<'a, T> {
+	pub fn nnz(&self) -> usize { self.values.len() }
+
+	/// Element at given index within the lane. Elements which are not stored are zero.
+	pub fn get(&self, index: usize) -> T {
+		match self.indices.binary_search(&index) {
+			Ok(p) => self.values[p],
+			Err(_) => T::zero(),
+		}
+	}
+
+	/// Iterates over the stored elements as `(index, value)`.
+	pub fn iter(&self) -> impl Iterator<Item=(usize, T)> + 'a {
+		self.indices.iter().cloned().zip(self.values.iter().cloned())
+	}
+}
+
+/// Iterator over the rows of a CSR or the columns of a CSC storage.
+pub struct SparseLaneIter<'a, T> {
+	storage: &'a Compressed<T>,
+	lane: usize,
+}
+
+impl<'a, T> SparseLaneIter<'a, T> {
+	pub(crate) fn new(storage: &'a Compressed<T>) -> Self { Self { storage, lane: 0 } }
+}
+
+impl<'a, T: NumericElement> Iterator for SparseLaneIter<'a, T> {
+	type Item = SparseLane<'a, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.lane == self.storage.lanes { return None; }
+		self.lane += 1;
+		Some(self.storage.lane(self.lane - 1))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.storage.lanes - self.lane;
+		(len, Some(len))
+	}
+}
+
+impl<'a, T: NumericElement> ExactSizeIterator for SparseLaneIter<'a, T> {}
+
+/// Iterator over the stored elements as `(row, col, value)` in storage order.
+pub struct SparseIter<'a, T> {
+	storage: &'a Compressed<T>,
+	transposed: bool,
+	lane: usize,
+	pos: usize,
+}
+
+impl<'a, T> SparseIter<'a, T> {
+	/// Iterates the entries of given compression. Lanes are columns if `transposed` is set.
+	pub(crate) fn new(storage: &'a Compressed<T>, transposed: bool) -> Self {
+		Self { storage, transposed, lane: 0, pos: 0 }
+	}
+}
+
+impl<'a, T: NumericElement> Iterator for SparseIter<'a, T> {
+	type Item = (usize, usize, T);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let s = self.storage;
+		if self.pos == s.nnz() { return None; }
+		while self.pos >= s.indptr[self.lane + 1] { self.lane += 1; }
+		let (lane, index, value) = (self.lane, s.indices[self.pos], s.data[self.pos]);
+		self.pos += 1;
+		Some(if self.transposed { (index, lane, value) } else { (lane, index, value) })
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.storage.nnz() - self.pos;
+		(len, Some(len))
+	}
+}
+
+impl<'a, T: NumericElement> ExactSizeIterator for SparseIter<'a, T> {}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use crate::*;
+use crate::sparse::compressed::Compressed;
+use crate::sparse::csr::exceeds_threshold;
+use std::ops::Add;
+
+/// Compressed sparse column storage. Stored elements of each column are kept sorted by their row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscStorage<T>
+	where T: NumericElement
+{
+	inner: Compressed<T>,
+}
+
+impl<T: NumericElement> CscStorage<T> {
+	pub(crate) fn from_compressed(inner: Compressed<T>) -> Self { Self { inner } }
+
+	/// Creates storage of given size from `(row, col, value)` triplets. Duplicate triplets are summed.
+	pub fn from_triplets<R: Dim, C: Dim>(size: Size<R, C>, triplets: &[(usize, usize, T)]) -> Self {
+		let entries = triplets.iter().map(|&(r, c, v)| (c, r, v));
+		Self::from_compressed(Compressed::from_entries(size.cols(), size.rows(), entries))
+	}
+
+	/// Stores the elements of a dense storage whose magnitude exceeds `threshold`.
+	pub fn from_dense<S: Storage<T>>(s: &S, threshold: T) -> Self
+		where T: Scalar
+	{
+		let entries = (0..s.cols())
+			.flat_map(|c| (0..s.rows()).map(move |r| (c, r, s.get(r, c))))
+			.filter(|&(_, _, v)| exceeds_threshold(v, threshold));
+		Self::from_compressed(Compressed::from_entries(s.cols(), s.rows(), entries))
+	}
+
+	/// Creates storage from its compressed representation. Row indices of column `c` are
+	/// `indices[indptr[c]..indptr[c + 1]]` and must be sorted and unique.
+	pub fn from_raw_parts<R: Dim, C: Dim>(size: Size<R, C>, indptr: Vec<usize>, indices: Vec<usize>, data: Vec<T>) -> Self {
+		Self::from_compressed(Compressed::from_raw_parts(size.cols(), size.rows(), indptr, indices, data))
+	}
+
+	pub fn indptr(&self) -> &[usize] { &self.inner.indptr }
+
+	/// Row indices of the stored elements.
+	pub fn indices(&self) -> &[usize] { &self.inner.indices }
+
+	pub fn data(&self) -> &[T] { &self.inner.data }
+
+	pub fn data_mut(&mut self) -> &mut [T] { &mut self.inner.data }
+
+	pub fn col(&self, c: usize) -> SparseLane<'_, T> { self.inner.lane(c) }
+
+	pub fn col_iter(&self) -> SparseLaneIter<'_, T> { SparseLaneIter::new(&self.inner) }
+
+	/// Transposed storage. Columns of the compression become rows, so the elements are only cloned.
+	pub fn transpose(&self) -> CsrStorage<T> { CsrStorage::from_compressed(self.inner.clone()) }
+
+	/// Converts into row compression without changing the elements.
+	pub fn to_csr(&self) -> CsrStorage<T> { CsrStorage::from_compressed(self.inner.recompress()) }
+
+	pub fn scale(&self, factor: T) -> Self {
+		let mut ret = self.clone();
+		ret.scale_inplace(factor);
+		ret
+	}
+
+	pub fn scale_inplace(&mut self, factor: T) { self.inner.scale(factor) }
+}
+
+impl<T: NumericElement> StorageSize for CscStorage<T> {
+	type Rows = Dynamic;
+	type Cols = Dynamic;
+
+	fn row_dim(&self) -> Self::Rows { Dynamic::new(self.inner.lane_len) }
+
+	fn col_dim(&self) -> Self::Cols { Dynamic::new(self.inner.lanes) }
+}
+
+impl<T: NumericElement> SparseStorage<T> for CscStorage<T> {
+	fn nnz(&self) -> usize { self.inner.nnz() }
+
+	fn get(&self, r: usize, c: usize) -> T { self.inner.get(c, r) }
+
+	fn iter(&self) -> SparseIter<'_, T> { SparseIter::new(&self.inner, true) }
+}
+
+impl<'a, T: NumericElement> Add<&'a CscStorage<T>> for &'a CscStorage<T> {
+	type Output = CscStorage<T>;
+
+	fn add(self, rhs: &'a CscStorage<T>) -> Self::Output { CscStorage::from_compressed(self.inner.plus(&rhs.inner)) }
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use crate::*;
+use crate::sparse::compressed::Compressed;
+use std::ops::Add;
+
+/// Compressed sparse row storage. Stored elements of each row are kept sorted by their column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrStorage<T>
+	where T: NumericElement
+{
+	inner: Compressed<T>,
+}
+
+impl<T: NumericElement> CsrStorage<T> {
+	pub(crate) fn from_compressed(inner: Compressed<T>) -> Self { Self { inner } }
+
+	/// Creates storage of given size from `(row, col, value)` triplets. Duplicate triplets are summed.
+	pub fn from_triplets<R: Dim, C: Dim>(size: Size<R, C>, triplets: &[(usize, usize, T)]) -> Self {
+		Self::from_compressed(Compressed::from_entries(size.rows(), size.cols(), triplets.iter().cloned()))
+	}
+
+	/// Stores the elements of a dense storage whose magnitude exceeds `threshold`.
+	pub fn from_dense<S: Storage<T>>(s: &S, threshold: T) -> Self
+		where T: Scalar
+	{
+		let entries = (0..s.rows())
+			.flat_map(|r| (0..s.cols()).map(move |c| (r, c, s.get(r, c))))
+			.filter(|&(_, _, v)| exceeds_threshold(v, threshold));
+		Self::from_compressed(Compressed::from_entries(s.rows(), s.cols(), entries))
+	}
+
+	/// Creates storage from its compressed representation. Column indices of row `r` are
+	/// `indices[indptr[r]..indptr[r + 1]]` and must be sorted and unique.
+	pub fn from_raw_parts<R: Dim, C: Dim>(size: Size<R, C>, indptr: Vec<usize>, indices: Vec<usize>, data: Vec<T>) -> Self {
+		Self::from_compressed(Compressed::from_raw_parts(size.rows(), size.cols(), indptr, indices, data))
+	}
+
+	pub fn indptr(&self) -> &[usize] { &self.inner.indptr }
+
+	/// Column indices of the stored elements.
+	pub fn indices(&self) -> &[usize] { &self.inner.indices }
+
+	pub fn data(&self) -> &[T] { &self.inner.data }
+
+	pub fn data_mut(&mut self) -> &mut [T] { &mut self.inner.data }
+
+	pub fn row(&self, r: usize) -> SparseLane<'_, T> { self.inner.lane(r) }
+
+	pub fn row_iter(&self) -> SparseLaneIter<'_, T> { SparseLaneIter::new(&self.inner) }
+
+	/// Transposed storage. Rows of the compression become columns, so the elements are only cloned.
+	pub fn transpose(&self) -> CscStorage<T> { CscStorage::from_compressed(self.inner.clone()) }
+
+	/// Converts into column compression without changing the elements.
+	pub fn to_csc(&self) -> CscStorage<T> { CscStorage::from_compressed(self.inner.recompress()) }
+
+	pub fn scale(&self, factor: T) -> Self {
+		let mut ret = self.clone();
+		ret.scale_inplace(factor);
+		ret
+	}
+
+	pub fn scale_inplace(&mut self, factor: T) { self.inner.scale(factor) }
+}
+
+/// Whether the magnitude of the value exceeds the threshold. Avoids negating unsigned values.
+pub(crate) fn exceeds_threshold<T: Scalar>(v: T, threshold: T) -> bool {
+	v > threshold || (v < T::zero() && v < T::zero() - threshold)
+}
+
+impl<T: NumericElement> StorageSize for CsrStorage<T> {
+	type Rows = Dynamic;
+	type Cols = Dynamic;
+
+	fn row_dim(&self) -> Self::Rows { Dynamic::new(self.inner.lanes) }
+
+	fn col_dim(&self) -> Self::Cols { Dynamic::new(self.inner.lane_len) }
+}
+
+impl<T: NumericElement> SparseStorage<T> for CsrStorage<T> {
+	fn nnz(&self) -> usize { self.inner.nnz() }
+
+	fn get(&self, r: usize, c: usize) -> T { self.inner.get(r, c) }
+
+	fn iter(&self) -> SparseIter<'_, T> { SparseIter::new(&self.inner, false) }
+}
+
+impl<'a, T: NumericElement> Add<&'a CsrStorage<T>> for &'a CsrStorage<T> {
+	type Output = CsrStorage<T>;
+
+	fn add(self, rhs: &'a CsrStorage<T>) -> Self::Output { CsrStorage::from_compressed(self.inner.plus(&rhs.inner)) }
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+pub mod compressed;
+pub mod storage;
+pub mod csr;
+pub mod csc;
+
+pub use compressed::{SparseLane, SparseLaneIter, SparseIter};
+pub use storage::*;
+pub use csr::*;
+pub use csc::*;
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::*;
+
+/// Storage keeping only a subset of its elements. Elements which are not stored are zero.
+pub trait SparseStorage<T: NumericElement>: StorageSize<Rows=Dynamic, Cols=Dynamic> {
+	/// Number of stored elements.
+	fn nnz(&self) -> usize;
+
+	fn get(&self, r: usize, c: usize) -> T;
+
+	/// Iterates over the stored elements as `(row, col, value)`.
+	fn iter(&self) -> SparseIter<'_, T>;
+
+	/// Fraction of the elements which are stored.
+	fn density(&self) -> f64 {
+		if self.len() == 0 { 0. } else { self.nnz() as f64 / self.len() as f64 }
+	}
+
+	fn to_dense(&self) -> ContainerRM<T, Dynamic, Dynamic> {
+		let mut ret = ContainerRM::zeros(self.size());
+		for (r, c, v) in self.iter() {
+			*ret.get_mut(r, c) += v;
+		}
+		ret
+	}
+
+	/// Product with a dense storage. Every stored element scales one row of the dense storage, so the cost is
+	/// proportional to the number of stored elements times the columns of the dense storage.
+	fn mul_dense<S: Storage<T>>(&self, s: &S) -> ContainerRM<T, Dynamic, S::Cols> {
+		assert_eq!(self.cols(), s.rows(), "Sparse storage columns must match the dense storage rows!");
+		let mut ret = ContainerRM::zeros(Size::new(self.row_dim(), s.col_dim()));
+		for (r, k, v) in self.iter() {
+			for (o, x) in ret.slice_rows_mut(r).as_row_iter_mut().zip(s.slice_rows(k).as_row_iter()) {
+				*o += v * *x;
+			}
+		}
+		ret
+	}
+
+	/// Product with a vector given as storage with a single row or column.
+	fn mul_vec<S: Storage<T>>(&self, v: &S) -> ColVec<T, Dynamic> {
+		assert!(v.rows() == 1 || v.cols() == 1, "Vector must consist of a single row or column!");
+		assert_eq!(v.len(), self.cols(), "Vector length must match the sparse storage columns!");
+		let mut ret = ColVec::zeros(Size::new(self.row_dim(), U1));
+		let row_vec = v.rows() == 1;
+		for (r, k, a) in self.iter() {
+			*ret.get_mut(r, 0) += a * if row_vec { v.get(0, k) } else { v.get(k, 0) };
+		}
+		ret
+	}
+}
\ No newline at end of file
@@ -1,6 +1,33 @@
 use crate::storage::*;
 use crate::format::*;
 use rand::Rng;
+use rand::distributions::{Distribution, Uniform, Normal, Exp, Bernoulli, StandardNormal};
+use num_traits::Float;
+use num_complex::Complex;
+
+/// Circularly symmetric complex normal distribution. Real and imaginary parts are independent and
+/// each carry half of the variance.
+#[derive(Debug, Clone, Copy, new)]
+pub struct ComplexNormal<F> {
+	mean: Complex<F>,
+	std_dev: F,
+}
+
+impl<F: Float> Distribution<Complex<F>> for ComplexNormal<F> {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex<F> {
+		let scale = self.std_dev / F::from(2.).unwrap().sqrt();
+		let re = F::from(rng.sample::<f64, _>(StandardNormal)).unwrap();
+		let im = F::from(rng.sample::<f64, _>(StandardNormal)).unwrap();
+		self.mean + Complex::new(re, im) * scale
+	}
+}
+
+/// Triangle of a matrix relative to its diagonal.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Triangle {
+	Upper,
+	Lower,
+}
 
 pub trait StorageConstructor<T>: StorageMut<T>
 	where T: Element
@@ -20,10 +47,7 @@ pub trait StorageConstructor<T>: StorageMut<T>
 	fn rand(s: SSize<Self>) -> Self
 		where rand::distributions::Standard: rand::distributions::Distribution<T>
 	{
-		let mut ret = Self::zeros(s);
-		let mut rng = rand::thread_rng();
-		for v in ret.as_iter_mut() { *v = rng.gen() }
-		ret
+		Self::rand_with(s, &mut rand::thread_rng(), rand::distributions::Standard)
 	}
 
 	/// Creates a container with all elements set to a random value
@@ -31,25 +55,76 @@ pub trait StorageConstructor<T>: StorageMut<T>
 	fn rand_range(s: SSize<Self>, from: T, to: T) -> Self
 		where T: rand::distributions::uniform::SampleUniform
 	{
+		Self::rand_uniform(s, &mut rand::thread_rng(), from, to)
+	}
+
+	/// Creates a container with all elements sampled from given distribution using given generator.
+	fn rand_with<R: Rng + ?Sized, D: Distribution<T>>(s: SSize<Self>, rng: &mut R, dist: D) -> Self {
 		let mut ret = Self::zeros(s);
-		let mut rng = rand::thread_rng();
-		for v in ret.as_iter_mut() { *v = rng.gen_range(from, to) }
+		for v in ret.as_iter_mut() { *v = dist.sample(rng) }
 		ret
 	}
 
+	/// Creates a container with elements sampled uniformly from the half open range [low, high).
+	fn rand_uniform<R: Rng + ?Sized>(s: SSize<Self>, rng: &mut R, low: T, high: T) -> Self
+		where T: rand::distributions::uniform::SampleUniform
+	{
+		Self::rand_with(s, rng, Uniform::new(low, high))
+	}
+
+	/// Creates a container with elements sampled from a normal distribution.
+	fn rand_normal<R: Rng + ?Sized>(s: SSize<Self>, rng: &mut R, mean: f64, std_dev: f64) -> Self
+		where T: Float
+	{
+		Self::rand_with(s, rng, Cast(Normal::new(mean, std_dev)))
+	}
+
+	/// Creates a container with elements sampled from an exponential distribution with rate `lambda`.
+	fn rand_exp<R: Rng + ?Sized>(s: SSize<Self>, rng: &mut R, lambda: f64) -> Self
+		where T: Float
+	{
+		Self::rand_with(s, rng, Cast(Exp::new(lambda)))
+	}
+
+	/// Creates a container with elements being `true` with probability `p`.
+	fn rand_bernoulli<R: Rng + ?Sized>(s: SSize<Self>, rng: &mut R, p: f64) -> Self
+		where Bernoulli: Distribution<T>
+	{
+		Self::rand_with(s, rng, Bernoulli::new(p))
+	}
+
+	/// Creates a container with elements sampled from a circularly symmetric complex normal distribution.
+	fn rand_complex_normal<R: Rng + ?Sized, F: Float>(s: SSize<Self>, rng: &mut R, mean: Complex<F>, std_dev: F) -> Self
+		where ComplexNormal<F>: Distribution<T>
+	{
+		Self::rand_with(s, rng, ComplexNormal::new(mean, std_dev))
+	}
+
 	// Crate a container from a vector containing the data. Data must be stored in row wise order.
 	fn from_vec(s: SSize<Self>, data: &[T]) -> Self {
-		assert_eq!(s.len(), data.len());
+		Self::try_from_vec(s, data).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Crate a container from a slice containing the data in row wise order. Fails if the length of the data
+	/// doesn't match the size.
+	fn try_from_vec(s: SSize<Self>, data: &[T]) -> ShapeResult<Self> {
+		if s.len() != data.len() {
+			return Err(ShapeError::LengthMismatch { expected: s.len(), actual: data.len() });
+		}
 		let mut ret = Self::zeros(s);
 		for (o, i) in ret.as_iter_mut().zip(data) { *o = *i; }
-		ret
+		Ok(ret)
 	}
 
 	/// Creates a container with all rows containing regularly spaced values from start to end.
 	fn linspace<A: Axis<Self::Rows, Self::Cols>>(s: SSize<Self>, _: A, start: T, end: T) -> Self
 		where T: NumericElement
 	{
-		let interval = (end - start) / T::from_usize(s.cols() - 1);
+		let count = match A::axis_type() {
+			AxisType::Row => s.cols(),
+			AxisType::Col => s.rows(),
+		};
+		let interval = if count > 1 { (end - start) / T::from_usize(count - 1) } else { T::zero() };
 		let mut ret = Self::zeros(s.clone());
 
 		match A::axis_type() {
@@ -76,6 +151,27 @@ pub trait StorageConstructor<T>: StorageMut<T>
 		ret
 	}
 
+	/// Creates a container with values spaced evenly on a log scale from base^start to base^end.
+	fn logspace<A: Axis<Self::Rows, Self::Cols>>(s: SSize<Self>, axis: A, start: T, end: T, base: T) -> Self
+		where T: NumericElement + Float
+	{
+		let mut ret = Self::linspace(s, axis, start, end);
+		ret.mapv_inplace(|v| base.powf(v));
+		ret
+	}
+
+	/// Creates a container with values forming a geometric progression from start to end.
+	/// Both ends must be nonzero and have the same sign.
+	fn geomspace<A: Axis<Self::Rows, Self::Cols>>(s: SSize<Self>, axis: A, start: T, end: T) -> Self
+		where T: NumericElement + Float
+	{
+		assert!(start * end > T::zero(), "Geomspace ends must be nonzero and have the same sign!");
+		let sign = start.signum();
+		let mut ret = Self::linspace(s, axis, start.abs().ln(), end.abs().ln());
+		ret.mapv_inplace(|v| sign * v.exp());
+		ret
+	}
+
 	/// Creates a container with all rows containing regularly spaced values from start to start + axis_size.
 	fn regspace<A: Axis<Self::Rows, Self::Cols>>(s: SSize<Self>, axis: A, start: T) -> Self
 		where T: NumericElement
@@ -111,4 +207,95 @@ pub trait StorageConstructor<T>: StorageMut<T>
 		}
 		ret
 	}
+
+	/// Creates a container with ones on the main diagonal and zeros elsewhere.
+	fn eye(s: SSize<Self>) -> Self
+		where T: NumericElement
+	{
+		Self::from_fn(s, |r, c| if r == c { T::one() } else { T::zero() })
+	}
+
+	/// Creates a container with given values on the main diagonal and zeros elsewhere.
+	fn diag(s: SSize<Self>, values: &[T]) -> Self {
+		Self::try_diag(s, values).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Creates a container with given values on the main diagonal and zeros elsewhere. Fails if the number
+	/// of values doesn't match the length of the diagonal.
+	fn try_diag(s: SSize<Self>, values: &[T]) -> ShapeResult<Self> {
+		let len = s.rows().min(s.cols());
+		if len != values.len() {
+			return Err(ShapeError::LengthMismatch { expected: len, actual: values.len() });
+		}
+		let mut ret = Self::zeros(s);
+		for (i, v) in values.iter().enumerate() { *ret.get_mut(i, i) = *v; }
+		Ok(ret)
+	}
+
+	/// Creates a container with each element computed from its row and col index.
+	fn from_fn<F: FnMut(usize, usize) -> T>(s: SSize<Self>, mut f: F) -> Self {
+		let mut ret = Self::zeros(s);
+		for r in 0..ret.rows() {
+			for (c, v) in ret.as_row_range_iter_mut(r).enumerate() { *v = f(r, c); }
+		}
+		ret
+	}
+
+	/// Creates a container from values in storage order. Panics if the iterator yields too few values.
+	fn from_iter<I: IntoIterator<Item=T>>(s: SSize<Self>, iter: I) -> Self {
+		let mut ret = Self::zeros(s);
+		fill_from_iter(ret.as_slice_mut().iter_mut(), iter);
+		ret
+	}
+
+	/// Creates a container from values in row wise order. Panics if the iterator yields too few values.
+	fn from_row_iter<I: IntoIterator<Item=T>>(s: SSize<Self>, iter: I) -> Self {
+		let mut ret = Self::zeros(s);
+		fill_from_iter(ret.as_row_iter_mut(), iter);
+		ret
+	}
+
+	/// Creates a container from values in col wise order. Panics if the iterator yields too few values.
+	fn from_col_iter<I: IntoIterator<Item=T>>(s: SSize<Self>, iter: I) -> Self {
+		let mut ret = Self::zeros(s);
+		fill_from_iter(ret.as_col_iter_mut(), iter);
+		ret
+	}
+
+	/// Creates coordinate containers from a vector of x and y values. Rows of the first repeat `x`
+	/// and cols of the second repeat `y`.
+	fn meshgrid(s: SSize<Self>, x: &[T], y: &[T]) -> (Self, Self) {
+		assert_eq!(x.len(), s.cols(), "Length of x must match the number of cols!");
+		assert_eq!(y.len(), s.rows(), "Length of y must match the number of rows!");
+		(Self::from_fn(s.clone(), |_, c| x[c]), Self::from_fn(s, |r, _| y[r]))
+	}
+
+	/// Creates a container with ones in the given triangle and zeros elsewhere. The triangle
+	/// includes the `k`th diagonal, where positive `k` is above the main diagonal.
+	fn tri(s: SSize<Self>, triangle: Triangle, k: isize) -> Self
+		where T: NumericElement
+	{
+		Self::from_fn(s, |r, c| {
+			let d = c as isize - r as isize;
+			let inside = match triangle {
+				Triangle::Upper => d >= k,
+				Triangle::Lower => d <= k,
+			};
+			if inside { T::one() } else { T::zero() }
+		})
+	}
+}
+
+/// Casts samples of a `f64` distribution to the float element type.
+struct Cast<D>(D);
+
+impl<T: Float, D: Distribution<f64>> Distribution<T> for Cast<D> {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T { T::from(self.0.sample(rng)).unwrap() }
+}
+
+fn fill_from_iter<'a, T: 'a, O: Iterator<Item=&'a mut T>, I: IntoIterator<Item=T>>(out: O, iter: I) {
+	let mut iter = iter.into_iter();
+	for (i, o) in out.enumerate() {
+		*o = iter.next().unwrap_or_else(|| panic!("Iterator yielded only {} values!", i));
+	}
 }
\ No newline at end of file
@@ -1,14 +1,99 @@
 use crate::format::*;
 use crate::storage::*;
+use crate::slice::SliceRange;
 
+/// Storage with a dynamic number of rows.
 pub trait DynamicRowStorage<T: Element>: StorageMut<T> + StorageSize<Rows=Dynamic> {
-	// TODO: add amortized version of resize otherwise -> super slow growing
+	/// Resizes to given number of rows. New rows are filled with default values.
 	#[inline]
 	fn set_rows(&mut self, count: usize);
+
+	/// Inserts the rows of given storage before row `index`.
+	fn insert_rows<S: Storage<T>>(&mut self, index: usize, rows: &S);
+
+	/// Removes the rows in given range.
+	fn remove_rows<RR: SliceRange>(&mut self, range: RR);
+
+	/// Appends a single row.
+	fn push_row<S: Storage<T>>(&mut self, row: &S) {
+		assert_eq!(row.rows(), 1, "Pushed storage must consist of a single row!");
+		let index = self.rows();
+		self.insert_rows(index, row);
+	}
 }
 
+/// Storage with a dynamic number of columns.
 pub trait DynamicColStorage<T: Element>: StorageMut<T> + StorageSize<Cols=Dynamic> {
+	/// Resizes to given number of columns. New columns are filled with default values.
 	#[inline]
 	fn set_cols(&mut self, count: usize);
+
+	/// Inserts the columns of given storage before column `index`.
+	fn insert_cols<S: Storage<T>>(&mut self, index: usize, cols: &S);
+
+	/// Removes the columns in given range.
+	fn remove_cols<CR: SliceRange>(&mut self, range: CR);
+
+	/// Appends a single column.
+	fn push_col<S: Storage<T>>(&mut self, col: &S) {
+		assert_eq!(col.cols(), 1, "Pushed storage must consist of a single column!");
+		let index = self.cols();
+		self.insert_cols(index, col);
+	}
+}
+
+// Helpers for vec storages. The data consists of `lane_count` contiguous lanes of `lane_len` elements, which are
+// rows for row major and columns for column major storages.
+
+/// Inserts whole lanes before lane `index`. Capacity grows geometrically, so repeated appends are amortized.
+pub(crate) fn insert_lanes<T, I>(data: &mut Vec<T>, lane_len: usize, index: usize, count: usize, values: I)
+	where T: Element, I: Iterator<Item=T>
+{
+	assert!(index * lane_len <= data.len(), "Insert position out of bounds!");
+	data.reserve(count * lane_len);
+	let at = index * lane_len;
+	data.splice(at..at, values.take(count * lane_len));
+}
+
+/// Removes whole lanes in given range.
+pub(crate) fn remove_lanes<T: Element, RR: SliceRange>(data: &mut Vec<T>, lane_len: usize, range: RR) {
+	assert!(range.begin() <= range.end() && range.end() * lane_len <= data.len(), "Remove range out of bounds!");
+	data.drain(range.begin() * lane_len..range.end() * lane_len);
+}
+
+/// Inserts `count` elements into every lane before element `index`. Lanes are moved back to front within the grown
+/// vector, so every element is copied once and no temporary buffer is allocated.
+pub(crate) fn insert_lane_elements<T, F>(
+	data: &mut Vec<T>, lane_count: usize, lane_len: usize, index: usize, count: usize, mut f: F
+)
+	where T: Element, F: FnMut(usize, usize) -> T
+{
+	assert!(index <= lane_len, "Insert position out of bounds!");
+	if count == 0 { return; }
+	let new_len = lane_len + count;
+	data.resize(lane_count * new_len, T::default());
+	for lane in (0..lane_count).rev() {
+		let (src, dst) = (lane * lane_len, lane * new_len);
+		data.copy_within(src + index..src + lane_len, dst + index + count);
+		data.copy_within(src..src + index, dst);
+		for (k, v) in data[dst + index..dst + index + count].iter_mut().enumerate() {
+			*v = f(lane, k);
+		}
+	}
 }
 
+/// Removes the elements in given range from every lane. Lanes are moved front to back in place.
+pub(crate) fn remove_lane_elements<T, RR>(data: &mut Vec<T>, lane_count: usize, lane_len: usize, range: RR)
+	where T: Element, RR: SliceRange
+{
+	let (start, end) = (range.begin(), range.end());
+	assert!(start <= end && end <= lane_len, "Remove range out of bounds!");
+	if start == end { return; }
+	let new_len = lane_len - (end - start);
+	for lane in 0..lane_count {
+		let (src, dst) = (lane * lane_len, lane * new_len);
+		data.copy_within(src..src + start, dst);
+		data.copy_within(src + end..src + lane_len, dst + start);
+	}
+	data.truncate(lane_count * new_len);
+}
\ No newline at end of file
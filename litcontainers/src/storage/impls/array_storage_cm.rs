@@ -0,0 +1,150 @@
+use crate::format::*;
+use crate::storage::{Storage, StorageMut, StorageConstructor, Ownable, ArrayDim};
+use crate::{InplaceMap, InplaceMapOrdered, Container, InplaceForeach};
+use generic_array::GenericArray;
+use std::ops::{Index, IndexMut};
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Elements of a static `R x C` matrix stored as `C` columns of `R` elements.
+pub type ArrayDataCM<T, R, C> = GenericArray<GenericArray<T, <R as ArrayDim>::Length<T>>, ArrayCols<T, R, C>>;
+
+type ArrayCols<T, R, C> = <C as ArrayDim>::Length<GenericArray<T, <R as ArrayDim>::Length<T>>>;
+
+/// Column major storage of a static size. Elements are stored inline, so creating and copying it doesn't allocate.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	data: ArrayDataCM<T, R, C>,
+}
+
+impl<T, R, C, SO> PartialEq<SO> for ArrayStorageCM<T, R, C>
+	where T: Element + PartialEq, R: ArrayDim, C: ArrayDim, SO: Storage<T>
+{
+	fn eq(&self, other: &SO) -> bool { self.equals(other) }
+}
+
+impl<T, R, C> Eq for ArrayStorageCM<T, R, C>
+	where T: Element + Eq, R: ArrayDim, C: ArrayDim
+{}
+
+impl<T, R, C> ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	pub fn from_data(data: ArrayDataCM<T, R, C>) -> Self { Self { data } }
+
+	/// Returns the underlying data in storage order.
+	pub fn into_data(self) -> ArrayDataCM<T, R, C> { self.data }
+}
+
+impl<T, R, C> Copy for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim, ArrayDataCM<T, R, C>: Copy
+{}
+
+impl<T, R, C> StorageSize for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	type Rows = R;
+	type Cols = C;
+
+	fn row_dim(&self) -> Self::Rows { R::name() }
+
+	fn col_dim(&self) -> Self::Cols { C::name() }
+}
+
+impl<T, R, C> Strided for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	type RowStride = U1;
+	type ColStride = R;
+
+	fn row_stride_dim(&self) -> Self::RowStride { U1 }
+
+	fn col_stride_dim(&self) -> Self::ColStride { self.row_dim() }
+}
+
+impl<T, R, C> Storage<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn as_ptr(&self) -> *const T { self.data.as_ptr() as *const T }
+}
+
+impl<T, R, C> StorageMut<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn as_ptr_mut(&mut self) -> *mut T { self.data.as_mut_ptr() as *mut T }
+}
+
+impl<T, R, C> StorageConstructor<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn from_value(_: SSize<Self>, value: T) -> Self {
+		let mut ret = Self { data: Default::default() };
+		ret.map_inplace(|v| *v = value);
+		ret
+	}
+}
+
+impl<T, R, C> Ownable<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	type OwnedType = Self;
+
+	fn owned(self) -> Container<T, Self::OwnedType> { self.into() }
+
+	fn clone_owned(&self) -> Container<T, Self::OwnedType> { self.clone().into() }
+}
+
+impl<T, R, C> InplaceMap<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn map_inplace<F: FnMut(&mut T)>(&mut self, f: F) { self.as_slice_mut().iter_mut().for_each(f) }
+}
+
+impl<T, R, C> InplaceMapOrdered<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn map_inplace_ordered<F: FnMut(&mut T)>(&mut self, mut f: F) {
+		for v in self.as_iter_mut() { f(v) }
+	}
+}
+
+impl<T, R, C> InplaceForeach<T> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn foreach<F: FnMut(&T)>(&self, f: F) { self.as_slice().iter().for_each(f) }
+}
+
+impl<T, R, C> Index<usize> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	type Output = T;
+
+	fn index(&self, index: usize) -> &Self::Output { &self.as_slice()[index] }
+}
+
+impl<T, R, C> IndexMut<usize> for ArrayStorageCM<T, R, C>
+	where T: Element, R: ArrayDim, C: ArrayDim
+{
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output { &mut self.as_slice_mut()[index] }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<T, R, C> Serialize for ArrayStorageCM<T, R, C>
+	where T: Element + Serialize, R: ArrayDim, C: ArrayDim
+{
+	fn serialize<SE: Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> {
+		crate::storage::serialize_storage(self, serializer)
+	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, T, R, C> Deserialize<'de> for ArrayStorageCM<T, R, C>
+	where T: Element + Deserialize<'de>, R: ArrayDim, C: ArrayDim
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		crate::storage::deserialize_storage(deserializer)
+	}
+}
\ No newline at end of file
@@ -1,7 +1,11 @@
 pub mod ptr_storage;
 pub mod vec_storage_cm;
 pub mod vec_storage_rm;
+pub mod array_storage_cm;
+pub mod array_storage_rm;
 
 #[doc(inline)] pub use ptr_storage::*;
 #[doc(inline)] pub use vec_storage_cm::*;
-#[doc(inline)] pub use vec_storage_rm::*;
\ No newline at end of file
+#[doc(inline)] pub use vec_storage_rm::*;
+#[doc(inline)] pub use array_storage_cm::*;
+#[doc(inline)] pub use array_storage_rm::*;
\ No newline at end of file
@@ -118,15 +118,18 @@ impl<T, R, RS, C, CS> InplaceMapOrdered<T> for PtrStorageCore<T, R, RS, C, CS>
 impl<T, R, RS, C, CS> Ownable<T> for PtrStorageCore<T, R, RS, C, CS>
 	where T: Element, R: Dim, RS: Dim, C: Dim, CS: Dim
 {
+	/// Row major storage. Slices with known dimensions keep small static sizes inline through their own `owned` and
+	/// `clone_owned`, see `OwnedDims`.
 	type OwnedType = VecStorageRM<T, R, C>;
 
 	#[inline]
-	fn owned(self) -> Container<T, Self::OwnedType> { self.clone_owned().into() }
+	fn owned(self) -> Container<T, Self::OwnedType> { self.clone_owned() }
 
 	#[inline]
 	fn clone_owned(&self) -> Container<T, Self::OwnedType> {
-		let data = self.as_iter().cloned().collect();
-		Self::OwnedType::from_data(self.size(), data).into()
+		let mut ret = Self::OwnedType::zeros(self.size());
+		for (o, v) in ret.as_iter_mut().zip(self.as_iter()) { *o = *v; }
+		ret.into()
 	}
 }
 
@@ -1,11 +1,14 @@
 use crate::format::*;
 use crate::storage::{Storage, StorageMut, DynamicRowStorage, DynamicColStorage, StorageConstructor, Ownable};
-use std::cmp::min;
+use crate::storage::{insert_lanes, remove_lanes, insert_lane_elements, remove_lane_elements};
+use crate::slice::SliceRange;
 use crate::{InplaceMap, InplaceMapOrdered, Container, InplaceForeach};
 use std::ops::{Index, IndexMut};
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[repr(C)]
-#[derive(Eq, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct VecStorageCM<T, R, C>
 	where T: Element, R: Dim, C: Dim
 {
@@ -13,6 +16,16 @@ pub struct VecStorageCM<T, R, C>
 	size: Size<R, C>
 }
 
+impl<T, R, C, SO> PartialEq<SO> for VecStorageCM<T, R, C>
+	where T: Element + PartialEq, R: Dim, C: Dim, SO: Storage<T>
+{
+	fn eq(&self, other: &SO) -> bool { self.equals(other) }
+}
+
+impl<T, R, C> Eq for VecStorageCM<T, R, C>
+	where T: Element + Eq, R: Dim, C: Dim
+{}
+
 impl<T, R, C> VecStorageCM<T, R, C>
 	where T: Element, R: Dim, C: Dim
 {
@@ -21,14 +34,14 @@ impl<T, R, C> VecStorageCM<T, R, C>
 		Self { data, size }
 	}
 
-	unsafe fn resize_element_count(&mut self, size: usize) {
-		if self.data.len() > size {
-			self.data.resize(size, T::default());
-		} else {
-			self.data.reserve_exact(size - self.data.len());
-			self.data.resize(size, T::default());
-		}
-	}
+	/// Returns the underlying data in storage order.
+	pub fn into_data(self) -> Vec<T> { self.data }
+
+	/// Number of elements the storage can hold without reallocating.
+	pub fn capacity(&self) -> usize { self.data.capacity() }
+
+	/// Releases the capacity reserved for growing.
+	pub fn shrink_to_fit(&mut self) { self.data.shrink_to_fit() }
 }
 
 impl<T, R, C> StorageSize for VecStorageCM<T, R, C>
@@ -69,30 +82,70 @@ impl<T, R> DynamicColStorage<T> for VecStorageCM<T, R, Dynamic>
 	where T: Element, R: Dim
 {
 	fn set_cols(&mut self, count: usize) {
-		unsafe {self.resize_element_count(count * self.rows())};
+		self.data.resize(count * self.rows(), T::default());
+		self.size.cols = Dynamic::from(count);
+	}
+
+	fn insert_cols<S: Storage<T>>(&mut self, index: usize, values: &S) {
+		assert_eq!(values.rows(), self.rows(), "Inserted columns must have the same number of rows!");
+		assert!(index <= self.cols(), "Insert position out of bounds!");
+		let lane_len = self.rows();
+		insert_lanes(&mut self.data, lane_len, index, values.cols(), values.as_col_iter().cloned());
+		self.size.cols = Dynamic::from(self.cols() + values.cols());
+	}
+
+	fn remove_cols<RR: SliceRange>(&mut self, range: RR) {
+		assert!(range.begin() <= range.end() && range.end() <= self.cols(), "Remove range out of bounds!");
+		let count = self.cols() - (range.end() - range.begin());
+		let lane_len = self.rows();
+		remove_lanes(&mut self.data, lane_len, range);
 		self.size.cols = Dynamic::from(count);
 	}
 }
 
+/// Changing the number of rows shifts every column within the data. Capacity grows geometrically, but each
+/// call still copies all elements.
 impl<T, C> DynamicRowStorage<T> for VecStorageCM<T, Dynamic, C>
 	where T: Element, C: Dim
 {
 	fn set_rows(&mut self, count: usize) {
-		if count == self.cols() { return; }
-
-		let mut new_data = vec![T::default(); self.cols() * count];
-		let copy_size = min(self.cols(), count);
-		for ci in 0..self.cols() {
-			let to = &mut new_data[ci * count..ci * count + copy_size];
-			let from = &self.data[ci * self.rows()..ci * self.rows() + copy_size];
-			to.clone_from_slice(from)
+		let (cols, rows) = (self.cols(), self.rows());
+		if count > rows {
+			insert_lane_elements(&mut self.data, cols, rows, rows, count - rows, |_, _| T::default());
+		} else {
+			remove_lane_elements(&mut self.data, cols, rows, count..rows);
 		}
+		self.size.rows = Dynamic::from(count);
+	}
+
+	fn insert_rows<S: Storage<T>>(&mut self, index: usize, values: &S) {
+		assert_eq!(values.cols(), self.cols(), "Inserted rows must have the same number of columns!");
+		let (cols, rows) = (self.cols(), self.rows());
+		insert_lane_elements(&mut self.data, cols, rows, index, values.rows(), |lane, k| values.get(k, lane));
+		self.size.rows = Dynamic::from(rows + values.rows());
+	}
 
-		self.data = new_data;
+	fn remove_rows<RR: SliceRange>(&mut self, range: RR) {
+		let (cols, rows) = (self.cols(), self.rows());
+		assert!(range.begin() <= range.end() && range.end() <= rows, "Remove range out of bounds!");
+		let count = rows - (range.end() - range.begin());
+		remove_lane_elements(&mut self.data, cols, rows, range);
 		self.size.rows = Dynamic::from(count);
 	}
 }
 
+impl<T, R> VecStorageCM<T, R, Dynamic>
+	where T: Element, R: Dim
+{
+	/// Keeps the first `count` columns. Does nothing if there are fewer columns.
+	pub fn truncate(&mut self, count: usize) {
+		if count < self.cols() { self.set_cols(count) }
+	}
+
+	/// Reserves capacity for at least `additional` more columns.
+	pub fn reserve(&mut self, additional: usize) { self.data.reserve(additional * self.rows()) }
+}
+
 impl<T, R, C> StorageConstructor<T> for VecStorageCM<T, R, C>
 	where T: Element, R: Dim, C: Dim
 {
@@ -169,3 +222,21 @@ impl<T, R, C> IndexMut<usize> for VecStorageCM<T, R, C>
 		unsafe { &mut *self.as_ptr_mut().offset(index as isize) }
 	}
 }
+
+#[cfg(feature = "serde-serialize")]
+impl<T, R, C> Serialize for VecStorageCM<T, R, C>
+	where T: Element + Serialize, R: Dim, C: Dim
+{
+	fn serialize<SE: Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> {
+		crate::storage::serialize_storage(self, serializer)
+	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, T, R, C> Deserialize<'de> for VecStorageCM<T, R, C>
+	where T: Element + Deserialize<'de>, R: Dim, C: Dim
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		crate::storage::deserialize_storage(deserializer)
+	}
+}
\ No newline at end of file
@@ -1,11 +1,14 @@
 use crate::format::*;
 use crate::storage::{Storage, StorageMut, DynamicRowStorage, DynamicColStorage, StorageConstructor, Ownable};
-use std::cmp::min;
-use crate::{InplaceMap, InplaceMapOrdered, Container, InplaceForeach};
+use crate::storage::{insert_lanes, remove_lanes, insert_lane_elements, remove_lane_elements};
+use crate::slice::SliceRange;
+use crate::{InplaceMap, InplaceMapOrdered, Container, InplaceForeach, check_transmute};
 use std::ops::{Index, IndexMut};
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[repr(C)]
-#[derive(Eq, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct VecStorageRM<T, R, C>
 	where T: Element, R: Dim, C: Dim
 {
@@ -13,6 +16,16 @@ pub struct VecStorageRM<T, R, C>
 	size: Size<R, C>
 }
 
+impl<T, R, C, SO> PartialEq<SO> for VecStorageRM<T, R, C>
+	where T: Element + PartialEq, R: Dim, C: Dim, SO: Storage<T>
+{
+	fn eq(&self, other: &SO) -> bool { self.equals(other) }
+}
+
+impl<T, R, C> Eq for VecStorageRM<T, R, C>
+	where T: Element + Eq, R: Dim, C: Dim
+{}
+
 impl<T, R, C> VecStorageRM<T, R, C>
 	where T: Element, R: Dim, C: Dim
 {
@@ -21,14 +34,35 @@ impl<T, R, C> VecStorageRM<T, R, C>
 		Self { data, size }
 	}
 
-	unsafe fn resize_element_count(&mut self, size: usize) {
-		if self.data.len() > size {
-			self.data.resize(size, T::default());
+	/// Adopts data laid out with arbitrary strides. Elements are compacted into row major order
+	/// within the same allocation when rows are stored in order, otherwise they are copied.
+	/// Panics if the strided elements do not fit into the data.
+	pub fn from_vec_unchecked_layout<RS: Dim, CS: Dim>(size: Size<R, C>, strides: Strides<RS, CS>, mut data: Vec<T>) -> Self {
+		check_transmute(&size, &strides, data.len()).unwrap_or_else(|e| panic!("{}", e));
+		let (rows, cols) = (size.rows(), size.cols());
+		let (rs, cs) = (strides.row_stride(), strides.col_stride());
+		if (rows <= 1 || rs >= cols) && (cols <= 1 || cs >= 1) {
+			// Source offset is never behind the destination so a forward pass can't clobber unread values
+			for r in 0..rows {
+				for c in 0..cols {
+					data[r * cols + c] = data[r * rs + c * cs];
+				}
+			}
+			data.truncate(size.len());
 		} else {
-			self.data.reserve_exact(size - self.data.len());
-			self.data.resize(size, T::default());
+			data = (0..rows).flat_map(|r| (0..cols).map(move |c| r * rs + c * cs)).map(|i| data[i]).collect();
 		}
+		Self { data, size }
 	}
+
+	/// Returns the underlying data in storage order.
+	pub fn into_data(self) -> Vec<T> { self.data }
+
+	/// Number of elements the storage can hold without reallocating.
+	pub fn capacity(&self) -> usize { self.data.capacity() }
+
+	/// Releases the capacity reserved for growing.
+	pub fn shrink_to_fit(&mut self) { self.data.shrink_to_fit() }
 }
 
 impl<T, R, C> StorageSize for VecStorageRM<T, R, C>
@@ -69,30 +103,70 @@ impl<T, C> DynamicRowStorage<T> for VecStorageRM<T, Dynamic, C>
 	where T: Element, C: Dim
 {
 	fn set_rows(&mut self, count: usize) {
-		unsafe {self.resize_element_count(count * self.cols())};
+		self.data.resize(count * self.cols(), T::default());
+		self.size.rows = Dynamic::from(count);
+	}
+
+	fn insert_rows<S: Storage<T>>(&mut self, index: usize, values: &S) {
+		assert_eq!(values.cols(), self.cols(), "Inserted rows must have the same number of columns!");
+		assert!(index <= self.rows(), "Insert position out of bounds!");
+		let lane_len = self.cols();
+		insert_lanes(&mut self.data, lane_len, index, values.rows(), values.as_row_iter().cloned());
+		self.size.rows = Dynamic::from(self.rows() + values.rows());
+	}
+
+	fn remove_rows<RR: SliceRange>(&mut self, range: RR) {
+		assert!(range.begin() <= range.end() && range.end() <= self.rows(), "Remove range out of bounds!");
+		let count = self.rows() - (range.end() - range.begin());
+		let lane_len = self.cols();
+		remove_lanes(&mut self.data, lane_len, range);
 		self.size.rows = Dynamic::from(count);
 	}
 }
 
+/// Changing the number of columns shifts every row within the data. Capacity grows geometrically, but each
+/// call still copies all elements.
 impl<T, R> DynamicColStorage<T> for VecStorageRM<T, R, Dynamic>
 	where T: Element, R: Dim
 {
 	fn set_cols(&mut self, count: usize) {
-		if count == self.cols() { return; }
-
-		let mut new_data = vec![T::default(); self.rows() * count];
-		let copy_size = min(self.cols(), count);
-		for ri in 0..self.rows() {
-			let to = &mut new_data[ri * count..ri * count + copy_size];
-			let from = &self.data[ri * self.cols()..ri * self.cols() + copy_size];
-			to.clone_from_slice(from)
+		let (rows, cols) = (self.rows(), self.cols());
+		if count > cols {
+			insert_lane_elements(&mut self.data, rows, cols, cols, count - cols, |_, _| T::default());
+		} else {
+			remove_lane_elements(&mut self.data, rows, cols, count..cols);
 		}
+		self.size.cols = Dynamic::from(count);
+	}
 
-		self.data = new_data;
+	fn insert_cols<S: Storage<T>>(&mut self, index: usize, values: &S) {
+		assert_eq!(values.rows(), self.rows(), "Inserted columns must have the same number of rows!");
+		let (rows, cols) = (self.rows(), self.cols());
+		insert_lane_elements(&mut self.data, rows, cols, index, values.cols(), |lane, k| values.get(lane, k));
+		self.size.cols = Dynamic::from(cols + values.cols());
+	}
+
+	fn remove_cols<RR: SliceRange>(&mut self, range: RR) {
+		let (rows, cols) = (self.rows(), self.cols());
+		assert!(range.begin() <= range.end() && range.end() <= cols, "Remove range out of bounds!");
+		let count = cols - (range.end() - range.begin());
+		remove_lane_elements(&mut self.data, rows, cols, range);
 		self.size.cols = Dynamic::from(count);
 	}
 }
 
+impl<T, C> VecStorageRM<T, Dynamic, C>
+	where T: Element, C: Dim
+{
+	/// Keeps the first `count` rows. Does nothing if there are fewer rows.
+	pub fn truncate(&mut self, count: usize) {
+		if count < self.rows() { self.set_rows(count) }
+	}
+
+	/// Reserves capacity for at least `additional` more rows.
+	pub fn reserve(&mut self, additional: usize) { self.data.reserve(additional * self.cols()) }
+}
+
 impl<T, R, C> StorageConstructor<T> for VecStorageRM<T, R, C>
 	where T: Element, R: Dim, C: Dim
 {
@@ -166,4 +240,22 @@ impl<T, R, C> IndexMut<usize> for VecStorageRM<T, R, C>
 		assert!(index < self.len(), "Index out of bounds");
 		unsafe { &mut *self.as_ptr_mut().offset(index as isize) }
 	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<T, R, C> Serialize for VecStorageRM<T, R, C>
+	where T: Element + Serialize, R: Dim, C: Dim
+{
+	fn serialize<SE: Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> {
+		crate::storage::serialize_storage(self, serializer)
+	}
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, T, R, C> Deserialize<'de> for VecStorageRM<T, R, C>
+	where T: Element + Deserialize<'de>, R: Dim, C: Dim
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		crate::storage::deserialize_storage(deserializer)
+	}
 }
\ No newline at end of file
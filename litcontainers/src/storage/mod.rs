@@ -8,6 +8,8 @@ pub mod transpose;
 pub mod printing;
 pub mod simple_types;
 pub mod utils;
+#[cfg(feature = "serde-serialize")]
+pub mod serialization;
 
 #[doc(inline)] pub use storage::*;
 #[doc(inline)] pub use mutable::*;
@@ -18,4 +20,6 @@ pub mod utils;
 #[doc(inline)] pub use transpose::*;
 #[doc(inline)] pub use printing::*;
 #[doc(inline)] pub use simple_types::*;
-#[doc(inline)] pub use utils::*;
\ No newline at end of file
+#[doc(inline)] pub use utils::*;
+#[cfg(feature = "serde-serialize")]
+#[doc(inline)] pub use serialization::*;
\ No newline at end of file
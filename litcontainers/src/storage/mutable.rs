@@ -22,11 +22,17 @@ pub trait StorageMut<T>: Storage<T> + InplaceMap<T> + InplaceMapOrdered<T>
 
 	#[inline]
 	fn get_mut(&mut self, r: usize, c: usize) -> &mut T {
-		assert!(r < self.rows(), "Out of range row!");
-		assert!(c < self.cols(), "Out of range col!");
-		unsafe { self.get_mut_unchecked(r, c) }
+		self.try_get_mut(r, c).unwrap_or_else(|e| panic!("{}", e))
 	}
 
+	#[inline]
+	fn try_get_mut(&mut self, r: usize, c: usize) -> ShapeResult<&mut T> {
+		self.check_position(r, c)?;
+		Ok(unsafe { self.get_mut_unchecked(r, c) })
+	}
+
+	/// # Safety
+	/// `r` and `c` must be within the rows and cols of the storage.
 	#[inline]
 	unsafe fn get_mut_unchecked(&mut self, r: usize, c: usize) -> &mut T {
 		&mut *self.as_ptr_mut().offset(self.get_index(r, c) as isize)
@@ -34,26 +40,51 @@ pub trait StorageMut<T>: Storage<T> + InplaceMap<T> + InplaceMapOrdered<T>
 
 	#[inline]
 	fn get_ptr_mut(&mut self, r: usize, c: usize) -> *mut T {
-		assert!(r < self.rows(), "Out of range row!");
-		assert!(c < self.cols(), "Out of range col!");
-		unsafe { self.as_ptr_mut().offset(self.get_index(r, c) as isize) }
+		self.try_get_ptr_mut(r, c).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	#[inline]
+	fn try_get_ptr_mut(&mut self, r: usize, c: usize) -> ShapeResult<*mut T> {
+		self.check_position(r, c)?;
+		Ok(unsafe { self.get_ptr_mut_unchecked(r, c) })
+	}
+
+	/// # Safety
+	/// `r` and `c` must be within the rows and cols of the storage.
+	#[inline]
+	unsafe fn get_ptr_mut_unchecked(&mut self, r: usize, c: usize) -> *mut T {
+		self.as_ptr_mut().offset(self.get_index(r, c) as isize)
 	}
 
 	#[inline]
 	fn as_row_ptr_mut(&mut self, p: usize) -> *mut T {
-		assert!(p < self.rows(), "Row out of bounds!");
-		unsafe { self.as_row_ptr_mut_unchecked(p) }
+		self.try_as_row_ptr_mut(p).unwrap_or_else(|e| panic!("{}", e))
 	}
 
+	#[inline]
+	fn try_as_row_ptr_mut(&mut self, p: usize) -> ShapeResult<*mut T> {
+		ShapeError::check_index(AxisType::Row, p, self.rows())?;
+		Ok(unsafe { self.as_row_ptr_mut_unchecked(p) })
+	}
+
+	/// # Safety
+	/// Row index must be within the rows of the storage.
 	#[inline]
 	unsafe fn as_row_ptr_mut_unchecked(&mut self, p: usize) -> *mut T { self.as_ptr_mut().offset(self.row_index(p) as isize) }
 
 	#[inline]
 	fn as_col_ptr_mut(&mut self, v: usize) -> *mut T {
-		assert!(v < self.cols(), "Col out of bounds!");
-		unsafe { self.as_col_ptr_mut_unchecked(v) }
+		self.try_as_col_ptr_mut(v).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	#[inline]
+	fn try_as_col_ptr_mut(&mut self, v: usize) -> ShapeResult<*mut T> {
+		ShapeError::check_index(AxisType::Col, v, self.cols())?;
+		Ok(unsafe { self.as_col_ptr_mut_unchecked(v) })
 	}
 
+	/// # Safety
+	/// Col index must be within the cols of the storage.
 	#[inline]
 	unsafe fn as_col_ptr_mut_unchecked(&mut self, p: usize) -> *mut T { self.as_ptr_mut().offset(self.col_index(p) as isize) }
 
@@ -84,10 +115,18 @@ pub trait StorageMut<T>: Storage<T> + InplaceMap<T> + InplaceMapOrdered<T>
 	#[inline]
 	fn copy_from<SO: Storage<T>>(&mut self, from: &SO)
 	{
-		assert!(self.equal_size(from), "Slice is out of bounds!");
+		self.try_copy_from(from).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Copies all elements from given storage. Fails if the sizes don't match.
+	#[inline]
+	fn try_copy_from<SO: Storage<T>>(&mut self, from: &SO) -> ShapeResult<()>
+	{
+		ShapeError::check_size(self, from)?;
 		for (t, f) in self.as_iter_mut().zip(from.as_iter()) {
 			*t = *f;
 		}
+		Ok(())
 	}
 }
 
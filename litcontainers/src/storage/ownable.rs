@@ -1,6 +1,10 @@
 use crate::format::*;
-use crate::storage::StorageMut;
-use crate::{InplaceMap, Container};
+use crate::storage::{Storage, StorageMut, StorageConstructor, VecStorageRM, ArrayStorageRM};
+use crate::{InplaceMap, Container, ContainerOwnedRM};
+use generic_array::ArrayLength;
+use std::any::Any;
+use std::fmt::Debug;
+use typenum::{Bit, Unsigned, UInt, UTerm, B1};
 
 /// Type can be turned or cloned into a container which owns its data.
 pub trait Ownable<T: Element> {
@@ -12,4 +16,105 @@ pub trait Ownable<T: Element> {
 
 	/// Clones it's data into a container which owns its data.
 	fn clone_owned(&self) -> Container<T, Self::OwnedType>;
-}
\ No newline at end of file
+}
+
+/// Storage owning its elements. Used as the owned type of storages which are views.
+pub trait OwnedStorage<T: Element, R: Dim, C: Dim>:
+	StorageMut<T> + StorageSize<Rows=R, Cols=C> + StorageConstructor<T> + Ownable<T, OwnedType=Self> +
+	Clone + Debug + Send + Sync
+{}
+
+impl<T, R, C, S> OwnedStorage<T, R, C> for S
+	where T: Element, R: Dim, C: Dim,
+	      S: StorageMut<T> + StorageSize<Rows=R, Cols=C> + StorageConstructor<T> + Ownable<T, OwnedType=S> +
+	      Clone + Debug + Send + Sync
+{}
+
+/// Copies a storage into the owned row major storage selected by `OwnedDims`.
+pub(crate) fn clone_owned_rm<T, S>(s: &S) -> ContainerOwnedRM<T, S::Rows, S::Cols>
+	where T: Element, S: Storage<T>, S::Rows: OwnedDims<S::Cols>
+{
+	let mut ret = <S::Rows as OwnedDims<S::Cols>>::OwnedRM::<T>::zeros(s.size());
+	for (o, v) in ret.as_iter_mut().zip(s.as_iter()) { *o = *v; }
+	ret.into()
+}
+
+/// Type-level integer which can be used as the length of a generic array.
+pub trait ArrayDim: DimName {
+	type Length<E>: ArrayLength<E>;
+}
+
+/// Selects the owned row major storage for `Self` rows and `C` columns. Implemented for every pair of dimensions, slices
+/// use it in `owned` and `clone_owned` so that small static sizes are copied into an `ArrayStorageRM` instead of
+/// allocating.
+pub trait OwnedDims<C: Dim>: Dim {
+	type OwnedRM<T: Element>: OwnedStorage<T, Self, C>;
+}
+
+/// Owned row major storage with `Self` columns and a small static number of rows `R`. Used by `OwnedDims` to pick the
+/// storage once the rows are known to be small.
+pub trait OwnedSmallRows<R: ArrayDim>: Dim {
+	type OwnedRM<T: Element>: OwnedStorage<T, R, Self>;
+}
+
+impl<C: Dim> OwnedDims<C> for Dynamic {
+	type OwnedRM<T: Element> = VecStorageRM<T, Dynamic, C>;
+}
+
+impl<R: ArrayDim> OwnedSmallRows<R> for Dynamic {
+	type OwnedRM<T: Element> = VecStorageRM<T, R, Dynamic>;
+}
+
+macro_rules! owned_dims (
+	(inline: $($D: ident),* $(,)*) => {$(
+		impl ArrayDim for $D {
+			type Length<E> = typenum::$D;
+		}
+
+		impl<C: OwnedSmallRows<$D>> OwnedDims<C> for $D {
+			type OwnedRM<T: Element> = <C as OwnedSmallRows<$D>>::OwnedRM<T>;
+		}
+
+		impl<R: ArrayDim> OwnedSmallRows<R> for $D {
+			type OwnedRM<T: Element> = ArrayStorageRM<T, R, $D>;
+		}
+	)*};
+	(heap: $($D: ident),* $(,)*) => {$(
+		impl ArrayDim for $D {
+			type Length<E> = typenum::$D;
+		}
+
+		owned_dims!(@heap $D; );
+	)*};
+	(@heap $D: ty; $($bounds: tt)*) => {
+		impl<C: Dim, $($bounds)*> OwnedDims<C> for $D {
+			type OwnedRM<T: Element> = VecStorageRM<T, $D, C>;
+		}
+
+		impl<R: ArrayDim, $($bounds)*> OwnedSmallRows<R> for $D {
+			type OwnedRM<T: Element> = VecStorageRM<T, R, $D>;
+		}
+	};
+);
+
+// Owned storages of dimensions up to U16 keep their elements inline, larger ones are allocated on the heap.
+owned_dims!(inline: U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16);
+owned_dims!(heap:
+	U17, U18, U19, U20, U21, U22, U23, U24, U25, U26, U27, U28, U29, U30, U31, U32, U33, U34, U35,
+	U36, U37, U38, U39, U40, U41, U42, U43, U44, U45, U46, U47, U48, U49, U50, U51, U52, U53, U54,
+	U55, U56, U57, U58, U59, U60, U61, U62, U63, U64, U65, U66, U67, U68, U69, U70, U71, U72, U73,
+	U74, U75, U76, U77, U78, U79, U80, U81, U82, U83, U84, U85, U86, U87, U88, U89, U90, U91, U92,
+	U93, U94, U95, U96, U97, U98, U99, U100, U101, U102, U103, U104, U105, U106, U107, U108, U109,
+	U110, U111, U112, U113, U114, U115, U116, U117, U118, U119, U120, U121, U122, U123, U124, U125,
+	U126, U127
+);
+owned_dims!(@heap UInt<UInt<UInt<UInt<UInt<UInt<UInt<UInt<UTerm, B1>, A>, B>, CB>, D>, E>, F>, G>;
+	A: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+	B: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+	CB: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+	D: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+	E: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+	F: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+	G: Bit + Any + Debug + Copy + PartialEq + Send + Sync,
+);
+owned_dims!(@heap UInt<U, B>; U: Unsigned + DimName, B: Bit + Any + Debug + Copy + PartialEq + Send + Sync);
\ No newline at end of file
@@ -1,48 +1,191 @@
 use std::fmt::{Formatter, Error};
+use std::fmt;
+use std::iter;
+use std::marker::PhantomData;
 use crate::format::*;
 use num_traits::cast::cast;
 use crate::Storage;
 
-pub fn print_storage<T, S>(s: &S, f: &mut Formatter) -> Result<(), Error>
+/// When to print numeric elements in scientific notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SciMode {
+	/// Used for complex elements and when any printed element is far from one in magnitude.
+	Auto,
+	Always,
+	Never,
+}
+
+/// Options used when printing storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintOptions {
+	/// Number of digits after the decimal point.
+	pub precision: usize,
+	/// Storages with more elements than this are summarized by printing only the edge rows and columns.
+	pub threshold: usize,
+	/// Number of rows and columns printed at each edge of a summarized storage.
+	pub edge_items: usize,
+	/// Maximum width of a printed row. Middle columns are elided when a row would exceed it.
+	pub line_width: usize,
+	pub sci_mode: SciMode,
+	/// Whether to print the element type, size and strides before the elements.
+	pub header: bool,
+}
+
+impl PrintOptions {
+	pub fn new() -> Self { Self::default() }
+
+	pub fn with_precision(mut self, precision: usize) -> Self {
+		self.precision = precision;
+		self
+	}
+
+	pub fn with_threshold(mut self, threshold: usize) -> Self {
+		self.threshold = threshold;
+		self
+	}
+
+	pub fn with_edge_items(mut self, edge_items: usize) -> Self {
+		self.edge_items = edge_items;
+		self
+	}
+
+	pub fn with_line_width(mut self, line_width: usize) -> Self {
+		self.line_width = line_width;
+		self
+	}
+
+	pub fn with_sci_mode(mut self, sci_mode: SciMode) -> Self {
+		self.sci_mode = sci_mode;
+		self
+	}
+
+	pub fn with_header(mut self, header: bool) -> Self {
+		self.header = header;
+		self
+	}
+}
+
+impl Default for PrintOptions {
+	fn default() -> Self {
+		Self { precision: 4, threshold: 1000, edge_items: 3, line_width: 120, sci_mode: SciMode::Auto, header: true }
+	}
+}
+
+/// Displays storage with given print options. Created by `Container::display_with`.
+pub struct DisplayWith<'a, T, S>
 	where T: Element, S: Storage<T>
 {
-	let use_sci_fmt = match T::numeric() {
-		false => false,
-		true => {
+	storage: &'a S,
+	options: PrintOptions,
+	_phantoms: PhantomData<T>,
+}
+
+impl<'a, T, S> DisplayWith<'a, T, S>
+	where T: Element, S: Storage<T>
+{
+	pub fn new(storage: &'a S, options: PrintOptions) -> Self {
+		Self { storage, options, _phantoms: PhantomData }
+	}
+}
+
+impl<'a, T, S> fmt::Display for DisplayWith<'a, T, S>
+	where T: Element, S: Storage<T>
+{
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		print_storage_with(self.storage, f, &self.options)
+	}
+}
+
+/// Indices printed along an axis. `None` marks the elided middle.
+fn shown_indices(len: usize, edge_items: usize, summarize: bool) -> Vec<Option<usize>> {
+	if !summarize || len <= 2 * edge_items {
+		(0..len).map(Some).collect()
+	} else {
+		(0..edge_items).map(Some)
+			.chain(iter::once(None))
+			.chain((len - edge_items..len).map(Some))
+			.collect()
+	}
+}
+
+fn use_sci<T: Element>(values: &[T], mode: SciMode) -> bool {
+	if !T::numeric() { return false; }
+	match mode {
+		SciMode::Always => true,
+		SciMode::Never => false,
+		SciMode::Auto => {
 			if T::NumericType::is_complex() { true }
 			else if T::NumericType::is_float() {
-				s.as_iter().any(|x| {
+				values.iter().any(|x| {
 					let n = T::NumericType::from(*x).as_scalar();
 					n >= cast(100).unwrap() || n <= cast(-100).unwrap()
 						|| (n < cast(0.0001).unwrap() && n >= cast(-0.0001).unwrap()
 						&& n != cast(0.0).unwrap())
 				})
 			} else {
-				s.as_iter().any(|x| {
+				values.iter().any(|x| {
 					let n = T::NumericType::from(*x).as_scalar();
 					n >= cast(100).unwrap() || n <= cast(-100).unwrap()
 				})
 			}
 		}
-	};
-
-	let padding = if T::NumericType::is_complex() { 27 } else if use_sci_fmt { 13 } else { 8 };
-
-	writeln!(
-		f,
-		"Storage[Type = {:#?}, {}, {}] => ",
-		T::element_type(),
-		s.size(),
-		s.strides()
-	)?;
-	for i in 0..s.rows() {
-		for e in s.as_row_range_iter(i) {
-			write!(f, "{:>pad$}", format!("{}", Fmt(|f| e.fmt_elem(f, 4, use_sci_fmt))), pad = padding)?;
+	}
+}
+
+pub fn print_storage<T, S>(s: &S, f: &mut Formatter) -> Result<(), Error>
+	where T: Element, S: Storage<T>
+{
+	print_storage_with(s, f, &PrintOptions::default())
+}
+
+/// Prints the storage row by row. Large storages are summarized with `...` in place of the middle rows and columns.
+pub fn print_storage_with<T, S>(s: &S, f: &mut Formatter, options: &PrintOptions) -> Result<(), Error>
+	where T: Element, S: Storage<T>
+{
+	let summarize = s.len() > options.threshold;
+	let rows = shown_indices(s.rows(), options.edge_items, summarize);
+	let cols = shown_indices(s.cols(), options.edge_items, summarize);
+
+	let shown: Vec<T> = rows.iter().filter_map(|r| *r)
+		.flat_map(|r| cols.iter().filter_map(|c| *c).map(move |c| (r, c)))
+		.map(|(r, c)| s.get(r, c))
+		.collect();
+	let use_sci_fmt = use_sci(&shown, options.sci_mode);
+
+	let mut cells: Vec<Vec<String>> = rows.iter().map(|r| match r {
+		Some(r) => cols.iter().map(|c| match c {
+			Some(c) => format!("{}", Fmt(|f| s.get(*r, *c).fmt_elem(f, options.precision, use_sci_fmt))),
+			None => "...".to_string(),
+		}).collect(),
+		None => vec!["...".to_string(); cols.len()],
+	}).collect();
+	let width = cells.iter().flatten().map(|c| c.chars().count()).max().unwrap_or(0) + 2;
+
+	let max_cols = (options.line_width / width).max(3);
+	if cols.len() > max_cols {
+		let edge = (max_cols - 1) / 2;
+		for row in cells.iter_mut() {
+			let tail = row.split_off(row.len() - edge);
+			row.truncate(edge);
+			row.push("...".to_string());
+			row.extend(tail);
 		}
-		write!(f, "\n")?;
 	}
 
-	// TODO: fixed size scientific format
-	// TODO: cut some rows on super large containers
+	if options.header {
+		writeln!(
+			f,
+			"Storage[Type = {:#?}, {}, {}] => ",
+			T::element_type(),
+			s.size(),
+			s.strides()
+		)?;
+	}
+	for row in cells {
+		for cell in row {
+			write!(f, "{:>pad$}", cell, pad = width)?;
+		}
+		writeln!(f)?;
+	}
 	Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,52 @@
+use crate::format::*;
+use crate::storage::{Storage, StorageConstructor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+use serde::ser::{SerializeSeq, SerializeStruct};
+
+/// Serializes elements of the storage in row major order.
+struct RowMajorData<'a, T: Element, S: Storage<T>>(&'a S, std::marker::PhantomData<T>);
+
+impl<'a, T, S> Serialize for RowMajorData<'a, T, S>
+	where T: Element + Serialize, S: Storage<T>
+{
+	fn serialize<SE: Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> {
+		let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+		for v in self.0.as_iter() {
+			seq.serialize_element(v)?;
+		}
+		seq.end()
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Storage", bound(deserialize = "T: Deserialize<'de>"))]
+struct StorageDef<R: Dim, C: Dim, T> {
+	size: Size<R, C>,
+	data: Vec<T>,
+}
+
+/// Serializes any storage as its size and elements in row major order. Layout and strides are not
+/// stored, so storage can be deserialized into any owned storage of the same size.
+pub fn serialize_storage<T, S, SE>(storage: &S, serializer: SE) -> Result<SE::Ok, SE::Error>
+	where T: Element + Serialize, S: Storage<T>, SE: Serializer
+{
+	let mut st = serializer.serialize_struct("Storage", 2)?;
+	st.serialize_field("size", &storage.size())?;
+	st.serialize_field("data", &RowMajorData(storage, std::marker::PhantomData))?;
+	st.end()
+}
+
+/// Deserializes storage written by `serialize_storage`. Fails if the size doesn't match static
+/// dimensions of the storage or the number of elements.
+pub fn deserialize_storage<'de, T, S, D>(deserializer: D) -> Result<S, D::Error>
+	where T: Element + Deserialize<'de>, S: Storage<T> + StorageConstructor<T>, D: Deserializer<'de>
+{
+	let def = StorageDef::<S::Rows, S::Cols, T>::deserialize(deserializer)?;
+	let len = def.size.rows().checked_mul(def.size.cols())
+		.ok_or_else(|| D::Error::custom(format!("Element count of {}x{} storage overflows", def.size.rows(), def.size.cols())))?;
+	if len != def.data.len() {
+		return Err(D::Error::invalid_length(def.data.len(), &format!("{} elements", len).as_str()));
+	}
+	Ok(S::from_vec(def.size, &def.data))
+}
@@ -7,7 +7,6 @@ use std::slice;
 use crate::{Sliceable, Container};
 use std::ops::Index;
 
-// TODO: implement proper equality?
 pub trait Storage<T>: StorageSize + Strided + Debug + Sized + Ownable<T> + Send + Sync + InplaceForeach<T> + Index<usize, Output=T>
 	where T: Element
 {
@@ -21,23 +20,43 @@ pub trait Storage<T>: StorageSize + Strided + Debug + Sized + Ownable<T> + Send
 
 	#[inline]
 	fn get(&self, r: usize, c: usize) -> T {
-		assert!(r < self.rows(), "Out of range row!");
-		assert!(c < self.cols(), "Out of range col!");
-		unsafe { self.get_unchecked(r, c) }
+		self.try_get(r, c).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// Returns the element at given position or an error if the position is out of bounds.
+	#[inline]
+	fn try_get(&self, r: usize, c: usize) -> ShapeResult<T> {
+		self.check_position(r, c)?;
+		Ok(unsafe { self.get_unchecked(r, c) })
+	}
+
+	/// Checks whether the position lies within the storage.
+	#[inline]
+	fn check_position(&self, r: usize, c: usize) -> ShapeResult<()> {
+		ShapeError::check_index(AxisType::Row, r, self.rows())?;
+		ShapeError::check_index(AxisType::Col, c, self.cols())
 	}
 
 	#[inline]
 	fn get_ptr(&self, r: usize, c: usize) -> *const T {
-		assert!(r < self.rows(), "Out of range row!");
-		assert!(c < self.cols(), "Out of range col!");
-		unsafe { self.get_ptr_unchecked(r, c) }
+		self.try_get_ptr(r, c).unwrap_or_else(|e| panic!("{}", e))
 	}
 
+	#[inline]
+	fn try_get_ptr(&self, r: usize, c: usize) -> ShapeResult<*const T> {
+		self.check_position(r, c)?;
+		Ok(unsafe { self.get_ptr_unchecked(r, c) })
+	}
+
+	/// # Safety
+	/// `r` and `c` must be within the rows and cols of the storage.
 	#[inline]
 	unsafe fn get_ptr_unchecked(&self, r: usize, c: usize) -> *const T {
 		self.as_ptr().offset(self.get_index(r, c) as isize)
 	}
 
+	/// # Safety
+	/// `r` and `c` must be within the rows and cols of the storage.
 	#[inline]
 	unsafe fn get_unchecked(&self, r: usize, c: usize) -> T {
 		*self.as_ptr().offset(self.get_index(r, c) as isize)
@@ -45,29 +64,49 @@ pub trait Storage<T>: StorageSize + Strided + Debug + Sized + Ownable<T> + Send
 
 	#[inline]
 	fn get_ref(&self, r: usize, c: usize) -> &T {
-		assert!(r < self.rows(), "Out of range row!");
-		assert!(c < self.cols(), "Out of range col!");
-		unsafe { self.get_ref_unchecked(r, c) }
+		self.try_get_ref(r, c).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	#[inline]
+	fn try_get_ref(&self, r: usize, c: usize) -> ShapeResult<&T> {
+		self.check_position(r, c)?;
+		Ok(unsafe { self.get_ref_unchecked(r, c) })
 	}
 
+	/// # Safety
+	/// `r` and `c` must be within the rows and cols of the storage.
 	#[inline]
 	unsafe fn get_ref_unchecked(&self, r: usize, c: usize) -> &T { &*self.as_ptr().offset(self.get_index(r, c) as isize) }
 
 	#[inline]
 	fn as_row_ptr(&self, p: usize) -> *const T {
-		assert!(p < self.rows(), "Row out of bounds!");
-		unsafe { self.as_row_ptr_unchecked(p) }
+		self.try_as_row_ptr(p).unwrap_or_else(|e| panic!("{}", e))
 	}
 
+	#[inline]
+	fn try_as_row_ptr(&self, p: usize) -> ShapeResult<*const T> {
+		ShapeError::check_index(AxisType::Row, p, self.rows())?;
+		Ok(unsafe { self.as_row_ptr_unchecked(p) })
+	}
+
+	/// # Safety
+	/// Row index must be within the rows of the storage.
 	#[inline]
 	unsafe fn as_row_ptr_unchecked(&self, p: usize) -> *const T { self.as_ptr().offset(self.row_index(p) as isize) }
 
 	#[inline]
 	fn as_col_ptr(&self, v: usize) -> *const T {
-		assert!(v < self.cols(), "Col out of bounds!");
-		unsafe { self.as_col_ptr_unchecked(v) }
+		self.try_as_col_ptr(v).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	#[inline]
+	fn try_as_col_ptr(&self, v: usize) -> ShapeResult<*const T> {
+		ShapeError::check_index(AxisType::Col, v, self.cols())?;
+		Ok(unsafe { self.as_col_ptr_unchecked(v) })
 	}
 
+	/// # Safety
+	/// Col index must be within the cols of the storage.
 	#[inline]
 	unsafe fn as_col_ptr_unchecked(&self, p: usize) -> *const T { self.as_ptr().offset(self.col_index(p) as isize) }
 
@@ -98,6 +137,23 @@ pub trait Storage<T>: StorageSize + Strided + Debug + Sized + Ownable<T> + Send
 		FullIter::from_storage_range(self, ColAxis, range)
 	}
 
+	// Comparison
+	/// Returns the position of the first element pair for which `eq` is false. Elements are compared in row major
+	/// order, so layout and strides of the storages don't matter.
+	fn find_mismatch<SO, F>(&self, other: &SO, mut eq: F) -> Option<(usize, usize)>
+		where SO: Storage<T>, F: FnMut(&T, &T) -> bool
+	{
+		let cols = self.cols();
+		self.as_iter().zip(other.as_iter()).position(|(a, b)| !eq(a, b)).map(|i| (i / cols, i % cols))
+	}
+
+	/// Checks whether both storages have equal size and elements.
+	fn equals<SO: Storage<T>>(&self, other: &SO) -> bool
+		where T: PartialEq
+	{
+		self.equal_size(other) && self.find_mismatch(other, |a, b| a == b).is_none()
+	}
+
 	// Container
 	fn into_container(self) -> Container<T, Self> { self.into() }
 }
@@ -0,0 +1,83 @@
+use litcontainers::*;
+use num_complex::Complex;
+
+fn mock_container() -> ContainerRM<f64, U3, Dynamic> {
+	ContainerRM::from_vec(Size::new(U3, Dynamic::new(2)), &[1., 2., 3., 4., 5., 6.])
+}
+
+#[test]
+fn equality() {
+	let rm = mock_container();
+	let cm = ContainerCM::from_vec(Size::new(D!(3), D!(2)), &[1., 2., 3., 4., 5., 6.]);
+
+	assert_eq!(rm, cm);
+	assert_eq!(rm.slice_rows(1..3), cm.slice_rows(1..3));
+	assert_eq!(rm.t(), cm.t());
+	assert_ne!(rm.slice_rows(0..2), cm.slice_rows(1..3));
+	assert_eq!(rm.slice_rows(0..3), cm);
+	assert_eq!(rm.clone_owned().slice_rows_mut(1..3), cm.slice_rows(1..3));
+	assert_eq!(rm.slice_rows(1..3).into_storage(), cm.slice_rows(1..3).into_storage());
+	assert!(rm.slice_rows(0..2).into_storage() != *cm.storage());
+
+	// Storages compare element-wise regardless of layout
+	let rm_storage = VecStorageRM::from_data(Size::new(U2, U2), vec![1, 2, 3, 4]);
+	let cm_storage = VecStorageCM::from_data(Size::new(U2, U2), vec![1, 3, 2, 4]);
+	assert_eq!(rm_storage, cm_storage);
+	assert_eq!(ArrayContainerCM::from_vec(Size::new(U2, U2), &[1, 2, 3, 4]).into_storage(), rm_storage);
+	assert_ne!(cm_storage, VecStorageRM::from_data(Size::new(U2, U2), vec![1, 3, 2, 4]));
+	assert_ne!(rm, ContainerRM::from_vec(Size::new(D!(2), D!(3)), &[1., 2., 3., 4., 5., 6.]));
+
+	let mut other = rm.clone_owned();
+	*other.get_mut(2, 1) = 7.;
+	assert_ne!(rm, other);
+	assert_eq!(rm.find_mismatch(&other, |a, b| a == b), Some((2, 1)));
+}
+
+#[test]
+fn approximate_equality() {
+	let s = mock_container();
+	let mut noisy = s.clone_owned();
+	noisy.mapv_inplace(|v| v + 1e-9);
+
+	assert!(s.abs_diff_eq(&noisy, 1e-8));
+	assert!(!s.abs_diff_eq(&noisy, 1e-10));
+	assert!(s.relative_eq(&noisy, 0., 1e-8));
+	assert!(!s.approx_eq(&noisy));
+
+	let next = s.clone_owned().into_storage();
+	let next = ContainerRM::from_vec(s.size(), &next.as_slice().iter().map(|v| f64::from_bits(v.to_bits() + 2)).collect::<Vec<_>>());
+	assert!(s.ulps_eq(&next, 0., 2));
+	assert!(!s.ulps_eq(&next, 0., 1));
+
+	let c = ContainerRM::from_vec(Size::new(U1, U2), &[Complex::new(1f32, 2.), Complex::new(-1., 0.5)]);
+	let c2 = ContainerRM::from_vec(Size::new(U1, U2), &[Complex::new(1f32, 2.0001), Complex::new(-1., 0.5)]);
+	assert!(c.abs_diff_eq(&c2, 1e-3));
+	assert!(!c.approx_eq(&c2));
+}
+
+#[test]
+fn assert_macros() {
+	let rm = mock_container();
+	let cm = ContainerCM::from_vec(Size::new(D!(3), D!(2)), &[1., 2., 3., 4., 5., 6.]);
+
+	assert_container_eq!(rm, cm);
+	assert_container_approx_eq!(rm, cm);
+	assert_container_approx_eq!(rm, cm.clone_owned() * 1.0001, epsilon = 1e-3);
+	assert_container_approx_eq!(rm, cm.clone_owned() * 1.0001, epsilon = 0., max_relative = 1e-3);
+	assert_container_approx_eq!(rm, cm, epsilon = 0., max_ulps = 0);
+}
+
+#[test]
+#[should_panic(expected = "first mismatch at (1, 0): left = 3.0, right = 0.0")]
+fn assert_eq_mismatch() {
+	let mut other = mock_container();
+	*other.get_mut(1, 0) = 0.;
+	assert_container_eq!(mock_container(), other);
+}
+
+#[test]
+#[should_panic(expected = "sizes differ: left is 3x2, right is 2x3")]
+fn assert_approx_eq_size_mismatch() {
+	let other = ContainerRM::from_vec(Size::new(D!(2), D!(3)), &[1., 2., 3., 4., 5., 6.]);
+	assert_container_approx_eq!(mock_container(), other, epsilon = 1e-6);
+}
@@ -28,3 +28,15 @@ fn join() {
 	assert_eq!(join_rows!(a, b, c).as_slice(), [1., 2., 3., 4., 1., 2., 3., 4., 1., 2., 3., 4.]);
 }
 
+
+
+#[test]
+fn stack() {
+	let a = ContainerRM::from_vec(Size::new(U2, U1), &[1, 2]);
+	let b = ContainerCM::from_vec(Size::new(U2, U2), &[3, 4, 5, 6]);
+
+	let h: ContainerRM<i32, U2, U4> = hstack![a, b, a];
+	assert_eq!(h.as_slice(), [1, 3, 4, 1, 2, 5, 6, 2]);
+	let v: ContainerRM<i32, U6, U2> = vstack![b, b.t(), b];
+	assert_eq!(v.as_slice(), [3, 4, 5, 6, 3, 5, 4, 6, 3, 4, 5, 6]);
+}
\ No newline at end of file
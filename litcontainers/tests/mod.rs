@@ -8,3 +8,8 @@ mod macros;
 mod ops;
 mod functions;
 mod slice;
+mod serialization;
+mod shape_errors;
+mod compare;
+mod signal;
+mod sparse;
\ No newline at end of file
@@ -53,3 +53,150 @@ fn complex_norm() {
 	assert_eq!(s.norm().as_slice(), [(5f32).sqrt(), (13f32).sqrt(), (25f32).sqrt(), (41f32).sqrt()]);
 	assert_eq!(s.norm_sqr().as_slice(), [5f32, 13f32, 25f32, 41f32]);
 }
+
+
+#[test]
+fn rolling_window() {
+	let s = ContainerRM::from_vec(Size::new(U2, D!(5)), &[1., 3., 2., 5., 4., 2., 2., 2., 8., 0.]);
+
+	assert_eq!(s.rolling(3, RowAxis).sum().as_slice(), [1., 4., 6., 10., 11., 2., 4., 6., 12., 10.]);
+	assert_eq!(s.rolling(3, RowAxis).max().as_slice(), [1., 3., 3., 5., 5., 2., 2., 2., 8., 8.]);
+	assert_eq!(s.rolling(3, RowAxis).min().as_slice(), [1., 1., 1., 2., 2., 2., 2., 2., 2., 0.]);
+	assert_eq!(s.rolling(3, RowAxis).median().as_slice(), [1., 2., 2., 3., 4., 2., 2., 2., 2., 2.]);
+
+	let centered = s.rolling(2, RowAxis).with_align(RollingAlign::Centered);
+	assert_eq!(centered.mean().as_slice(), [1., 2., 2.5, 3.5, 4.5, 2., 2., 2., 5., 4.]);
+
+	let filled = s.rolling(3, RowAxis).with_align(RollingAlign::Centered).with_edge(RollingEdge::Fill(-1.));
+	assert_eq!(filled.max().as_slice(), [-1., 3., 5., 5., -1., -1., 2., 8., 8., -1.]);
+
+	let std = s.rolling(3, RowAxis).with_edge(RollingEdge::Fill(0.)).std(0);
+	assert!((std.get(0, 2) - (2f64 / 3.).sqrt()).abs() < 1e-12);
+	assert!((std.get(1, 2)).abs() < 1e-12);
+	assert!(s.rolling(1, RowAxis).std(1).as_slice().iter().all(|v| v.is_nan()));
+
+	// Large offsets don't cancel out the variance.
+	let offset: Vec<f64> = (0..100).map(|i| 1e9 + (i % 3) as f64).collect();
+	let std = rvec![&offset].rolling(3, RowAxis).std(0);
+	assert!(std.as_slice()[2..].iter().all(|v| (v - (2f64 / 3.).sqrt()).abs() < 1e-6));
+
+	// Works on strided views along columns.
+	let t = s.t();
+	assert_eq!(t.rolling(3, ColAxis).sum().as_slice(), [1., 2., 4., 4., 6., 6., 10., 12., 11., 10.]);
+	assert_eq!(rvec![&[3, 1, 2]].rolling(5, RowAxis).with_align(RollingAlign::Centered).median().as_slice(), [2, 2, 2]);
+}
+
+#[test]
+fn cumulative() {
+	let s = ContainerRM::from_vec(Size::new(U2, U3), &[1, 3, 2, 4, -1, 5]);
+
+	assert_eq!(s.cumsum(RowAxis).as_slice(), [1, 4, 6, 4, 3, 8]);
+	assert_eq!(s.cumprod(RowAxis).as_slice(), [1, 3, 6, 4, -4, -20]);
+	assert_eq!(s.cumprod(ColAxis).as_slice(), [1, 3, 2, 4, -3, 10]);
+	assert_eq!(s.cummax(RowAxis).as_slice(), [1, 3, 3, 4, 4, 5]);
+	assert_eq!(s.cummin(ColAxis).as_slice(), [1, 3, 2, 1, -1, 2]);
+}
+
+#[test]
+fn differences() {
+	let s = ContainerRM::from_vec(Size::new(U2, U4), &[1, 4, 9, 16, 2, 2, 5, 1]);
+
+	let d = s.diff(1, RowAxis);
+	assert_eq!(d.size(), Size::new(U2, D!(3)));
+	assert_eq!(d.as_slice(), [3, 5, 7, 0, 3, -4]);
+	assert_eq!(s.diff(2, RowAxis).as_slice(), [2, 2, 3, -7]);
+	let d = s.diff(1, ColAxis);
+	assert_eq!(d.size(), Size::new(D!(1), U4));
+	assert_eq!(d.as_slice(), [1, -2, -4, -15]);
+	assert_eq!(s.diff(5, RowAxis).size(), Size::new(U2, D!(0)));
+	assert_eq!(s.t().diff(1, RowAxis).as_slice(), [1, -2, -4, -15]);
+
+	let f = rvec![&[1., 4., 9., 16.]];
+	assert_eq!(f.gradient(RowAxis, &Spacing::Uniform(1.)).as_slice(), [3., 4., 6., 7.]);
+	assert_eq!(f.gradient(RowAxis, &Spacing::Uniform(0.5)).as_slice(), [6., 8., 12., 14.]);
+	// Squares of the coordinates, so the central differences are exact.
+	let x = rvec![&[0., 1., 3., 4.]];
+	let g = rvec![&[0., 1., 9., 16.]].gradient(RowAxis, &Spacing::from_storage(&x));
+	assert_container_approx_eq!(g, rvec![&[1., 2., 6., 7.]]);
+}
+
+#[test]
+fn integration() {
+	let x: Vec<f64> = (0..=10).map(|i| i as f64 * 0.1).collect();
+	let f = ContainerRM::from_vec(Size::new(U2, D!(11)), &x.iter().map(|v| v * v).chain(x.iter().cloned()).collect::<Vec<_>>());
+
+	let t = f.trapz(RowAxis, &Spacing::Uniform(0.1));
+	assert!((t[0] - 0.335).abs() < 1e-12);
+	assert!((t[1] - 0.5).abs() < 1e-12);
+
+	let s = f.simpson(RowAxis, &Spacing::Uniform(0.1));
+	assert!((s[0] - 1. / 3.).abs() < 1e-12);
+
+	// Odd number of intervals and nonuniform spacing are exact for quadratics.
+	let x = rvec![&[0., 0.5, 1.5, 2., 3.]];
+	let f = rvec![&[0., 0.25, 2.25, 4., 9.]];
+	assert!((f.simpson(RowAxis, &Spacing::from_storage(&x))[0] - 9.).abs() < 1e-12);
+	let cubic = ContainerRM::from_vec(Size::new(D!(4), U1), &[0., 1., 8., 27.]);
+	let s = cubic.simpson(ColAxis, &Spacing::Uniform(1.));
+	assert_eq!(s.size(), Size::new(U1, U1));
+	assert!((s[0] - 20.5).abs() < 1e-12);
+}
+
+#[test]
+fn padding() {
+	let s = ContainerRM::from_vec(Size::new(U2, U3), &[1, 2, 3, 4, 5, 6]);
+
+	let constant: ContainerRM<i32, U2, U6> = s.pad(U1, U2, RowAxis, PadMode::Constant(0));
+	assert_eq!(constant.as_slice(), [0, 1, 2, 3, 0, 0, 0, 4, 5, 6, 0, 0]);
+	assert_eq!(s.pad(U2, U2, RowAxis, PadMode::Edge).as_slice(), [1, 1, 1, 2, 3, 3, 3, 4, 4, 4, 5, 6, 6, 6]);
+	assert_eq!(s.pad(U2, U2, RowAxis, PadMode::Reflect).as_slice(), [3, 2, 1, 2, 3, 2, 1, 6, 5, 4, 5, 6, 5, 4]);
+	assert_eq!(s.pad(U2, U2, RowAxis, PadMode::Symmetric).as_slice(), [2, 1, 1, 2, 3, 3, 2, 5, 4, 4, 5, 6, 6, 5]);
+	assert_eq!(s.pad(U2, U2, RowAxis, PadMode::Wrap).as_slice(), [2, 3, 1, 2, 3, 1, 2, 5, 6, 4, 5, 6, 4, 5]);
+
+	let rows = s.pad(D!(1), U1, ColAxis, PadMode::Wrap);
+	assert_eq!(rows.size(), Size::new(D!(4), U3));
+	assert_eq!(rows.as_slice(), [4, 5, 6, 1, 2, 3, 4, 5, 6, 1, 2, 3]);
+
+	let framed: ContainerRM<i32, U4, U5> = s.pad2d(U1, U1, U1, U1, PadMode::Constant(-1));
+	assert_eq!(framed.as_slice(), [-1, -1, -1, -1, -1, -1, 1, 2, 3, -1, -1, 4, 5, 6, -1, -1, -1, -1, -1, -1]);
+	let reflected = s.t().pad2d(U1, U0, D!(0), D!(1), PadMode::Reflect);
+	assert_eq!(reflected.size(), Size::new(U4, D!(3)));
+	assert_eq!(reflected.as_slice(), [2, 5, 2, 1, 4, 1, 2, 5, 2, 3, 6, 3]);
+}
+
+#[test]
+#[should_panic(expected = "Only constant padding is possible for an empty axis!")]
+fn padding_empty() {
+	ContainerRM::<f64, Dynamic, Dynamic>::zeros(Size::new(D!(2), D!(0))).pad(U1, U1, RowAxis, PadMode::Edge);
+}
+
+#[test]
+fn stacking() {
+	let a = ContainerRM::from_vec(Size::new(U2, U3), &[1, 2, 3, 4, 5, 6]);
+	let b = ContainerCM::from_vec(Size::new(D!(2), U1), &[7, 8]);
+
+	let h = a.hstack(&b);
+	assert_eq!(h.size(), Size::new(U2, U4));
+	assert_eq!(h.as_slice(), [1, 2, 3, 7, 4, 5, 6, 8]);
+	let v = a.t().vstack(&a.t().slice_rows(1..3));
+	assert_eq!(v.size(), Size::new(D!(5), U2));
+	assert_eq!(v.as_slice(), [1, 4, 2, 5, 3, 6, 2, 5, 3, 6]);
+
+	let c = concat(&[&a, &b, &a.slice_cols(1..2)], RowAxis);
+	assert_eq!(c.size(), Size::new(D!(2), D!(5)));
+	assert_eq!(c.as_slice(), [1, 2, 3, 7, 2, 4, 5, 6, 8, 5]);
+	let c = concat(&[&a.t(), &b.t()], ColAxis);
+	assert_eq!(c.as_slice(), [1, 4, 2, 5, 3, 6, 7, 8]);
+
+	let rows = vec![rvec![&[1., 2.]], rvec![&[3., 4.]], rvec![&[5., 6.]]];
+	let s = stack(&rows);
+	assert_eq!(s.size(), Size::new(D!(3), D!(2)));
+	assert_eq!(s.as_slice(), [1., 2., 3., 4., 5., 6.]);
+}
+
+#[test]
+#[should_panic(expected = "Joined storages must have equal number of rows! Expected 2, found 1")]
+fn stacking_mismatch() {
+	let a = ContainerRM::from_vec(Size::new(U2, U1), &[1, 2]);
+	a.hstack(&a.t());
+}
\ No newline at end of file
@@ -8,4 +8,32 @@ fn mock_container() -> ContainerRM<f64, U3, Dynamic> {
 fn test_printing() {
 	let s = mock_container();
 	println!("{}", format!("{}", Fmt(|f| print_storage(&s, f))));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_printing_options() {
+	let s = mock_container();
+	let opts = PrintOptions::new().with_header(false).with_precision(1);
+	assert_eq!(format!("{}", s.display_with(opts.clone())), "  1.0  2.0\n  3.0  4.0\n  5.0  6.0\n");
+	assert_eq!(
+		format!("{}", s.display_with(opts.clone().with_sci_mode(SciMode::Always))),
+		"  +1.0e+00  +2.0e+00\n  +3.0e+00  +4.0e+00\n  +5.0e+00  +6.0e+00\n"
+	);
+	assert!(format!("{}", s).starts_with("Storage[Type = Scalar("));
+}
+
+#[test]
+fn test_printing_summarized() {
+	let s = ContainerRM::regspace(Size::new(D!(1025), D!(4000)), RowAxis, 0i32);
+	let opts = PrintOptions::new().with_header(false).with_edge_items(2).with_sci_mode(SciMode::Never);
+	let out = format!("{}", s.display_with(opts.clone()));
+	let lines: Vec<_> = out.lines().collect();
+	assert_eq!(lines.len(), 5);
+	assert_eq!(lines[0].split_whitespace().collect::<Vec<_>>(), ["0", "1", "...", "3998", "3999"]);
+	assert_eq!(lines[2].split_whitespace().collect::<Vec<_>>(), ["..."; 5]);
+
+	// Rows exceeding the line width get their middle columns elided.
+	let s = ContainerRM::regspace(Size::new(U2, D!(20)), RowAxis, 0i32);
+	let out = format!("{}", s.display_with(opts.with_threshold(usize::MAX).with_line_width(28)));
+	assert_eq!(out.lines().next().unwrap().split_whitespace().collect::<Vec<_>>(), ["0", "1", "2", "...", "17", "18", "19"]);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "serde-serialize")]
+use litcontainers::*;
+
+fn mock_container() -> ContainerRM<f64, U3, Dynamic> {
+	ContainerRM::from_vec(Size::new(U3, Dynamic::new(2)), &[1., 2., 3., 4., 5., 6.])
+}
+
+#[test]
+fn serialize_format() {
+	let json = serde_json::to_string(&mock_container()).unwrap();
+	assert_eq!(json, r#"{"size":{"rows":3,"cols":2},"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#);
+
+	assert_eq!(serde_json::to_string(&Dynamic::new(4)).unwrap(), "4");
+	assert_eq!(serde_json::to_string(&Strides::new(U2, U1)).unwrap(), r#"{"row_stride":2,"col_stride":1}"#);
+}
+
+#[test]
+fn deserialize_roundtrip() {
+	let s = mock_container();
+	let json = serde_json::to_string(&s).unwrap();
+
+	let rm: ContainerRM<f64, U3, Dynamic> = serde_json::from_str(&json).unwrap();
+	assert_eq!(rm.as_slice(), s.as_slice());
+
+	// Layout isn't stored, elements are always in row major order
+	let cm: ContainerCM<f64, Dynamic, U2> = serde_json::from_str(&json).unwrap();
+	assert!(cm.as_iter().eq(s.as_iter()));
+	let storage: VecStorageCM<f64, U3, U2> = serde_json::from_str(&serde_json::to_string(&cm).unwrap()).unwrap();
+	assert!(storage.as_iter().eq(s.as_iter()));
+
+	let c = ContainerRM::from_value(Size::new(U1, U2), c64::new(1., -2.));
+	let c2: ContainerRM<c64, U1, U2> = serde_json::from_str(&serde_json::to_string(&c).unwrap()).unwrap();
+	assert_eq!(c.as_slice(), c2.as_slice());
+}
+
+#[test]
+fn serialize_slice_owned() {
+	let s = mock_container();
+	let slice = s.slice_rows(1..3);
+	let json = serde_json::to_string(&slice).unwrap();
+	assert_eq!(json, r#"{"size":{"rows":2,"cols":2},"data":[3.0,4.0,5.0,6.0]}"#);
+
+	let owned: ContainerRM<f64, Dynamic, Dynamic> = serde_json::from_str(&json).unwrap();
+	assert_eq!(owned.as_slice(), &[3., 4., 5., 6.]);
+}
+
+#[test]
+fn deserialize_validation() {
+	let json = serde_json::to_string(&mock_container()).unwrap();
+	assert!(serde_json::from_str::<ContainerRM<f64, U2, Dynamic>>(&json).is_err());
+	assert!(serde_json::from_str::<ContainerRM<f64, U3, U3>>(&json).is_err());
+	assert!(serde_json::from_str::<Size<U3, U2>>(r#"{"rows":3,"cols":2}"#).is_ok());
+	assert!(serde_json::from_str::<Size<U3, U2>>(r#"{"rows":3,"cols":5}"#).is_err());
+
+	let short = r#"{"size":{"rows":3,"cols":2},"data":[1.0,2.0]}"#;
+	assert!(serde_json::from_str::<ContainerRM<f64, U3, Dynamic>>(short).is_err());
+
+	// Element count wraps to zero without overflow checks.
+	let huge = r#"{"size":{"rows":4294967296,"cols":4294967296},"data":[]}"#;
+	assert!(serde_json::from_str::<ContainerRM<f32, Dynamic, Dynamic>>(huge).is_err());
+}
+
+#[test]
+fn embedded() {
+	let value = (String::from("mel"), mock_container());
+	let json = serde_json::to_string(&value).unwrap();
+	let (name, s): (String, ContainerRM<f64, U3, Dynamic>) = serde_json::from_str(&json).unwrap();
+	assert_eq!(name, "mel");
+	assert_eq!(s.as_slice(), value.1.as_slice());
+}
@@ -0,0 +1,129 @@
+use litcontainers::*;
+
+fn mock_container() -> ContainerRM<f64, U3, Dynamic> {
+	ContainerRM::from_vec(Size::new(U3, Dynamic::new(2)), &[1., 2., 3., 4., 5., 6.])
+}
+
+#[test]
+fn try_indexing() {
+	let mut s = mock_container();
+
+	assert_eq!(s.try_get(2, 1), Ok(6.));
+	assert_eq!(s.try_get(3, 1), Err(ShapeError::IndexOutOfBounds { axis: AxisType::Row, index: 3, len: 3 }));
+	assert_eq!(s.try_get_ref(0, 2), Err(ShapeError::IndexOutOfBounds { axis: AxisType::Col, index: 2, len: 2 }));
+	assert!(s.try_as_row_ptr(3).is_err());
+	assert!(s.try_as_col_ptr(1).is_ok());
+
+	*s.try_get_mut(1, 0).unwrap() = 10.;
+	assert_eq!(s.get(1, 0), 10.);
+	assert!(s.try_get_mut(0, 5).is_err());
+}
+
+#[test]
+fn try_slicing() {
+	let s = mock_container();
+
+	assert_eq!(s.try_slice_rows(1..3).unwrap().as_iter().cloned().collect::<Vec<_>>(), [3., 4., 5., 6.]);
+	assert_eq!(s.try_slice_cols(1).unwrap().as_iter().cloned().collect::<Vec<_>>(), [2., 4., 6.]);
+	assert_eq!(s.try_slice_rows(3..3).unwrap().len(), 0);
+	assert_eq!(
+		s.try_slice_rows(2..4).unwrap_err(),
+		ShapeError::RangeOutOfBounds { axis: AxisType::Row, begin: 2, end: 4, len: 3 }
+	);
+	assert_eq!(
+		s.try_slice(0..2, 1..3).unwrap_err(),
+		ShapeError::RangeOutOfBounds { axis: AxisType::Col, begin: 1, end: 3, len: 2 }
+	);
+
+	let mut s = s;
+	assert!(s.try_slice_mut(0..3, 0..2).is_ok());
+	assert!(s.try_slice_cols_mut(2).is_err());
+}
+
+#[test]
+fn try_copy_and_construct() {
+	let mut s = mock_container();
+	let other = ContainerRM::from_value(Size::new(U3, D!(3)), 1.);
+	assert_eq!(s.try_copy_from(&other), Err(ShapeError::SizeMismatch { expected: (3, 2), actual: (3, 3) }));
+	assert!(s.try_copy_from(&ContainerCM::from_value(Size::new(U3, D!(2)), 1.)).is_ok());
+	assert_eq!(s.as_slice(), [1.; 6]);
+
+	let c = ContainerRM::<f64, Dynamic, Dynamic>::try_from_vec(Size::new(D!(2), D!(2)), &[1., 2., 3.]);
+	assert_eq!(c.unwrap_err(), ShapeError::LengthMismatch { expected: 4, actual: 3 });
+}
+
+#[test]
+fn try_transmute() {
+	let s = mock_container();
+
+	assert!(s.try_transmute_dims(Size::new(D!(2), D!(3)), Strides::new(D!(3), U1)).is_ok());
+	assert_eq!(
+		s.try_transmute_dims(Size::new(D!(3), D!(3)), Strides::new(D!(3), U1)).unwrap_err(),
+		ShapeError::TransmuteOutOfBounds { required: 9, len: 6 }
+	);
+}
+
+#[test]
+fn try_storage_ops() {
+	let l = mock_container();
+	let r = ContainerRM::from_vec(Size::new(U3, D!(2)), &[1., 1., 1., 1., 1., 1.]);
+	let wide = ContainerRM::from_value(Size::new(U3, D!(3)), 1.);
+
+	assert_eq!(l.into_slice().try_add_storage(&r).unwrap().as_slice(), [2., 3., 4., 5., 6., 7.]);
+	assert_eq!(l.into_slice().try_sub_storage_rev(&r).unwrap().as_slice(), [0., -1., -2., -3., -4., -5.]);
+	assert_eq!(
+		l.into_slice().try_mul_storage(&wide).unwrap_err(),
+		ShapeError::SizeMismatch { expected: (3, 2), actual: (3, 3) }
+	);
+
+	let mut s = l.clone_owned();
+	assert!(s.try_add_assign_storage(&wide).is_err());
+	assert!(s.try_add_assign_storage(&r).is_ok());
+	assert_eq!(s.as_slice(), [2., 3., 4., 5., 6., 7.]);
+}
+
+#[test]
+fn try_split() {
+	let s = ContainerRM::regspace(Size::new(U4, U4), RowAxis, 0);
+	let slice = s.into_slice();
+
+	assert_eq!(try_split_into!(slice, 2; RowAxis).unwrap().len(), 2);
+	assert_eq!(
+		try_split_into!(slice, 5; ColAxis).unwrap_err(),
+		ShapeError::ChunkCount { axis: AxisType::Col, chunks: 5, len: 4 }
+	);
+}
+
+#[test]
+fn try_join() {
+	let a = ContainerRM::from_vec(Size::new(U2, U3), &[1, 2, 3, 4, 5, 6]);
+	let b = ContainerCM::from_vec(Size::new(U2, U1), &[7, 8]);
+
+	assert_eq!(a.try_hstack(&b).unwrap().as_slice(), [1, 2, 3, 7, 4, 5, 6, 8]);
+	assert_eq!(a.try_hstack(&a.t()).unwrap_err(), ShapeError::JoinMismatch { axis: AxisType::Row, expected: 2, actual: 3 });
+	assert_eq!(try_vstack(&a, &b).unwrap_err(), ShapeError::JoinMismatch { axis: AxisType::Col, expected: 3, actual: 1 });
+	let h: ContainerRM<i32, U2, U5> = try_hstack!(a, b, b).unwrap();
+	assert_eq!(h.as_slice(), [1, 2, 3, 7, 7, 4, 5, 6, 8, 8]);
+	assert!(try_hstack!(a, b, a.t()).is_err());
+	assert_eq!(try_vstack!(a, a, a).unwrap().size(), Size::new(U6, U3));
+
+	assert_eq!(try_concat(&[&a, &b], RowAxis).unwrap().as_slice(), [1, 2, 3, 7, 4, 5, 6, 8]);
+	assert_eq!(
+		try_concat(&[&a, &b, &a.t()], RowAxis).unwrap_err(),
+		ShapeError::JoinMismatch { axis: AxisType::Row, expected: 2, actual: 3 }
+	);
+	assert_eq!(try_concat::<i32, _>(&[], ColAxis).unwrap_err(), ShapeError::EmptyJoin);
+
+	assert_eq!(try_stack(&[rvec![&[1, 2]], rvec![&[3, 4]]]).unwrap().as_slice(), [1, 2, 3, 4]);
+	assert_eq!(
+		try_stack(&[rvec![&[1, 2]], rvec![&[3]]]).unwrap_err(),
+		ShapeError::JoinMismatch { axis: AxisType::Col, expected: 2, actual: 1 }
+	);
+	assert_eq!(try_stack::<i32, ContainerRM<i32, U1, Dynamic>>(&[]).unwrap_err(), ShapeError::EmptyJoin);
+}
+
+#[test]
+#[should_panic(expected = "Range is out of bounds!")]
+fn slice_panics() {
+	mock_container().slice_rows(2..4);
+}
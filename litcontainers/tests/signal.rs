@@ -0,0 +1,272 @@
+use litcontainers::*;
+use num_complex::Complex;
+
+#[test]
+fn convolution_modes() {
+	let x = rvec![&[1., 2., 3.]];
+	let k = rvec![&[0., 1., 0.5]];
+
+	assert_eq!(x.convolve(&k, ConvolutionMode::Full).as_slice(), [0., 1., 2.5, 4., 1.5]);
+	assert_eq!(x.convolve(&k, ConvolutionMode::Same).as_slice(), [1., 2.5, 4.]);
+	assert_eq!(x.convolve(&k, ConvolutionMode::Valid).as_slice(), [2.5]);
+	assert_eq!(x.correlate(&k, ConvolutionMode::Full).as_slice(), [0.5, 2., 3.5, 3., 0.]);
+	assert_eq!(rvec![&[1, 2, 3]].convolve(&rvec![&[1, 1]], ConvolutionMode::Full).as_slice(), [1, 3, 5, 3]);
+}
+
+#[test]
+fn convolution_saturates() {
+	let x = rvec![&[200u8, 100, 0]];
+	assert_eq!(x.convolve(&rvec![&[1u8, 1]], ConvolutionMode::Full).as_slice(), [200, 255, 100, 0]);
+	let x = rvec![&[i16::MIN, i16::MAX]];
+	assert_eq!(x.convolve(&rvec![&[2i16]], ConvolutionMode::Full).as_slice(), [i16::MIN, i16::MAX]);
+	assert_eq!(rvec![&[i64::MAX]].convolve(&rvec![&[1i64]], ConvolutionMode::Full).as_slice(), [i64::MAX]);
+
+	let long: Vec<i16> = vec![i16::MAX; 200];
+	let full = rvec![&long].convolve(&rvec![&long], ConvolutionMode::Full);
+	assert!(full.as_iter().all(|v| *v == i16::MAX));
+
+	let s = ContainerRM::from_vec(Size::new(U2, U2), &[100i8, 100, 100, 100]);
+	let k = ContainerRM::from_vec(Size::new(U1, U2), &[-2i8, 2]);
+	assert_eq!(s.convolve2d(&s, ConvolutionMode::Valid, Boundary::Zero).as_slice(), [i8::MAX]);
+	assert_eq!(s.convolve2d(&k, ConvolutionMode::Full, Boundary::Zero).as_slice(), [-128, 0, 127, -128, 0, 127]);
+}
+
+#[test]
+fn convolution_complex() {
+	let x = rvec![&[Complex::new(1., 1.), Complex::new(2., 0.)]];
+	let k = rvec![&[Complex::new(1., 0.), Complex::new(0., 1.)]];
+
+	assert_eq!(
+		x.convolve(&k, ConvolutionMode::Full).as_slice(),
+		[Complex::new(1., 1.), Complex::new(1., 1.), Complex::new(0., 2.)]
+	);
+	assert_eq!(
+		x.correlate(&k, ConvolutionMode::Valid).as_slice(),
+		[Complex::new(1., -1.)]
+	);
+}
+
+#[test]
+fn convolution_overlap_add() {
+	let x: Vec<f64> = (0..1000).map(|i| ((i * 7919) % 101) as f64 / 50. - 1.).collect();
+	let k: Vec<f64> = (0..150).map(|i| ((i * 31) % 17) as f64 / 8. - 1.).collect();
+
+	let mut expected = vec![0.; x.len() + k.len() - 1];
+	for (i, xv) in x.iter().enumerate() {
+		for (j, kv) in k.iter().enumerate() {
+			expected[i + j] += xv * kv;
+		}
+	}
+
+	let full = rvec![&x].convolve(&rvec![&k], ConvolutionMode::Full);
+	assert_container_approx_eq!(full, rvec![&expected], epsilon = 1e-9);
+	let same = rvec![&x].convolve(&rvec![&k], ConvolutionMode::Same);
+	assert_container_approx_eq!(same, rvec![&expected[74..1074]], epsilon = 1e-9);
+}
+
+#[test]
+fn convolution_axis() {
+	let s = ContainerRM::from_vec(Size::new(U2, U3), &[1., 2., 3., 4., 5., 6.]);
+	let k = rvec![&[1., 1.]];
+
+	let rows = s.convolve_axis(&k, RowAxis, ConvolutionMode::Valid);
+	assert_eq!(rows.size(), Size::new(D!(2), D!(2)));
+	assert_eq!(rows.as_slice(), [3., 5., 9., 11.]);
+
+	let cols = s.convolve_axis(&k, ColAxis, ConvolutionMode::Full);
+	assert_eq!(cols.size(), Size::new(D!(3), D!(3)));
+	assert_eq!(cols.as_slice(), [1., 2., 3., 5., 7., 9., 4., 5., 6.]);
+}
+
+#[test]
+fn convolution_2d() {
+	let s = ContainerRM::from_vec(Size::new(U3, U3), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+	let k = ContainerRM::from_vec(Size::new(U2, U2), &[1, 0, 0, -1]);
+
+	assert_eq!(s.convolve2d(&k, ConvolutionMode::Same, Boundary::Zero).as_slice(), [1, 2, 3, 4, 4, 4, 7, 4, 4]);
+	assert_eq!(s.convolve2d(&k, ConvolutionMode::Same, Boundary::Wrap).as_slice(), [-8, -5, -5, 1, 4, 4, 1, 4, 4]);
+	assert_eq!(s.convolve2d(&k, ConvolutionMode::Same, Boundary::Reflect).as_slice(), [-4, -2, -2, 2, 4, 4, 2, 4, 4]);
+	assert_eq!(s.convolve2d(&k, ConvolutionMode::Valid, Boundary::Zero).as_slice(), [4, 4, 4, 4]);
+	assert_eq!(s.convolve2d(&k, ConvolutionMode::Full, Boundary::Zero).size(), Size::new(D!(4), D!(4)));
+}
+
+
+/// Magnitude of the transfer function `b / a` at normalized frequency `f`.
+fn response(b: &[f64], a: &[f64], f: f64) -> f64 {
+	let eval = |p: &[f64]| p.iter().enumerate()
+		.fold(Complex::new(0., 0.), |acc, (k, v)| acc + Complex::from_polar(v, &(-std::f64::consts::PI * f * k as f64)));
+	(eval(b) / eval(a)).norm()
+}
+
+fn sos_response(sections: &[Biquad<f64>], f: f64) -> f64 {
+	let (b, a) = sos_to_tf(sections);
+	response(b.as_slice(), a.as_slice(), f)
+}
+
+#[test]
+fn lfilter_basic() {
+	let x = rvec![&[1., 0., 0., 0.]];
+	assert_eq!(x.lfilter(&rvec![&[2.]], &rvec![&[2., -1.]], RowAxis).as_slice(), [1., 0.5, 0.25, 0.125]);
+	assert_eq!(rvec![&[1., 2., 3.]].lfilter(&rvec![&[1., 1.]], &rvec![&[1.]], RowAxis).as_slice(), [1., 3., 5.]);
+
+	let s = ContainerRM::from_vec(Size::new(U3, U2), &[1., 1., 2., 0., 3., 0.]);
+	let cols = s.lfilter(&rvec![&[1., 1.]], &rvec![&[1.]], ColAxis);
+	assert_eq!(cols.as_slice(), [1., 1., 3., 1., 5., 0.]);
+}
+
+#[test]
+fn lfilter_zi_nan() {
+	let zi = lfilter_zi(&[1., f64::NAN, 0.5], &[1., -0.5, 0.25]);
+	assert_eq!(zi.len(), 2);
+	assert!(zi.iter().all(|v| v.is_nan()));
+}
+
+#[test]
+fn filtfilt_zero_phase() {
+	let (b, a) = sos_to_tf(&butter(4, FilterBand::LowPass(0.2)));
+
+	let constant = rvec![&[3.; 50]];
+	assert_container_approx_eq!(constant.filtfilt(&b, &a, RowAxis), constant, epsilon = 1e-9);
+
+	let x: Vec<f64> = (0..400).map(|i| (0.02 * std::f64::consts::PI * i as f64).sin()).collect();
+	let y = rvec![&x].filtfilt(&b, &a, RowAxis);
+	for i in 50..350 {
+		assert!((y.get(0, i) - x[i]).abs() < 1e-3);
+	}
+}
+
+#[test]
+fn sos_block_processing() {
+	let sections = butter(3, FilterBand::HighPass(0.3));
+	let x: Vec<f64> = (0..64).map(|i| ((i * 37) % 11) as f64 - 5.).collect();
+	let whole = rvec![&x].sosfilt(&sections, RowAxis);
+
+	let mut filter = SosFilter::new(sections.clone());
+	let first = filter.process(&rvec![&x[..20]], RowAxis);
+	let second = filter.process(&rvec![&x[20..]], RowAxis);
+	assert_container_approx_eq!(first, rvec![&whole.as_slice()[..20]], epsilon = 1e-12);
+	assert_container_approx_eq!(second, rvec![&whole.as_slice()[20..]], epsilon = 1e-12);
+
+	let (b, a) = sos_to_tf(&sections);
+	assert_container_approx_eq!(rvec![&x].lfilter(&b, &a, RowAxis), whole, epsilon = 1e-9);
+}
+
+#[test]
+fn butterworth_design() {
+	let (b, a) = sos_to_tf(&butter(2, FilterBand::LowPass(0.5)));
+	assert_container_approx_eq!(b, rvec![&[0.29289322, 0.58578644, 0.29289322]], epsilon = 1e-8);
+	assert_container_approx_eq!(a, rvec![&[1., 0., 0.17157288]], epsilon = 1e-8);
+
+	let cutoff = std::f64::consts::FRAC_1_SQRT_2;
+	let lp = butter(5, FilterBand::LowPass(0.3));
+	assert!((sos_response(&lp, 0.) - 1.).abs() < 1e-9);
+	assert!((sos_response(&lp, 0.3) - cutoff).abs() < 1e-9);
+	assert!(sos_response(&lp, 0.99) < 1e-6);
+
+	let hp = butter(4, FilterBand::HighPass(0.2));
+	assert!(sos_response(&hp, 0.) < 1e-9);
+	assert!((sos_response(&hp, 0.2) - cutoff).abs() < 1e-9);
+	assert!((sos_response(&hp, 1.) - 1.).abs() < 1e-9);
+
+	let bp = butter(3, FilterBand::BandPass(0.2, 0.4));
+	assert_eq!(bp.len(), 3);
+	assert!(sos_response(&bp, 0.) < 1e-9);
+	assert!(sos_response(&bp, 1.) < 1e-9);
+	assert!((sos_response(&bp, 0.2) - cutoff).abs() < 1e-9);
+	assert!((sos_response(&bp, 0.4) - cutoff).abs() < 1e-9);
+}
+
+#[test]
+fn fir_design() {
+	let lp = firwin::<f64>(31, FilterBand::LowPass(0.25), Window::Hamming);
+	assert!((lp.sum() - 1.).abs() < 1e-12);
+	assert!((0..15).all(|i| (lp.get(0, i) - lp.get(0, 30 - i)).abs() < 1e-15));
+	assert!(response(lp.as_slice(), &[1.], 0.6) < 1e-2);
+
+	let hp = firwin::<f64>(31, FilterBand::HighPass(0.5), Window::Kaiser(8.));
+	assert!((response(hp.as_slice(), &[1.], 1.) - 1.).abs() < 1e-12);
+	assert!(response(hp.as_slice(), &[1.], 0.) < 1e-3);
+
+	let bp = firwin::<f32>(41, FilterBand::BandPass(0.3, 0.5), Window::Blackman);
+	let bp: Vec<f64> = bp.as_slice().iter().map(|v| *v as f64).collect();
+	assert!((response(&bp, &[1.], 0.4) - 1.).abs() < 1e-5);
+	assert_container_approx_eq!(window::<f64>(Window::Hann, 5), rvec![&[0., 0.5, 1., 0.5, 0.]]);
+}
+
+#[test]
+#[should_panic(expected = "High-pass FIR filter must have an odd number of taps!")]
+fn fir_even_highpass() {
+	firwin::<f64>(10, FilterBand::HighPass(0.5), Window::Hann);
+}
+
+#[test]
+fn cookbook_biquads() {
+	let q = std::f64::consts::FRAC_1_SQRT_2;
+	let lp = Biquad::lowpass(0.25, q);
+	assert!((sos_response(&[lp], 0.) - 1.).abs() < 1e-12);
+	assert!(sos_response(&[lp], 1.) < 1e-12);
+	assert!((sos_response(&[lp], 0.25) - q).abs() < 1e-12);
+
+	assert!(sos_response(&[Biquad::highpass(0.25, q)], 0.) < 1e-12);
+	assert!((sos_response(&[Biquad::bandpass(0.25, 2.)], 0.25) - 1.).abs() < 1e-12);
+	assert!(sos_response(&[Biquad::notch(0.25, 2.)], 0.25) < 1e-12);
+	assert!((sos_response(&[Biquad::allpass(0.25, 2.)], 0.1) - 1.).abs() < 1e-12);
+	assert!((sos_response(&[Biquad::peaking(0.25, 1., 6.)], 0.25) - 10f64.powf(0.3)).abs() < 1e-12);
+	assert!((sos_response(&[Biquad::lowshelf(0.25, q, 6.)], 0.) - 10f64.powf(0.3)).abs() < 1e-12);
+	assert!((sos_response(&[Biquad::highshelf(0.25, q, -6.)], 1.) - 10f64.powf(-0.3)).abs() < 1e-12);
+}
+
+#[test]
+fn interpolation() {
+	let x = rvec![&[0., 1., 2., 4.]];
+	let y = rvec![&[0., 2., 1., 5.]];
+	let p = rvec![&[-1., 0.4, 0.6, 1.5, 2.9, 4., 5.]];
+
+	assert_eq!(interp1d(&x, &y, &p, Interpolation::Nearest).as_slice(), [0., 0., 2., 1., 1., 5., 5.]);
+	assert_container_approx_eq!(interp1d(&x, &y, &p, Interpolation::Linear), rvec![&[0., 0.8, 1.2, 1.5, 2.8, 5., 5.]]);
+
+	// Spline passes through the samples and reproduces a straight line exactly.
+	let spline = interp1d(&x, &y, &x, Interpolation::CubicSpline);
+	assert_container_approx_eq!(spline, y);
+	let line = rvec![&[1., 3., 5., 9.]];
+	assert_container_approx_eq!(interp1d(&x, &line, &p, Interpolation::CubicSpline), rvec![&[1., 1.8, 2.2, 4., 6.8, 9., 9.]]);
+
+	let xs: Vec<f64> = (0..=20).map(|i| i as f64 * 0.5).collect();
+	let ys: Vec<f64> = xs.iter().map(|v| v.sin()).collect();
+	let p: Vec<f64> = (0..40).map(|i| 2. + i as f64 * 0.15).collect();
+	let interp = interp1d(&rvec![&xs], &rvec![&ys], &rvec![&p], Interpolation::CubicSpline);
+	for (v, p) in interp.as_slice().iter().zip(&p) {
+		assert!((v - p.sin()).abs() < 1e-3);
+	}
+
+	for kind in &[Interpolation::Nearest, Interpolation::Linear, Interpolation::CubicSpline] {
+		let ret = interp1d_slice(&[0., 1., 2.], &[0., 2., 1.], &[f64::NAN, 0.5], *kind);
+		assert!(ret[0].is_nan());
+		assert!(!ret[1].is_nan());
+	}
+}
+
+#[test]
+fn resampling() {
+	assert_eq!(resample_ratio(44100, 16000), (160, 441));
+	assert_eq!(resampled_len(44100, 160, 441), 16000);
+
+	let tone = |rate: f64, len: usize| -> Vec<f64> {
+		(0..len).map(|i| (2. * std::f64::consts::PI * 440. * i as f64 / rate).sin()).collect()
+	};
+	let down = rvec![&tone(44100., 4410)].resample(44100, 16000, RowAxis);
+	assert_eq!(down.size(), Size::new(D!(1), D!(1600)));
+	let expected = tone(16000., 1600);
+	for i in 100..1500 {
+		assert!((down.get(0, i) - expected[i]).abs() < 1e-3);
+	}
+
+	let channels = ContainerRM::from_vec(Size::new(D!(200), U2), &(0..400).map(|i| (i % 2) as f64 + 1.).collect::<Vec<_>>());
+	let up = channels.resample(8000, 12000, ColAxis);
+	assert_eq!(up.size(), Size::new(D!(300), D!(2)));
+	for r in 50..250 {
+		assert!((up.get(r, 0) - 1.).abs() < 5e-3);
+		assert!((up.get(r, 1) - 2.).abs() < 1e-2);
+	}
+	assert_eq!(channels.resample(8000, 8000, ColAxis), channels);
+}
\ No newline at end of file
@@ -40,4 +40,60 @@ fn split_into() {
 				assert_eq!(chunk.as_slice(), [i, i, i, i]);
 		}
 	}
+}
+#[test]
+fn from_external_slice() {
+	let data = [1., 2., 3., 4., 5., 6., 7., 8.];
+	let s = Slice::from_slice(&data, Size::new(U2, U3), Strides::new(U4, U1));
+	assert_eq!(s.get(1, 2), 7.);
+	assert_eq!(s.clone_owned().as_slice(), [1., 2., 3., 5., 6., 7.]);
+
+	let s = Slice::from_slice(&data, Size::new(D!(4), U2), Strides::new(U1, U4));
+	assert_eq!(s.get(3, 1), 8.);
+	assert_eq!(
+		Slice::try_from_slice(&data, Size::new(U3, U3), Strides::new(U3, U1)).err(),
+		Some(ShapeError::TransmuteOutOfBounds { required: 9, len: 8 })
+	);
+
+	let mut data = [0f32; 6];
+	{
+		let mut s = SliceMut::from_slice_mut(&mut data, Size::new(U3, U1), Strides::new(U2, U1));
+		s.mapv_inplace(|_| 1.);
+	}
+	assert_eq!(data, [1., 0., 1., 0., 1., 0.]);
+}
+
+#[test]
+fn from_slice_mut_overlapping() {
+	let mut data = [0f32; 6];
+	assert_eq!(
+		SliceMut::try_from_slice_mut(&mut data[..1], Size::new(D!(2), U1), Strides::new(D!(0), U1)).err(),
+		Some(ShapeError::OverlappingStrides { row_stride: 0, col_stride: 1 })
+	);
+	assert!(SliceMut::try_from_slice_mut(&mut data, Size::new(U2, U3), Strides::new(U2, U1)).is_err());
+	assert!(SliceMut::try_from_slice_mut(&mut data, Size::new(U2, U2), Strides::new(U1, U1)).is_err());
+
+	// Strides of length-1 axes are never used and column major layouts are fine.
+	assert!(SliceMut::try_from_slice_mut(&mut data, Size::new(U1, U3), Strides::new(D!(0), U2)).is_ok());
+	assert!(SliceMut::try_from_slice_mut(&mut data, Size::new(U2, U3), Strides::new(U1, U2)).is_ok());
+	// Shared slices may still broadcast.
+	assert!(Slice::try_from_slice(&data[..1], Size::new(D!(2), U1), Strides::new(D!(0), U1)).is_ok());
+
+	let mut c = ContainerRM::from_vec(Size::new(U2, U3), &[1., 2., 3., 4., 5., 6.]);
+	assert!(c.try_transmute_dims_mut(Size::new(U3, U2), Strides::new(U1, U1)).is_err());
+	assert!(c.try_transmute_dims_mut(Size::new(U3, U2), Strides::new(U2, U1)).is_ok());
+}
+
+#[test]
+fn from_slice_huge_strides() {
+	let data = [1f32];
+	let huge = D!(1usize << 63);
+	assert_eq!(
+		Slice::try_from_slice(&data, Size::new(D!(3), U1), Strides::new(huge, U1)).err(),
+		Some(ShapeError::TransmuteOutOfBounds { required: usize::MAX, len: 1 })
+	);
+	assert!(Slice::try_from_slice(&data, Size::new(U2, D!(2)), Strides::new(D!(usize::MAX), D!(usize::MAX))).is_err());
+
+	let c = ContainerRM::from_value(Size::new(U1, U2), 0f32);
+	assert!(c.try_transmute_dims(Size::new(D!(3), U1), Strides::new(huge, U1)).is_err());
 }
\ No newline at end of file
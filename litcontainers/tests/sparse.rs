@@ -0,0 +1,105 @@
+use litcontainers::*;
+
+fn mock_dense() -> ContainerRM<f64, U3, U4> {
+	ContainerRM::from_vec(Size::new(U3, U4), &[
+		1., 0., 0., 2.,
+		0., 0., 3., 0.,
+		4., 0., 5., 0.01,
+	])
+}
+
+#[test]
+fn construction() {
+	let d = mock_dense();
+	let csr = CsrStorage::from_dense(&d, 0.1);
+	assert_eq!(csr.size(), Size::new(D!(3), D!(4)));
+	assert_eq!(csr.nnz(), 5);
+	assert_eq!(csr.indptr(), [0, 2, 3, 5]);
+	assert_eq!(csr.indices(), [0, 3, 2, 0, 2]);
+	assert_eq!(csr.data(), [1., 2., 3., 4., 5.]);
+	assert_eq!(csr.get(2, 2), 5.);
+	assert_eq!(csr.get(1, 1), 0.);
+
+	let triplets = [(2, 2, 2.), (0, 3, 2.), (0, 0, 1.), (1, 2, 3.), (2, 0, 4.), (2, 2, 3.)];
+	assert_eq!(CsrStorage::from_triplets(Size::new(U3, U4), &triplets), csr);
+
+	let csc = CscStorage::from_dense(&d, 0.1);
+	assert_eq!(csc.indptr(), [0, 2, 2, 4, 5]);
+	assert_eq!(csc.indices(), [0, 2, 1, 2, 0]);
+	assert_eq!(CscStorage::from_triplets(Size::new(U3, U4), &triplets), csc);
+	assert_eq!(csr.to_csc(), csc);
+	assert_eq!(csc.to_csr(), csr);
+
+	let raw = CsrStorage::from_raw_parts(Size::new(U3, U4), vec![0, 2, 3, 5], vec![0, 3, 2, 0, 2], vec![1., 2., 3., 4., 5.]);
+	assert_eq!(raw, csr);
+
+	let i = CsrStorage::from_dense(&ContainerRM::from_vec(Size::new(U1, U3), &[-3i32, 1, 0]), 2);
+	assert_eq!(i.data(), [-3]);
+	let u = CscStorage::from_dense(&ContainerRM::from_vec(Size::new(U1, U3), &[3u8, 1, 0]), 0);
+	assert_eq!(u.data(), [3, 1]);
+}
+
+#[test]
+#[should_panic]
+fn construction_unsorted() {
+	CsrStorage::from_raw_parts(Size::new(U1, U3), vec![0, 2], vec![2, 0], vec![1., 2.]);
+}
+
+#[test]
+fn dense_conversion() {
+	let d = mock_dense();
+	let csr = CsrStorage::from_dense(&d, 0.);
+	assert_eq!(csr.to_dense(), d);
+	assert_eq!(csr.to_csc().to_dense(), d);
+	assert_eq!(csr.transpose().to_dense(), d.transpose_owned());
+	assert_eq!(csr.transpose().size(), Size::new(D!(4), D!(3)));
+	assert_eq!(csr.density(), 6. / 12.);
+}
+
+#[test]
+fn iteration() {
+	let csr = CsrStorage::from_dense(&mock_dense(), 0.1);
+	let rows: Vec<Vec<(usize, f64)>> = csr.row_iter().map(|r| r.iter().collect()).collect();
+	assert_eq!(rows, vec![vec![(0, 1.), (3, 2.)], vec![(2, 3.)], vec![(0, 4.), (2, 5.)]]);
+	assert_eq!(csr.row(2).get(2), 5.);
+	assert_eq!(csr.row(1).nnz(), 1);
+
+	let csc = csr.to_csc();
+	let cols: Vec<usize> = csc.col_iter().map(|c| c.nnz()).collect();
+	assert_eq!(cols, [2, 0, 2, 1]);
+	assert_eq!(csc.col(2).indices, [1, 2]);
+
+	let triplets: Vec<_> = csc.iter().collect();
+	assert_eq!(triplets, [(0, 0, 1.), (2, 0, 4.), (1, 2, 3.), (2, 2, 5.), (0, 3, 2.)]);
+	assert_eq!(csr.iter().len(), 5);
+}
+
+#[test]
+fn products() {
+	let d = mock_dense();
+	let csr = CsrStorage::from_dense(&d, 0.);
+	let m = ContainerRM::from_vec(Size::new(U4, U2), &[1., 2., 3., 4., 5., 6., 7., 8.]);
+	let expected = ContainerRM::from_vec(Size::new(U3, U2), &[15., 18., 15., 18., 29.07, 38.08]);
+	assert_container_approx_eq!(csr.mul_dense(&m), expected);
+	assert_container_approx_eq!(csr.to_csc().mul_dense(&m), expected);
+
+	let v = ContainerRM::from_vec(Size::new(U1, U4), &[1., 1., 1., 1.]);
+	let expected = ContainerCM::from_vec(Size::new(D!(3), U1), &[3., 3., 9.01]);
+	assert_container_approx_eq!(csr.mul_vec(&v), expected);
+	assert_container_approx_eq!(csr.to_csc().mul_vec(&v.t()), expected);
+}
+
+#[test]
+fn elementwise() {
+	let a = CsrStorage::from_triplets(Size::new(U2, U3), &[(0, 0, 1.), (1, 2, 2.)]);
+	let b = CsrStorage::from_triplets(Size::new(U2, U3), &[(0, 1, 3.), (1, 2, -2.), (1, 0, 1.)]);
+	let sum = &a + &b;
+	assert_eq!(sum.nnz(), 4);
+	assert_eq!(sum.to_dense().as_slice(), [1., 3., 0., 1., 0., 0.]);
+	assert_eq!((&a.to_csc() + &b.to_csc()).to_csr(), sum);
+
+	let mut s = a.scale(2.);
+	assert_eq!(s.data(), [2., 4.]);
+	s.scale_inplace(0.5);
+	assert_eq!(s, a);
+}
\ No newline at end of file
@@ -107,6 +107,12 @@ fn constructors() {
 		3, 3, 3,
 	]);
 
+	assert_eq!(ContainerRM::linspace(Size::new(U3, U2), ColAxis, 0., 1.).as_slice(), [
+		0., 0.,
+		0.5, 0.5,
+		1., 1.,
+	]);
+
 	assert_eq!(ContainerRM::regspace(Size::new(U3, U3), RowAxis, 1).as_slice(), [
 		1, 2, 3,
 		1, 2, 3,
@@ -117,4 +123,343 @@ fn constructors() {
 		2, 2, 2,
 		3, 3, 3,
 	]);
+}
+
+#[test]
+fn reshape() {
+	let s = mock_container();
+
+	let r = s.reshape(Size::new(U2, U3), Order::RowMajor);
+	assert!(r.is_view());
+	assert_eq!(r.view().as_slice(), [1., 2., 3., 4., 5., 6.]);
+	assert_eq!(r.view().get(1, 0), 4.);
+
+	let transposed = s.t();
+	let t = transposed.reshape(Size::new(D!(3), U2), Order::RowMajor);
+	assert!(!t.is_view());
+	assert_eq!(t.view().as_slice(), [1., 3., 5., 2., 4., 6.]);
+	assert_eq!(t.into_owned().size(), Size::new(D!(3), U2));
+
+	// Transposed row major storage is column major contiguous
+	let t = transposed.reshape(Size::new(U3, U2), Order::ColMajor);
+	assert!(t.is_view());
+	assert_eq!(t.into_owned().as_slice(), [1., 4., 2., 5., 3., 6.]);
+	let c = s.reshape(Size::new(U2, U3), Order::ColMajor);
+	assert!(!c.is_view());
+	assert_eq!(c.view().as_slice(), [1., 5., 4., 3., 2., 6.]);
+
+	assert!(s.slice_rows(1..3).reshape(Size::new(U1, U4), Order::RowMajor).is_view());
+	assert!(!s.slice_cols(1..2).reshape(Size::new(U1, U3), Order::RowMajor).is_view());
+	assert!(transposed.slice_cols(1..3).reshape(Size::new(U4, U1), Order::ColMajor).is_view());
+	assert!(s.slice_cols(1..2).slice_rows(0..1).ravel().is_view());
+
+	assert_eq!(s.t().flatten().as_slice(), [1., 3., 5., 2., 4., 6.]);
+	assert_eq!(s.ravel().into_owned().size(), Size::new(U1, D!(6)));
+	assert!(s.try_reshape(Size::new(U4, U2), Order::RowMajor).is_err());
+}
+
+#[test]
+fn into_reshaped() {
+	let s = mock_container();
+	let ptr = s.as_ptr();
+	let r = s.into_reshaped(Size::new(U1, U6), Order::RowMajor);
+	assert_eq!(r.as_ptr(), ptr);
+	assert_eq!(r.as_slice(), [1., 2., 3., 4., 5., 6.]);
+
+	let cm = ContainerCM::from_vec(Size::new(U2, U2), &[1, 2, 3, 4]);
+	let r = cm.into_reshaped(Size::new(U4, U1), Order::ColMajor);
+	assert_eq!(r.as_slice(), [1, 3, 2, 4]);
+
+	// Borrowing and consuming reshapes agree for both layouts and orders
+	let rm = ContainerRM::from_vec(Size::new(U3, U4), &(0..12).collect::<Vec<_>>());
+	let cm = rm.to_col_major();
+	for order in &[Order::RowMajor, Order::ColMajor] {
+		let expected = rm.reshape(Size::new(U2, U6), *order).into_owned();
+		assert_eq!(cm.reshape(Size::new(U2, U6), *order).into_owned(), expected);
+		assert_eq!(rm.clone().into_reshaped(Size::new(U2, U6), *order), expected);
+		assert_eq!(cm.clone().into_reshaped(Size::new(U2, U6), *order), expected);
+	}
+	assert_eq!(rm.reshape(Size::new(U2, U6), Order::ColMajor).into_owned().get(1, 0), 4);
+}
+
+#[test]
+#[should_panic(expected = "Length mismatch! Expected 4 elements, found 6")]
+fn into_reshaped_mismatch() {
+	mock_container().into_reshaped(Size::new(U2, U2), Order::RowMajor);
+}
+
+#[test]
+fn layout_conversion() {
+	let s = mock_container();
+
+	let cm = s.to_col_major();
+	assert_eq!(cm.as_slice(), [1., 3., 5., 2., 4., 6.]);
+	assert_container_eq!(cm, s);
+	assert_eq!(cm.to_row_major().as_slice(), s.as_slice());
+	assert_eq!(s.transpose_owned().size(), Size::new(D!(2), U3));
+	assert_eq!(s.transpose_owned().as_slice(), [1., 3., 5., 2., 4., 6.]);
+
+	// Larger than a block so the blocked kernel crosses block edges.
+	let data: Vec<i32> = (0..70 * 45).collect();
+	let big = ContainerRM::from_vec(Size::new(D!(70), D!(45)), &data);
+	let big_cm = big.to_col_major();
+	assert_container_eq!(big_cm, big);
+	assert_container_eq!(big.transpose_owned(), big.t());
+}
+
+#[test]
+fn transpose_inplace() {
+	let data: Vec<i32> = (0..70 * 45).collect();
+	let big = ContainerRM::from_vec(Size::new(D!(70), D!(45)), &data);
+	let expected = big.transpose_owned();
+	let t = big.clone_owned().transpose_inplace();
+	assert_eq!(t.size(), Size::new(D!(45), D!(70)));
+	assert_container_eq!(t, expected);
+
+	let cm = big.to_col_major().transpose_inplace();
+	assert_container_eq!(cm, expected);
+
+	let mut square = ContainerRM::from_vec(Size::new(D!(40), D!(40)), &data[..1600]);
+	let expected = square.transpose_owned();
+	square.transpose_square_inplace();
+	assert_container_eq!(square, expected);
+
+	let mut square = ContainerCM::from_vec(Size::new(U2, U2), &[1, 2, 3, 4]);
+	square.transpose_square_inplace();
+	assert_eq!(square.as_slice(), [1, 2, 3, 4]);
+	assert_eq!(square.get(0, 1), 3);
+}
+
+#[test]
+fn growing_major_axis() {
+	let mut s = ContainerRM::from_vec(Size::new(D!(0), U2), &[]);
+	for i in 0..100 {
+		s.push_row(&RowVec::from_vec(Size::new(U1, U2), &[i as f64, -(i as f64)]));
+	}
+	assert_eq!(s.rows(), 100);
+	assert_eq!(s.get(42, 1), -42.);
+	assert!(s.capacity() >= 200);
+
+	s.insert_rows(1, &ContainerRM::from_vec(Size::new(U2, U2), &[7., 8., 9., 10.]));
+	assert_eq!(&s.as_slice()[..8], [0., 0., 7., 8., 9., 10., 1., -1.]);
+	s.remove_rows(1..3);
+	s.remove_rows(0);
+	assert_eq!(s.rows(), 99);
+	assert_eq!(&s.as_slice()[..4], [1., -1., 2., -2.]);
+
+	s.truncate(2);
+	s.truncate(5);
+	assert_eq!(s.as_slice(), [1., -1., 2., -2.]);
+	s.shrink_to_fit();
+	assert_eq!(s.capacity(), 4);
+	s.reserve(3);
+	assert!(s.capacity() >= 10);
+
+	let mut s = ContainerCM::from_vec(Size::new(U2, D!(1)), &[1., 2.]);
+	s.push_col(&ContainerCM::from_vec(Size::new(U2, U1), &[3., 4.]));
+	s.insert_cols(0, &ContainerRM::from_vec(Size::new(U2, U1), &[5., 6.]));
+	assert_eq!(s.as_slice(), [5., 6., 1., 2., 3., 4.]);
+	s.remove_cols(1);
+	assert_eq!(s.as_slice(), [5., 6., 3., 4.]);
+}
+
+#[test]
+fn growing_minor_axis() {
+	let mut s = ContainerRM::from_vec(Size::new(U2, D!(2)), &[1., 2., 3., 4.]);
+	s.push_col(&ContainerRM::from_vec(Size::new(U2, U1), &[5., 6.]));
+	assert_eq!(s.as_slice(), [1., 2., 5., 3., 4., 6.]);
+	s.insert_cols(1, &ContainerRM::from_vec(Size::new(U2, U2), &[7., 8., 9., 10.]));
+	assert_eq!(s.as_slice(), [1., 7., 8., 2., 5., 3., 9., 10., 4., 6.]);
+	s.remove_cols(0..2);
+	assert_eq!(s.as_slice(), [8., 2., 5., 10., 4., 6.]);
+	s.remove_cols(2);
+	assert_eq!(s.as_slice(), [8., 2., 10., 4.]);
+
+	let mut s = ContainerCM::from_vec(Size::new(D!(2), U2), &[1., 2., 3., 4.]);
+	s.push_row(&RowVec::from_vec(Size::new(U1, U2), &[5., 6.]));
+	assert_eq!(s.as_slice(), [1., 3., 5., 2., 4., 6.]);
+	s.insert_rows(0, &RowVec::from_vec(Size::new(U1, U2), &[7., 8.]));
+	s.remove_rows(2);
+	assert_eq!(s.as_slice(), [7., 1., 5., 8., 2., 6.]);
+
+	let mut s = ContainerRM::from_vec(Size::new(U2, D!(0)), &[]);
+	s.push_col(&ContainerRM::from_vec(Size::new(U2, U1), &[1., 2.]));
+	assert_eq!(s.as_slice(), [1., 2.]);
+}
+
+#[test]
+#[should_panic(expected = "Remove range out of bounds!")]
+fn remove_out_of_bounds() {
+	let mut s = ContainerCM::from_vec(Size::new(D!(2), U2), &[1., 2., 3., 4.]);
+	s.remove_rows(1..4);
+}
+
+#[test]
+fn array_storage() {
+	let a = ArrayContainerRM::from_vec(Size::new(U2, U3), &[1., 2., 3., 4., 5., 6.]);
+	assert_eq!(std::mem::size_of_val(&a), 6 * std::mem::size_of::<f64>());
+	assert_eq!(a.get(1, 0), 4.);
+	assert_eq!(a.row_stride(), 3);
+
+	let mut b = a;
+	*b.get_mut(0, 0) = 7.;
+	assert_eq!(a.get(0, 0), 1.);
+	assert_eq!(b.as_slice(), [7., 2., 3., 4., 5., 6.]);
+
+	let sum: ArrayContainerRM<f64, U2, U3> = &a + b;
+	assert_eq!(sum.as_slice(), [8., 4., 6., 8., 10., 12.]);
+	let scaled: ArrayContainerRM<f64, U2, U3> = a * 2.;
+	assert_eq!(scaled.as_slice(), [2., 4., 6., 8., 10., 12.]);
+
+	let c = ArrayContainerCM::from_vec(Size::new(U2, U3), &[1., 2., 3., 4., 5., 6.]);
+	assert_eq!(c.as_slice(), [1., 4., 2., 5., 3., 6.]);
+	assert_eq!(c, a);
+
+	// Owned iterators move the inline elements along with them.
+	let mut iter = c.iter();
+	assert_eq!(iter.next(), Some(1.));
+	let mut moved = Box::new(iter);
+	assert_eq!(moved.len(), 5);
+	assert_eq!(moved.next(), Some(2.));
+	let rest: Vec<f64> = (*moved).collect();
+	assert_eq!(rest, [3., 4., 5., 6.]);
+}
+
+#[test]
+fn slice_owned_types() {
+	let s = ContainerRM::from_vec(Size::new(U3, U3), &[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+	let o: ArrayContainerRM<f64, U2, U3> = s.slice_rows(SizedRange::new(1, U2)).clone_owned();
+	assert_eq!(o.as_slice(), [4., 5., 6., 7., 8., 9.]);
+	let o: ArrayContainerRM<f64, U3, U3> = s.t().owned();
+	assert_eq!(o.as_slice(), [1., 4., 7., 2., 5., 8., 3., 6., 9.]);
+
+	let o: ContainerRM<f64, Dynamic, U3> = s.slice_rows(0..2).clone_owned();
+	assert_eq!(o.rows(), 2);
+
+	let s = ContainerRM::from_value(Size::new(U17, U1), 1.);
+	let o: ContainerRM<f64, U17, U1> = s.into_slice().owned();
+	assert_eq!(o.len(), 17);
+
+	// Without an `OwnedDims` bound slices fall back to `Ownable`, which always allocates
+	fn generic_owned<R: Dim, RS: Dim, C: Dim>(s: Slice<f64, R, RS, C, U1>) -> ContainerRM<f64, R, C> { s.owned() }
+	let s = ContainerRM::from_vec(Size::new(U2, U2), &[1., 2., 3., 4.]);
+	assert_eq!(generic_owned(s.slice_rows(0..2)).as_slice(), [1., 2., 3., 4.]);
+}
+
+#[test]
+fn unchecked_layout() {
+	let padded = VecStorageRM::from_vec_unchecked_layout(
+		Size::new(U2, U2), Strides::new(U3, U1), vec![1, 2, 0, 3, 4, 0]
+	);
+	assert_eq!(padded.as_slice(), [1, 2, 3, 4]);
+
+	let col_major = VecStorageRM::from_vec_unchecked_layout(
+		Size::new(U2, U3), Strides::new(U1, U2), vec![1, 4, 2, 5, 3, 6]
+	);
+	assert_eq!(col_major.as_slice(), [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn seeded_random() {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	let a = ContainerRM::<f64, U3, U4>::rand_uniform(Size::new(U3, U4), &mut StdRng::seed_from_u64(7), -1., 1.);
+	let b = ContainerRM::<f64, U3, U4>::rand_uniform(Size::new(U3, U4), &mut StdRng::seed_from_u64(7), -1., 1.);
+	assert_eq!(a, b);
+	assert!(a.as_iter().all(|v| *v >= -1. && *v < 1.));
+
+	let mut rng = StdRng::seed_from_u64(1);
+	let n = ContainerRM::<f32, U1, Dynamic>::rand_normal(Size::new(U1, D!(10000)), &mut rng, 2., 0.5);
+	let mean = n.as_iter().sum::<f32>() / 10000.;
+	assert!((mean - 2.).abs() < 0.05);
+
+	let e = ContainerRM::<f64, U1, Dynamic>::rand_exp(Size::new(U1, D!(1000)), &mut rng, 2.);
+	assert!(e.as_iter().all(|v| *v >= 0.));
+
+	let b = ContainerRM::<bool, U1, Dynamic>::rand_bernoulli(Size::new(U1, D!(1000)), &mut rng, 0.25);
+	let ones = b.as_iter().filter(|v| **v).count();
+	assert!(ones > 150 && ones < 350);
+
+	let c = ContainerRM::<c64, U1, Dynamic>::rand_complex_normal(
+		Size::new(U1, D!(1000)), &mut rng, c64::new(1., -1.), 0.1
+	);
+	assert!(c.as_iter().all(|v| (v - c64::new(1., -1.)).norm() < 1.));
+}
+
+#[test]
+fn shuffle() {
+	use rand::{SeedableRng, rngs::StdRng};
+
+	let s = ContainerRM::regspace(Size::new(U5, U2), ColAxis, 0);
+	let p = s.permute_rows(&[4, 3, 2, 1, 0]);
+	assert_eq!(p.as_slice(), [4, 4, 3, 3, 2, 2, 1, 1, 0, 0]);
+
+	let mut rng = StdRng::seed_from_u64(3);
+	let p = s.permutation_rows(&mut rng);
+	let mut rows: Vec<_> = p.as_col_range_iter(0).cloned().collect();
+	rows.sort();
+	assert_eq!(rows, [0, 1, 2, 3, 4]);
+
+	let mut s = ContainerRM::regspace(Size::new(U2, U5), RowAxis, 0);
+	s.shuffle_cols(&mut StdRng::seed_from_u64(3));
+	let mut cols: Vec<_> = s.as_row_range_iter(0).cloned().collect();
+	assert_eq!(cols, s.as_row_range_iter(1).cloned().collect::<Vec<_>>());
+	cols.sort();
+	assert_eq!(cols, [0, 1, 2, 3, 4]);
+
+	assert_eq!(permutation(5, &mut StdRng::seed_from_u64(9)), permutation(5, &mut StdRng::seed_from_u64(9)));
+}
+
+#[test]
+fn structured_constructors() {
+	assert_eq!(ContainerRM::<i32, U2, U3>::eye(Size::new(U2, U3)).as_slice(), [
+		1, 0, 0,
+		0, 1, 0,
+	]);
+	assert_eq!(ContainerCM::diag(Size::new(U3, U2), &vec![4, 5]), ContainerRM::from_vec(Size::new(U3, U2), &[
+		4, 0,
+		0, 5,
+		0, 0,
+	]));
+	assert_eq!(
+		ContainerRM::<i32, U2, U2>::try_diag(Size::new(U2, U2), &[1]).err(),
+		Some(ShapeError::LengthMismatch { expected: 2, actual: 1 })
+	);
+	assert_eq!(ContainerRM::from_fn(Size::new(U2, U3), |r, c| (r * 10 + c) as u32).as_slice(), [
+		0, 1, 2,
+		10, 11, 12,
+	]);
+
+	let cm = ContainerCM::from_iter(Size::new(U2, U2), 1..);
+	assert_eq!(cm.as_slice(), [1, 2, 3, 4]);
+	assert_eq!(cm, ContainerRM::from_col_iter(Size::new(U2, U2), 1..5));
+	assert_eq!(ContainerCM::from_row_iter(Size::new(U2, U2), 1..5), ContainerRM::from_vec(Size::new(U2, U2), &[1, 2, 3, 4]));
+
+	let (x, y) = ContainerRM::meshgrid(Size::new(U2, U3), &[1, 2, 3], &[7, 8]);
+	assert_eq!(x.as_slice(), [1, 2, 3, 1, 2, 3]);
+	assert_eq!(y.as_slice(), [7, 7, 7, 8, 8, 8]);
+
+	assert_eq!(ContainerRM::<u8, U3, U3>::tri(Size::new(U3, U3), Triangle::Lower, 0).as_slice(), [
+		1, 0, 0,
+		1, 1, 0,
+		1, 1, 1,
+	]);
+	assert_eq!(ContainerRM::<u8, U2, U3>::tri(Size::new(U2, U3), Triangle::Upper, 1).as_slice(), [
+		0, 1, 1,
+		0, 0, 1,
+	]);
+}
+
+#[test]
+fn log_constructors() {
+	let l = ContainerRM::logspace(Size::new(U1, U3), RowAxis, 0., 2., 10.);
+	assert!(l.as_iter().zip(&[1., 10., 100.]).all(|(a, b)| (a - b).abs() < 1e-9));
+	let g = ContainerRM::geomspace(Size::new(U3, U1), ColAxis, -1., -8.);
+	assert!(g.as_iter().zip(&[-1., -2.8284271247461903, -8.]).all(|(a, b)| (a - b).abs() < 1e-9));
+}
+
+#[test]
+#[should_panic]
+fn from_iter_too_short() {
+	ContainerRM::from_row_iter(Size::new(U2, U2), 0..3);
 }
\ No newline at end of file
@@ -0,0 +1,77 @@
+/* C interface of litio. Generated from src/lib.rs with cbindgen, see cbindgen.toml. */
+
+#ifndef LITIO_H
+#define LITIO_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct LitContainerF32 LitContainerF32;
+typedef struct LitContainerF64 LitContainerF64;
+typedef struct LitContainerI16 LitContainerI16;
+
+typedef struct LitSliceF32 LitSliceF32;
+typedef struct LitSliceF64 LitSliceF64;
+typedef struct LitSliceI16 LitSliceI16;
+
+/* Failing calls return null or -1, including on overflowing sizes or failed allocations.
+ * Message of the last error raised on the calling thread, or null if none occurred. */
+const char *litio_last_error(void);
+
+LitContainerF32 *litio_f32_new(size_t rows, size_t cols);
+LitContainerF32 *litio_f32_from_data(const float *data, size_t rows, size_t cols);
+void litio_f32_free(LitContainerF32 *handle);
+size_t litio_f32_rows(const LitContainerF32 *handle);
+size_t litio_f32_cols(const LitContainerF32 *handle);
+size_t litio_f32_row_stride(const LitContainerF32 *handle);
+size_t litio_f32_col_stride(const LitContainerF32 *handle);
+const float *litio_f32_data(const LitContainerF32 *handle);
+float *litio_f32_data_mut(LitContainerF32 *handle);
+LitContainerF32 *litio_f32_read(const char *path);
+int litio_f32_write(const LitContainerF32 *handle, const char *path);
+LitSliceF32 *litio_f32_view_new(const float *data, size_t len, size_t rows, size_t cols, size_t row_stride, size_t col_stride);
+void litio_f32_view_free(LitSliceF32 *handle);
+LitContainerF32 *litio_f32_view_to_owned(const LitSliceF32 *handle);
+int litio_f32_view_write(const LitSliceF32 *handle, const char *path);
+
+LitContainerF64 *litio_f64_new(size_t rows, size_t cols);
+LitContainerF64 *litio_f64_from_data(const double *data, size_t rows, size_t cols);
+void litio_f64_free(LitContainerF64 *handle);
+size_t litio_f64_rows(const LitContainerF64 *handle);
+size_t litio_f64_cols(const LitContainerF64 *handle);
+size_t litio_f64_row_stride(const LitContainerF64 *handle);
+size_t litio_f64_col_stride(const LitContainerF64 *handle);
+const double *litio_f64_data(const LitContainerF64 *handle);
+double *litio_f64_data_mut(LitContainerF64 *handle);
+LitContainerF64 *litio_f64_read(const char *path);
+int litio_f64_write(const LitContainerF64 *handle, const char *path);
+LitSliceF64 *litio_f64_view_new(const double *data, size_t len, size_t rows, size_t cols, size_t row_stride, size_t col_stride);
+void litio_f64_view_free(LitSliceF64 *handle);
+LitContainerF64 *litio_f64_view_to_owned(const LitSliceF64 *handle);
+int litio_f64_view_write(const LitSliceF64 *handle, const char *path);
+
+LitContainerI16 *litio_i16_new(size_t rows, size_t cols);
+LitContainerI16 *litio_i16_from_data(const int16_t *data, size_t rows, size_t cols);
+void litio_i16_free(LitContainerI16 *handle);
+size_t litio_i16_rows(const LitContainerI16 *handle);
+size_t litio_i16_cols(const LitContainerI16 *handle);
+size_t litio_i16_row_stride(const LitContainerI16 *handle);
+size_t litio_i16_col_stride(const LitContainerI16 *handle);
+const int16_t *litio_i16_data(const LitContainerI16 *handle);
+int16_t *litio_i16_data_mut(LitContainerI16 *handle);
+LitContainerI16 *litio_i16_read(const char *path);
+int litio_i16_write(const LitContainerI16 *handle, const char *path);
+LitSliceI16 *litio_i16_view_new(const int16_t *data, size_t len, size_t rows, size_t cols, size_t row_stride, size_t col_stride);
+void litio_i16_view_free(LitSliceI16 *handle);
+LitContainerI16 *litio_i16_view_to_owned(const LitSliceI16 *handle);
+int litio_i16_view_write(const LitSliceI16 *handle, const char *path);
+
+#ifdef __cplusplus
+} /* extern "C" */
+#endif
+
+#endif /* LITIO_H */
@@ -0,0 +1,247 @@
+//! C ABI for sharing containers with other languages. Containers are passed around as opaque
+//! handles which are created and freed by this library. Functions returning a handle return null
+//! and functions returning a status return -1 on failure, `litio_last_error` describes the cause.
+//! Panics are caught at the boundary and reported the same way.
+use litio::{IOResult, Error, ErrorKind, read_binary_file, write_binary_file};
+use litcontainers::*;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::ptr;
+
+pub type LitContainerF32 = ContainerRM<f32, Dynamic, Dynamic>;
+pub type LitContainerF64 = ContainerRM<f64, Dynamic, Dynamic>;
+pub type LitContainerI16 = ContainerRM<i16, Dynamic, Dynamic>;
+
+pub type LitSliceF32 = Slice<'static, f32, Dynamic, Dynamic, Dynamic, Dynamic>;
+pub type LitSliceF64 = Slice<'static, f64, Dynamic, Dynamic, Dynamic, Dynamic>;
+pub type LitSliceI16 = Slice<'static, i16, Dynamic, Dynamic, Dynamic, Dynamic>;
+
+thread_local! {
+	static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: &str) {
+	let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+	LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+/// Runs the body of an exported function, turning a panic into `failed` so it never unwinds into C.
+fn guard<R, F: FnOnce() -> R>(failed: R, f: F) -> R {
+	catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|e| {
+		let message = e.downcast_ref::<&str>().map(|m| m.to_string())
+			.or_else(|| e.downcast_ref::<String>().cloned())
+			.unwrap_or_else(|| "Unknown panic".to_string());
+		set_last_error(&message);
+		failed
+	})
+}
+
+/// Stores the error for `litio_last_error` and turns the result into an option.
+fn check<T>(result: IOResult<T>) -> Option<T> {
+	result.map_err(|e| set_last_error(e.message())).ok()
+}
+
+unsafe fn path_arg<'a>(path: *const c_char) -> IOResult<&'a Path> {
+	if path.is_null() {
+		return Err(Error::new(ErrorKind::FileIO, "Path is null".to_string()));
+	}
+	CStr::from_ptr(path).to_str()
+		.map(Path::new)
+		.map_err(|_| Error::new(ErrorKind::FileIO, "Path is not valid UTF-8".to_string()))
+}
+
+fn null_error<T>(data: *const T) -> IOResult<()> {
+	if data.is_null() { Err(Error::new(ErrorKind::DataFormat, "Data is null".to_string())) } else { Ok(()) }
+}
+
+/// Allocates a zeroed container, failing instead of aborting if the size overflows or memory runs out.
+fn try_zeros<T: Element>(rows: usize, cols: usize) -> IOResult<ContainerRM<T, Dynamic, Dynamic>> {
+	let dim_error = |message: &str| Error::new(ErrorKind::Dimension { rows: rows as u64, cols: cols as u64 }, message.to_string());
+	let len = rows.checked_mul(cols).ok_or_else(|| dim_error("Element count overflows"))?;
+	let mut data = Vec::new();
+	data.try_reserve_exact(len).map_err(|_| dim_error("Container doesn't fit into memory"))?;
+	data.resize(len, T::default());
+	Ok(VecStorageRM::from_data(Size::new(D!(rows), D!(cols)), data).into())
+}
+
+fn into_handle<T>(value: Option<T>) -> *mut T {
+	value.map_or(ptr::null_mut(), |v| Box::into_raw(Box::new(v)))
+}
+
+fn status(value: Option<()>) -> c_int {
+	if value.is_some() { 0 } else { -1 }
+}
+
+/// Message of the last error raised on the calling thread, or null if none occurred.
+/// The string is owned by the library and valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn litio_last_error() -> *const c_char {
+	LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+macro_rules! ffi_container (
+	(
+		$T: ty, $Container: ident, $Slice: ident,
+		$new: ident, $from_data: ident, $free: ident,
+		$rows: ident, $cols: ident, $row_stride: ident, $col_stride: ident, $data: ident, $data_mut: ident,
+		$read: ident, $write: ident,
+		$view_new: ident, $view_free: ident, $view_to_owned: ident, $view_write: ident $(,)*
+	) => {
+		/// Creates a zero initialized container. Free it with the matching free function.
+		#[no_mangle]
+		pub extern "C" fn $new(rows: usize, cols: usize) -> *mut $Container {
+			guard(ptr::null_mut(), || into_handle(check(try_zeros(rows, cols))))
+		}
+
+		/// Creates a container by copying `rows * cols` row major values.
+		///
+		/// # Safety
+		/// `data` must point to at least `rows * cols` values.
+		#[no_mangle]
+		pub unsafe extern "C" fn $from_data(data: *const $T, rows: usize, cols: usize) -> *mut $Container {
+			guard(ptr::null_mut(), || into_handle(check(null_error(data).and_then(|_| {
+				let mut ret = try_zeros(rows, cols)?;
+				let len = ret.len();
+				ret.as_slice_mut().copy_from_slice(std::slice::from_raw_parts(data, len));
+				Ok(ret)
+			}))))
+		}
+
+		/// # Safety
+		/// `handle` must be null or created by this library and not freed before.
+		#[no_mangle]
+		pub unsafe extern "C" fn $free(handle: *mut $Container) {
+			guard((), || if !handle.is_null() { drop(Box::from_raw(handle)); })
+		}
+
+		/// # Safety
+		/// `handle` must be a valid container.
+		#[no_mangle]
+		pub unsafe extern "C" fn $rows(handle: *const $Container) -> usize { guard(0, || (*handle).rows()) }
+
+		/// # Safety
+		/// `handle` must be a valid container.
+		#[no_mangle]
+		pub unsafe extern "C" fn $cols(handle: *const $Container) -> usize { guard(0, || (*handle).cols()) }
+
+		/// # Safety
+		/// `handle` must be a valid container.
+		#[no_mangle]
+		pub unsafe extern "C" fn $row_stride(handle: *const $Container) -> usize { guard(0, || (*handle).row_stride()) }
+
+		/// # Safety
+		/// `handle` must be a valid container.
+		#[no_mangle]
+		pub unsafe extern "C" fn $col_stride(handle: *const $Container) -> usize { guard(0, || (*handle).col_stride()) }
+
+		/// Pointer to the first element. Valid until the container is freed.
+		///
+		/// # Safety
+		/// `handle` must be a valid container.
+		#[no_mangle]
+		pub unsafe extern "C" fn $data(handle: *const $Container) -> *const $T { guard(ptr::null(), || (*handle).as_ptr()) }
+
+		/// Mutable pointer to the first element. Valid until the container is freed.
+		///
+		/// # Safety
+		/// `handle` must be a valid container.
+		#[no_mangle]
+		pub unsafe extern "C" fn $data_mut(handle: *mut $Container) -> *mut $T { guard(ptr::null_mut(), || (*handle).as_ptr_mut()) }
+
+		/// Reads a container from a binary litio file.
+		///
+		/// # Safety
+		/// `path` must be a null terminated string.
+		#[no_mangle]
+		pub unsafe extern "C" fn $read(path: *const c_char) -> *mut $Container {
+			guard(ptr::null_mut(), || into_handle(check(path_arg(path).and_then(|p| read_binary_file(p)))))
+		}
+
+		/// Writes a container to a binary litio file.
+		///
+		/// # Safety
+		/// `handle` must be a valid container and `path` a null terminated string.
+		#[no_mangle]
+		pub unsafe extern "C" fn $write(handle: *const $Container, path: *const c_char) -> c_int {
+			guard(-1, || status(check(path_arg(path).and_then(|p| write_binary_file(p, &*handle)))))
+		}
+
+		/// Borrows a foreign buffer of `len` values as a strided view without copying.
+		/// Returns null if the strides address values outside of the buffer.
+		///
+		/// # Safety
+		/// `data` must point to `len` values which outlive the view.
+		#[no_mangle]
+		pub unsafe extern "C" fn $view_new(
+			data: *const $T, len: usize, rows: usize, cols: usize, row_stride: usize, col_stride: usize
+		) -> *mut $Slice {
+			guard(ptr::null_mut(), || into_handle(check(null_error(data).and_then(|_| {
+				$Slice::try_from_slice(
+					std::slice::from_raw_parts(data, len),
+					Size::new(D!(rows), D!(cols)),
+					Strides::new(D!(row_stride), D!(col_stride))
+				).map_err(|e| Error::new(ErrorKind::Stride { row_stride: row_stride as u64, col_stride: col_stride as u64 }, e.to_string()))
+			}))))
+		}
+
+		/// Frees the view. The borrowed buffer is left untouched.
+		///
+		/// # Safety
+		/// `handle` must be null or created by this library and not freed before.
+		#[no_mangle]
+		pub unsafe extern "C" fn $view_free(handle: *mut $Slice) {
+			guard((), || if !handle.is_null() { drop(Box::from_raw(handle)); })
+		}
+
+		/// Copies the view into a new row major container.
+		///
+		/// # Safety
+		/// `handle` must be a valid view.
+		#[no_mangle]
+		pub unsafe extern "C" fn $view_to_owned(handle: *const $Slice) -> *mut $Container {
+			guard(ptr::null_mut(), || into_handle(check({
+				let view = &*handle;
+				try_zeros(view.rows(), view.cols()).map(|mut ret| {
+					ret.copy_from(view);
+					ret
+				})
+			})))
+		}
+
+		/// Writes a view to a binary litio file.
+		///
+		/// # Safety
+		/// `handle` must be a valid view and `path` a null terminated string.
+		#[no_mangle]
+		pub unsafe extern "C" fn $view_write(handle: *const $Slice, path: *const c_char) -> c_int {
+			guard(-1, || status(check(path_arg(path).and_then(|p| write_binary_file(p, &*handle)))))
+		}
+	}
+);
+
+ffi_container!(
+	f32, LitContainerF32, LitSliceF32,
+	litio_f32_new, litio_f32_from_data, litio_f32_free,
+	litio_f32_rows, litio_f32_cols, litio_f32_row_stride, litio_f32_col_stride, litio_f32_data, litio_f32_data_mut,
+	litio_f32_read, litio_f32_write,
+	litio_f32_view_new, litio_f32_view_free, litio_f32_view_to_owned, litio_f32_view_write,
+);
+
+ffi_container!(
+	f64, LitContainerF64, LitSliceF64,
+	litio_f64_new, litio_f64_from_data, litio_f64_free,
+	litio_f64_rows, litio_f64_cols, litio_f64_row_stride, litio_f64_col_stride, litio_f64_data, litio_f64_data_mut,
+	litio_f64_read, litio_f64_write,
+	litio_f64_view_new, litio_f64_view_free, litio_f64_view_to_owned, litio_f64_view_write,
+);
+
+ffi_container!(
+	i16, LitContainerI16, LitSliceI16,
+	litio_i16_new, litio_i16_from_data, litio_i16_free,
+	litio_i16_rows, litio_i16_cols, litio_i16_row_stride, litio_i16_col_stride, litio_i16_data, litio_i16_data_mut,
+	litio_i16_read, litio_i16_write,
+	litio_i16_view_new, litio_i16_view_free, litio_i16_view_to_owned, litio_i16_view_write,
+);
\ No newline at end of file
@@ -0,0 +1,66 @@
+use litio_ffi::*;
+use std::ffi::{CString, CStr};
+use std::path::PathBuf;
+
+#[test]
+fn test_ffi_container() {
+	unsafe {
+		let data = [1f64, 2., 3., 4., 5., 6.];
+		let c = litio_f64_from_data(data.as_ptr(), 2, 3);
+		assert_eq!((litio_f64_rows(c), litio_f64_cols(c)), (2, 3));
+		assert_eq!((litio_f64_row_stride(c), litio_f64_col_stride(c)), (3, 1));
+		*litio_f64_data_mut(c).add(5) = 9.;
+
+		let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tmp");
+		if !path.exists() {
+			std::fs::create_dir(&path).unwrap();
+		}
+		path.push("ffi_container.lit");
+		let path = CString::new(path.to_str().unwrap()).unwrap();
+		assert_eq!(litio_f64_write(c, path.as_ptr()), 0);
+
+		let r = litio_f64_read(path.as_ptr());
+		assert!(!r.is_null());
+		assert_eq!(std::slice::from_raw_parts(litio_f64_data(r), 6), [1., 2., 3., 4., 5., 9.]);
+		litio_f64_free(r);
+		litio_f64_free(c);
+
+		let missing = CString::new("tmp/ffi_missing.lit").unwrap();
+		assert!(litio_i16_read(missing.as_ptr()).is_null());
+		assert!(!litio_last_error().is_null());
+	}
+}
+
+#[test]
+fn test_ffi_view() {
+	unsafe {
+		let data = [1f32, 2., 3., 0., 4., 5., 6., 0.];
+		let v = litio_f32_view_new(data.as_ptr(), data.len(), 2, 3, 4, 1);
+		let c = litio_f32_view_to_owned(v);
+		assert_eq!(std::slice::from_raw_parts(litio_f32_data(c), 6), [1., 2., 3., 4., 5., 6.]);
+		litio_f32_free(c);
+		litio_f32_view_free(v);
+
+		assert!(litio_f32_view_new(data.as_ptr(), data.len(), 3, 3, 3, 1).is_null());
+		let message = CStr::from_ptr(litio_last_error()).to_str().unwrap();
+		assert!(message.contains("out of bounds"));
+	}
+}
+
+#[test]
+fn test_ffi_overflow() {
+	unsafe {
+		let data = [1i16];
+		assert!(litio_i16_from_data(data.as_ptr(), usize::MAX, 2).is_null());
+		assert!(!litio_last_error().is_null());
+		assert!(litio_i16_new(usize::MAX, usize::MAX).is_null());
+		assert!(litio_i16_new(usize::MAX / 4, 1).is_null());
+		assert!(litio_i16_view_new(data.as_ptr(), 1, 3, 1, 1 << 63, 1).is_null());
+
+		// Zero strides fit any buffer, copying them must fail gracefully instead of aborting
+		let v = litio_i16_view_new(data.as_ptr(), 1, usize::MAX / 2, 4, 0, 0);
+		assert!(!v.is_null());
+		assert!(litio_i16_view_to_owned(v).is_null());
+		litio_i16_view_free(v);
+	}
+}
\ No newline at end of file
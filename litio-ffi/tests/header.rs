@@ -0,0 +1,26 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// Names starting with `litio_`. Exported functions are only named in the source and the header.
+fn symbols(path: &str) -> BTreeSet<String> {
+	let text = std::fs::read_to_string(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path)).unwrap();
+	text.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+		.filter(|s| s.starts_with("litio_"))
+		.map(|s| s.to_string())
+		.collect()
+}
+
+#[test]
+fn test_header_in_sync() {
+	let exported = symbols("src/lib.rs");
+	let declared = symbols("include/litio.h");
+	assert!(exported.contains("litio_f64_view_new"));
+	assert_eq!(
+		exported.difference(&declared).collect::<Vec<_>>(), Vec::<&String>::new(),
+		"Exported functions missing from include/litio.h, regenerate it with cbindgen"
+	);
+	assert_eq!(
+		declared.difference(&exported).collect::<Vec<_>>(), Vec::<&String>::new(),
+		"include/litio.h declares functions which aren't exported"
+	);
+}
\ No newline at end of file
@@ -0,0 +1,236 @@
+use crate::format::*;
+use crate::error::*;
+use crate::{BinarySerializer, BinaryDeserializer, GeneralDeserializer, AttributeSerializer, AttributeDeserializer};
+use litcontainers::*;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+const ARCHIVE_MAGIC: [u8; 4] = *b"LITA";
+const ARCHIVE_VERSION: u8 = 1;
+const PREAMBLE_SIZE: u64 = 5;
+const FOOTER_SIZE: u64 = 12;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Footer {
+	index_offset: u64,
+	magic: [u8; 4],
+}
+
+/// Index record of a single container stored in an archive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveEntry {
+	name: String,
+	element_type: u8,
+	rows: u64,
+	cols: u64,
+	offset: u64,
+	length: u64,
+}
+
+impl ArchiveEntry {
+	pub fn name(&self) -> &str { &self.name }
+
+	pub fn element_type(&self) -> Option<ElementType> { element_from_byte(self.element_type) }
+
+	pub fn rows(&self) -> usize { self.rows as usize }
+
+	pub fn cols(&self) -> usize { self.cols as usize }
+
+	/// Number of bytes the entry occupies in the archive.
+	pub fn byte_size(&self) -> usize { self.length as usize }
+}
+
+/// Single file holding many named containers of possibly different element types and shapes.
+/// Layout:
+/// * Magic `LITA` and format version
+/// * Entries, each written by the `BinarySerializer`
+/// * Index table listing name, type, shape and position of every entry
+/// * Footer with the index offset and magic
+///
+/// Index is written anew on `flush`, `close` or drop when entries were added. Entries and indices are only
+/// ever appended and every insert ends the file with a footer pointing to the last written index, so a
+/// failed or interrupted session loses the unflushed entries but leaves the archive readable.
+/// Superseded indices stay in the file as unused bytes.
+#[derive(Debug)]
+pub struct Archive {
+	file: File,
+	entries: Vec<ArchiveEntry>,
+	/// Offset of the last written index.
+	index_offset: u64,
+	/// Offset where the next entry or index is written.
+	data_end: u64,
+	dirty: bool,
+}
+
+impl Archive {
+	/// Creates a new empty archive. Truncates the file if it exists.
+	pub fn create(path: &Path) -> IOResult<Self> {
+		let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+		Self::init(file)
+	}
+
+	/// Opens an existing archive for reading.
+	pub fn open(path: &Path) -> IOResult<Self> {
+		Self::load(File::open(path)?)
+	}
+
+	/// Opens an archive for reading and appending entries. Creates the archive if it doesn't exist.
+	pub fn open_append(path: &Path) -> IOResult<Self> {
+		let file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+		if file.metadata()?.len() == 0 {
+			Self::init(file)
+		} else {
+			Self::load(file)
+		}
+	}
+
+	fn init(mut file: File) -> IOResult<Self> {
+		file.write_all(&ARCHIVE_MAGIC)?;
+		file.write_all(&[ARCHIVE_VERSION])?;
+		let mut ret = Self { file, entries: Vec::new(), index_offset: PREAMBLE_SIZE, data_end: PREAMBLE_SIZE, dirty: true };
+		ret.flush()?;
+		Ok(ret)
+	}
+
+	fn load(mut file: File) -> IOResult<Self> {
+		let mut preamble = [0u8; PREAMBLE_SIZE as usize];
+		file.read_exact(&mut preamble)?;
+		if preamble[0..4] != ARCHIVE_MAGIC {
+			return Err(df_error("Not a litio archive!"));
+		}
+		if preamble[4] != ARCHIVE_VERSION {
+			return Err(df_error("Unsupported archive version!"));
+		}
+
+		let len = file.metadata()?.len();
+		if len < PREAMBLE_SIZE + FOOTER_SIZE {
+			return Err(df_error("Archive is missing its index!"));
+		}
+		file.seek(SeekFrom::Start(len - FOOTER_SIZE))?;
+		let footer: Footer = bincode::deserialize_from(&mut file)?;
+		if footer.magic != ARCHIVE_MAGIC || footer.index_offset < PREAMBLE_SIZE || footer.index_offset > len - FOOTER_SIZE {
+			return Err(df_error("Invalid archive footer!"));
+		}
+
+		file.seek(SeekFrom::Start(footer.index_offset))?;
+		let entries = bincode::deserialize_from((&mut file).take(len - FOOTER_SIZE - footer.index_offset))?;
+		Ok(Self { file, entries, index_offset: footer.index_offset, data_end: len, dirty: false })
+	}
+
+	/// Lists all entries in insertion order.
+	pub fn entries(&self) -> &[ArchiveEntry] { &self.entries }
+
+	pub fn entry(&self, name: &str) -> Option<&ArchiveEntry> {
+		self.entries.iter().find(|e| e.name == name)
+	}
+
+	pub fn contains(&self, name: &str) -> bool { self.entry(name).is_some() }
+
+	pub fn len(&self) -> usize { self.entries.len() }
+
+	pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+	/// Reads the entry with given name. Only the entry itself is read from disk.
+	pub fn get<T, S>(&mut self, name: &str) -> IOResult<S>
+		where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>
+	{
+		let reader = self.entry_reader::<T>(name)?;
+		BinaryDeserializer::read(reader)
+	}
+
+	/// Reads the entry with given name together with its attributes.
+	pub fn get_with_attributes<T, S>(&mut self, name: &str) -> IOResult<(S, Attributes)>
+		where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>
+	{
+		let reader = self.entry_reader::<T>(name)?;
+		BinaryDeserializer::read_with_attributes(reader)
+	}
+
+	fn entry_reader<T: Element>(&mut self, name: &str) -> IOResult<std::io::Take<&mut File>> {
+		let (offset, length) = match self.entry(name) {
+			Some(e) if e.element_type() == Some(T::element_type()) => (e.offset, e.length),
+			Some(e) => return Err(ErrorKind::ElementType { expected: element_to_byte(T::element_type()), found: e.element_type }.into()),
+			None => return Err(ErrorKind::EntryNotFound(name.to_string()).into()),
+		};
+		self.file.seek(SeekFrom::Start(offset))?;
+		Ok((&mut self.file).take(length))
+	}
+
+	/// Appends a new entry to the archive. Names must be unique.
+	pub fn insert<T, S>(&mut self, name: &str, data: &S) -> IOResult<()>
+		where T: NumericElement + SerializableScalar, S: Storage<T>
+	{
+		self.insert_with_attributes(name, data, &Attributes::new())
+	}
+
+	/// Appends a new entry with attributes to the archive. Names must be unique.
+	pub fn insert_with_attributes<T, S>(&mut self, name: &str, data: &S, attributes: &Attributes) -> IOResult<()>
+		where T: NumericElement + SerializableScalar, S: Storage<T>
+	{
+		if self.contains(name) {
+			return Err(ErrorKind::DuplicateEntry(name.to_string()).into());
+		}
+
+		let mut bytes = Vec::new();
+		BinarySerializer::write_with_attributes(&mut bytes, data, attributes)?;
+
+		if let Err(e) = self.append_entry(&bytes) {
+			// Drop the partial entry so the file ends with a valid footer again
+			let _ = self.file.set_len(self.data_end).map_err(Error::from).and_then(|_| self.write_footer(self.data_end));
+			return Err(e);
+		}
+		self.entries.push(ArchiveEntry {
+			name: name.to_string(),
+			element_type: element_to_byte(T::element_type()),
+			rows: data.rows() as u64,
+			cols: data.cols() as u64,
+			offset: self.data_end,
+			length: bytes.len() as u64,
+		});
+		self.data_end += bytes.len() as u64;
+		self.dirty = true;
+		Ok(())
+	}
+
+	/// Writes the entry at the end of the data followed by a footer still pointing to the last written index.
+	fn append_entry(&mut self, bytes: &[u8]) -> IOResult<()> {
+		self.file.seek(SeekFrom::Start(self.data_end))?;
+		self.file.write_all(bytes)?;
+		self.write_footer(self.data_end + bytes.len() as u64)
+	}
+
+	/// Writes the footer for the current index at given offset and truncates anything after it.
+	fn write_footer(&mut self, offset: u64) -> IOResult<()> {
+		self.file.seek(SeekFrom::Start(offset))?;
+		self.file.write_all(&bincode::serialize(&Footer { index_offset: self.index_offset, magic: ARCHIVE_MAGIC })?)?;
+		self.file.set_len(offset + FOOTER_SIZE)?;
+		Ok(())
+	}
+
+	/// Writes the index table and footer if entries were added since the last flush.
+	pub fn flush(&mut self) -> IOResult<()> {
+		if !self.dirty { return Ok(()); }
+
+		// The previous index stays valid until the footer pointing to the new one is written
+		let index = bincode::serialize(&self.entries)?;
+		self.file.seek(SeekFrom::Start(self.data_end))?;
+		self.file.write_all(&index)?;
+		self.index_offset = self.data_end;
+		self.data_end += index.len() as u64;
+		self.write_footer(self.data_end)?;
+		self.data_end += FOOTER_SIZE;
+		self.file.flush()?;
+		self.dirty = false;
+		Ok(())
+	}
+
+	/// Flushes and closes the archive.
+	pub fn close(mut self) -> IOResult<()> { self.flush() }
+}
+
+impl Drop for Archive {
+	fn drop(&mut self) {
+		let _ = self.flush();
+	}
+}
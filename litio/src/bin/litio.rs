@@ -0,0 +1,187 @@
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use litcontainers::*;
+use litio::*;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+type Data<T> = ContainerRM<T, Dynamic, Dynamic>;
+
+/// Element types the tool can load and convert.
+trait CliElement: Scalar + Display + FromStr + SerializableScalar + DeserializableScalar {
+	fn load(path: &Path, info: &BinaryInfo) -> IOResult<Data<Self>>;
+}
+
+macro_rules! impl_cli_element {
+	($Deserializer: ident => $($Type: ty),*) => {$(
+		impl CliElement for $Type {
+			fn load(path: &Path, info: &BinaryInfo) -> IOResult<Data<Self>> {
+				if info.is_row_major() {
+					litio::read::<$Deserializer<_, _>, _>(path)
+				} else {
+					let data: ContainerCM<Self, Dynamic, Dynamic> = litio::read::<$Deserializer<_, _>, _>(path)?;
+					Ok(ContainerRM::from_vec(data.size(), &data.as_iter().cloned().collect::<Vec<_>>()))
+				}
+			}
+		}
+	)*}
+}
+
+impl_cli_element!(QuantizedDeserializer => f32, f64);
+impl_cli_element!(BinaryDeserializer => i16, i32, i64, u8);
+
+macro_rules! dispatch {
+	($element_type: expr, $f: ident ( $($arg: expr),* )) => {
+		match $element_type {
+			Some(ElementType::Scalar(ScalarType::F32)) => $f::<f32>($($arg),*),
+			Some(ElementType::Scalar(ScalarType::F64)) => $f::<f64>($($arg),*),
+			Some(ElementType::Scalar(ScalarType::I16)) => $f::<i16>($($arg),*),
+			Some(ElementType::Scalar(ScalarType::I32)) => $f::<i32>($($arg),*),
+			Some(ElementType::Scalar(ScalarType::I64)) => $f::<i64>($($arg),*),
+			Some(ElementType::Scalar(ScalarType::U8)) => $f::<u8>($($arg),*),
+			t => Err(df_error(&format!("Unsupported element type {:?}!", t))),
+		}
+	}
+}
+
+fn parse_type(name: &str) -> Option<ElementType> {
+	match name {
+		"f32" => Some(f32::element_type()),
+		"f64" => Some(f64::element_type()),
+		"i16" => Some(i16::element_type()),
+		"i32" => Some(i32::element_type()),
+		"i64" => Some(i64::element_type()),
+		"u8" => Some(u8::element_type()),
+		_ => None,
+	}
+}
+
+fn is_archive(path: &Path) -> IOResult<bool> {
+	use std::io::Read;
+	let mut magic = [0u8; 4];
+	let n = std::fs::File::open(path)?.read(&mut magic)?;
+	Ok(n == 4 && &magic == b"LITA")
+}
+
+fn info(path: &Path) -> IOResult<()> {
+	if is_archive(path)? {
+		let archive = Archive::open(path)?;
+		println!("Archive with {} entries", archive.len());
+		for e in archive.entries() {
+			println!("  {:<24} {:?} {}x{} ({} bytes)", e.name(), e.element_type(), e.rows(), e.cols(), e.byte_size());
+		}
+		return Ok(());
+	}
+
+	let info = BinaryInfo::read_file(path)?;
+	println!("Element type: {:?}", info.element_type);
+	println!("Element size: {}", info.element_size);
+	println!("Shape:        {}x{}", info.rows, info.cols);
+	println!("Strides:      {}, {}", info.row_stride, info.col_stride);
+	println!("Layout:       {}", if info.is_row_major() { "row major" } else { "col major" });
+	println!("Quantized:    {}", info.quantized);
+	println!("Checksum:     {}", info.checksum);
+	if !info.attributes.is_empty() {
+		println!("Attributes:");
+		for (k, v) in &info.attributes {
+			println!("  {} = {:?}", k, v);
+		}
+	}
+	Ok(())
+}
+
+fn stats<T: CliElement>(path: &Path, info: &BinaryInfo) -> IOResult<()> {
+	let data = T::load(path, info)?;
+	println!("Count: {}", data.len());
+	if data.len() == 0 { return Ok(()); }
+
+	let values: Vec<f64> = data.as_iter().map(|v| num_traits::cast(*v).unwrap_or(f64::NAN)).collect();
+	let values = ContainerRM::from_vec(data.size(), &values);
+	println!("Min:   {}", data.minimum());
+	println!("Max:   {}", data.maximum());
+	println!("Mean:  {}", values.mean());
+	Ok(())
+}
+
+fn head<T: CliElement>(path: &Path, info: &BinaryInfo, rows: usize) -> IOResult<()> {
+	let data = T::load(path, info)?;
+	print!("{}", data.slice_rows(0..rows.min(data.rows())));
+	Ok(())
+}
+
+fn to_csv<T: CliElement>(input: &Path, info: &BinaryInfo, output: &Path) -> IOResult<()> {
+	let data = T::load(input, info)?;
+	write_csv_file(output, &data)
+}
+
+fn from_csv<T: CliElement>(input: &Path, output: &Path, options: &WriteOptions) -> IOResult<()> {
+	let data: Data<T> = read_csv_file(input)?;
+	let mut f = std::fs::File::create(output)?;
+	BinarySerializer::write_with_options(&mut f, &data, options)
+}
+
+fn is_csv(path: &Path) -> bool {
+	path.extension().is_some_and(|e| e.eq_ignore_ascii_case("csv"))
+}
+
+fn run(matches: &ArgMatches) -> IOResult<()> {
+	let path = |m: &ArgMatches, name: &str| Path::new(m.value_of(name).unwrap()).to_path_buf();
+
+	match matches.subcommand() {
+		("info", Some(m)) => info(&path(m, "FILE")),
+		("stats", Some(m)) => {
+			let file = path(m, "FILE");
+			let info = BinaryInfo::read_file(&file)?;
+			dispatch!(info.element_type, stats(&file, &info))
+		}
+		("head", Some(m)) => {
+			let file = path(m, "FILE");
+			let rows = m.value_of("rows").unwrap().parse().map_err(|_| df_error("Invalid row count!"))?;
+			let info = BinaryInfo::read_file(&file)?;
+			dispatch!(info.element_type, head(&file, &info, rows))
+		}
+		("convert", Some(m)) => {
+			let (input, output) = (path(m, "INPUT"), path(m, "OUTPUT"));
+			if is_csv(&input) {
+				let element_type = parse_type(m.value_of("type").unwrap());
+				let options = WriteOptions::new().with_checksum(m.is_present("checksum"));
+				dispatch!(element_type, from_csv(&input, &output, &options))
+			} else {
+				let info = BinaryInfo::read_file(&input)?;
+				dispatch!(info.element_type, to_csv(&input, &info, &output))
+			}
+		}
+		_ => unreachable!(),
+	}
+}
+
+fn main() {
+	let file_arg = || Arg::with_name("FILE").help("Binary container file").required(true);
+	let matches = App::new("litio")
+		.about("Inspects and converts litio container files")
+		.setting(AppSettings::SubcommandRequiredElseHelp)
+		.subcommand(SubCommand::with_name("info")
+			.about("Prints the header, element type, shape and strides")
+			.arg(file_arg()))
+		.subcommand(SubCommand::with_name("stats")
+			.about("Prints the minimum, maximum and mean")
+			.arg(file_arg()))
+		.subcommand(SubCommand::with_name("head")
+			.about("Prints the first rows")
+			.arg(file_arg())
+			.arg(Arg::with_name("rows").short("n").long("rows").takes_value(true).default_value("10")))
+		.subcommand(SubCommand::with_name("convert")
+			.about("Converts between the binary format and CSV. Direction is chosen by the .csv extension of the input")
+			.arg(Arg::with_name("INPUT").required(true))
+			.arg(Arg::with_name("OUTPUT").required(true))
+			.arg(Arg::with_name("type").short("t").long("type").takes_value(true)
+				.possible_values(&["f32", "f64", "i16", "i32", "i64", "u8"]).default_value("f64")
+				.help("Element type of the binary output when converting from CSV"))
+			.arg(Arg::with_name("checksum").long("checksum").help("Append a checksum to the binary output")))
+		.get_matches();
+
+	if let Err(e) = run(&matches) {
+		eprintln!("Error: {}", e);
+		std::process::exit(1);
+	}
+}
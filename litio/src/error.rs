@@ -4,10 +4,47 @@ use std::fmt::Formatter;
 
 pub type IOResult<T> = Result<T, Error>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ErrorKind {
 	FileIO,
-	DataFormat
+	DataFormat,
+	/// Data ended before the container was complete.
+	Truncated,
+	/// Data continues after the container.
+	TrailingData,
+	ElementType { expected: u8, found: u8 },
+	ElementSize { expected: u64, found: u64 },
+	/// Stored shape doesn't fit the dimensions of the requested storage.
+	Dimension { rows: u64, cols: u64 },
+	Stride { row_stride: u64, col_stride: u64 },
+	/// Number of stored values doesn't match the header.
+	BodySize { expected: u64, found: u64 },
+	ElementLimit { limit: u64, found: u64 },
+	ByteLimit { limit: u64 },
+	Checksum { expected: u32, found: u32 },
+	EntryNotFound(String),
+	DuplicateEntry(String),
+}
+
+impl fmt::Display for ErrorKind {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			ErrorKind::FileIO => write!(f, "File IO error"),
+			ErrorKind::DataFormat => write!(f, "Invalid data format"),
+			ErrorKind::Truncated => write!(f, "Unexpected end of data"),
+			ErrorKind::TrailingData => write!(f, "Unexpected data after the container"),
+			ErrorKind::ElementType { expected, found } => write!(f, "Invalid element type {}, expected {}", found, expected),
+			ErrorKind::ElementSize { expected, found } => write!(f, "Invalid element size {}, expected {}", found, expected),
+			ErrorKind::Dimension { rows, cols } => write!(f, "Invalid dimensions {}x{}", rows, cols),
+			ErrorKind::Stride { row_stride, col_stride } => write!(f, "Invalid storage strides {}, {}", row_stride, col_stride),
+			ErrorKind::BodySize { expected, found } => write!(f, "Invalid body size {}, expected {}", found, expected),
+			ErrorKind::ElementLimit { limit, found } => write!(f, "Element count {} exceeds the limit of {}", found, limit),
+			ErrorKind::ByteLimit { limit } => write!(f, "Data exceeds the limit of {} bytes", limit),
+			ErrorKind::Checksum { expected, found } => write!(f, "Checksum mismatch {:08x}, expected {:08x}", found, expected),
+			ErrorKind::EntryNotFound(name) => write!(f, "Archive entry '{}' not found", name),
+			ErrorKind::DuplicateEntry(name) => write!(f, "Archive entry '{}' already exists", name),
+		}
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +54,10 @@ impl Error {
 	pub fn new(kind: ErrorKind, message: String) -> Self {
 		Self(kind, message)
 	}
+
+	pub fn kind(&self) -> &ErrorKind { &self.0 }
+
+	pub fn message(&self) -> &str { &self.1 }
 }
 
 impl error::Error for Error {}
@@ -27,14 +68,31 @@ impl fmt::Display for Error {
 	}
 }
 
+impl From<ErrorKind> for Error {
+	fn from(kind: ErrorKind) -> Self {
+		let message = kind.to_string();
+		Self::new(kind, message)
+	}
+}
+
 impl From<std::io::Error> for Error {
-	fn from(e: std::io::Error) -> Self { Self::new(ErrorKind::FileIO, e.to_string())}
+	fn from(e: std::io::Error) -> Self {
+		match e.kind() {
+			std::io::ErrorKind::UnexpectedEof => Self::new(ErrorKind::Truncated, e.to_string()),
+			_ => Self::new(ErrorKind::FileIO, e.to_string()),
+		}
+	}
 }
 
 impl From<bincode::Error> for Error {
-	fn from(e: bincode::Error) -> Self { Self::new(ErrorKind::DataFormat, e.to_string())}
+	fn from(e: bincode::Error) -> Self {
+		match *e {
+			bincode::ErrorKind::Io(e) => e.into(),
+			e => Self::new(ErrorKind::DataFormat, e.to_string()),
+		}
+	}
 }
 
 pub fn df_error(message: &str) -> Error {
 	Error::new(ErrorKind::DataFormat, message.to_string())
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use crate::{GeneralSerializer, IOResult, GeneralDeserializer};
+use crate::{GeneralSerializer, IOResult, GeneralDeserializer, AttributeSerializer, AttributeDeserializer, Attributes};
 use std::fs::File;
 use std::path::Path;
 
@@ -10,4 +10,14 @@ pub fn write<S: GeneralSerializer<T>, T>(path: &Path, data: &T) -> IOResult<()>{
 pub fn read<S: GeneralDeserializer<T>, T>(path: &Path) -> IOResult<T>{
 	let mut f = File::open(path)?;
 	S::read(&mut f)
+}
+
+pub fn write_with_attributes<S: AttributeSerializer<T>, T>(path: &Path, data: &T, attributes: &Attributes) -> IOResult<()>{
+	let mut f = File::create(path)?;
+	S::write_with_attributes(&mut f, data, attributes)
+}
+
+pub fn read_with_attributes<S: AttributeDeserializer<T>, T>(path: &Path) -> IOResult<(T, Attributes)>{
+	let mut f = File::open(path)?;
+	S::read_with_attributes(&mut f)
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Iter;
+
+/// Value of a single metadata attribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AttributeValue {
+	Int(i64),
+	Float(f64),
+	String(String),
+	IntVec(Vec<i64>),
+	FloatVec(Vec<f64>),
+}
+
+impl AttributeValue {
+	pub fn as_int(&self) -> Option<i64> {
+		match self { AttributeValue::Int(v) => Some(*v), _ => None }
+	}
+
+	pub fn as_float(&self) -> Option<f64> {
+		match self { AttributeValue::Float(v) => Some(*v), _ => None }
+	}
+
+	pub fn as_str(&self) -> Option<&str> {
+		match self { AttributeValue::String(v) => Some(v.as_str()), _ => None }
+	}
+
+	pub fn as_int_vec(&self) -> Option<&[i64]> {
+		match self { AttributeValue::IntVec(v) => Some(v.as_slice()), _ => None }
+	}
+
+	pub fn as_float_vec(&self) -> Option<&[f64]> {
+		match self { AttributeValue::FloatVec(v) => Some(v.as_slice()), _ => None }
+	}
+}
+
+macro_rules! impl_attribute_from (
+	($($Type: ty => $Variant: ident: $conv: expr),* $(,)*) => {$(
+		impl From<$Type> for AttributeValue {
+			fn from(v: $Type) -> Self { AttributeValue::$Variant($conv(v)) }
+		}
+	)*}
+);
+
+impl_attribute_from!(
+	i64      => Int: |v| v,
+	i32      => Int: |v| v as i64,
+	u32      => Int: |v| v as i64,
+	f64      => Float: |v| v,
+	f32      => Float: |v| v as f64,
+	String   => String: |v| v,
+	&str     => String: |v: &str| v.to_string(),
+	Vec<i64> => IntVec: |v| v,
+	&[i64]   => IntVec: |v: &[i64]| v.to_vec(),
+	Vec<f64> => FloatVec: |v| v,
+	&[f64]   => FloatVec: |v: &[f64]| v.to_vec(),
+);
+
+/// Key/value metadata stored alongside a container. Keys are kept sorted so serialized output is
+/// deterministic.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Attributes(BTreeMap<String, AttributeValue>);
+
+impl Attributes {
+	pub fn new() -> Self { Self::default() }
+
+	pub fn insert<K: Into<String>, V: Into<AttributeValue>>(&mut self, key: K, value: V) -> Option<AttributeValue> {
+		self.0.insert(key.into(), value.into())
+	}
+
+	/// Builder style variant of `insert`.
+	pub fn with<K: Into<String>, V: Into<AttributeValue>>(mut self, key: K, value: V) -> Self {
+		self.insert(key, value);
+		self
+	}
+
+	pub fn get(&self, key: &str) -> Option<&AttributeValue> { self.0.get(key) }
+
+	pub fn remove(&mut self, key: &str) -> Option<AttributeValue> { self.0.remove(key) }
+
+	pub fn contains_key(&self, key: &str) -> bool { self.0.contains_key(key) }
+
+	pub fn len(&self) -> usize { self.0.len() }
+
+	pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+	pub fn iter(&self) -> Iter<'_, String, AttributeValue> { self.0.iter() }
+}
+
+impl<'a> IntoIterator for &'a Attributes {
+	type Item = (&'a String, &'a AttributeValue);
+	type IntoIter = Iter<'a, String, AttributeValue>;
+
+	fn into_iter(self) -> Self::IntoIter { self.0.iter() }
+}
@@ -1,5 +1,7 @@
 pub mod scalar;
 pub mod serializable_scalar;
+pub mod attributes;
 
 pub use scalar::*;
-pub use serializable_scalar::*;
\ No newline at end of file
+pub use serializable_scalar::*;
+pub use attributes::*;
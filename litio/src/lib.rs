@@ -7,11 +7,13 @@ pub mod format;
 pub mod error;
 pub mod serializers;
 pub mod file;
+pub mod archive;
 
 pub use format::*;
 pub use serializers::*;
 pub use file::*;
 pub use error::*;
+pub use archive::*;
 
 #[cfg(test)]
 mod tests {
@@ -1,27 +1,275 @@
 use crate::format::*;
 use crate::error::*;
-use crate::{StorageSerializerLossy, GeneralSerializer, GeneralDeserializer, StorageDeserializerLossy};
+use crate::{StorageSerializerLossy, GeneralSerializer, GeneralDeserializer, StorageDeserializerLossy, AttributeSerializer, AttributeDeserializer, ReadLimits, WriteOptions};
 use litcontainers::*;
 use std::marker::PhantomData;
-use serde::{Serialize, Deserialize};
+use std::io::Read;
 use std::path::Path;
+use bincode::Options;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use std::fmt;
 
 // TODO: can be improved alot by serialilzing certain types like size, stride. But works just fine for now
 
+/// Set on `Header::element_type` when a list of `HeaderSection`s follows the header.
+const EXTENDED_HEADER: u8 = 32;
+
+/// Section kind holding bincode serialized `Attributes`.
+pub(crate) const SECTION_ATTRIBUTES: u8 = 1;
+
+/// Section kind holding the quantization parameters. Body is stored as quantized integers.
+pub(crate) const SECTION_QUANTIZATION: u8 = 2;
+
+/// Section kind without data. Marks a little endian CRC32 of all preceding bytes after the body.
+pub(crate) const SECTION_CHECKSUM: u8 = 3;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct Header {
-	element_type: u8,
-	element_size: u64,
-	rows: u64,
-	cols: u64,
-	row_stride: u64,
-	col_stride: u64,
+pub(crate) struct Header {
+	pub element_type: u8,
+	pub element_size: u64,
+	pub rows: u64,
+	pub cols: u64,
+	pub row_stride: u64,
+	pub col_stride: u64,
 }
 
 impl Header {
 	pub fn element_type(&self) -> Option<ElementType> {
-		element_from_byte(self.element_type)
+		element_from_byte(self.element_type & !EXTENDED_HEADER)
+	}
+
+	pub fn is_extended(&self) -> bool { self.element_type & EXTENDED_HEADER == EXTENDED_HEADER }
+}
+
+/// Tagged block of the extended header. Readers skip sections of a kind they don't know.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct HeaderSection {
+	pub kind: u8,
+	pub data: Vec<u8>,
+}
+
+pub(crate) fn find_section(sections: &[HeaderSection], kind: u8) -> Option<&HeaderSection> {
+	sections.iter().find(|s| s.kind == kind)
+}
+
+/// Sections for the attributes and checksum. Empty attributes are not written.
+pub(crate) fn option_sections(attributes: &Attributes, checksum: bool) -> IOResult<Vec<HeaderSection>> {
+	let mut ret = Vec::new();
+	if !attributes.is_empty() {
+		ret.push(HeaderSection { kind: SECTION_ATTRIBUTES, data: bincode::serialize(attributes)? });
+	}
+	if checksum {
+		ret.push(HeaderSection { kind: SECTION_CHECKSUM, data: Vec::new() });
+	}
+	Ok(ret)
+}
+
+pub(crate) fn read_attributes(sections: &[HeaderSection]) -> IOResult<Attributes> {
+	match find_section(sections, SECTION_ATTRIBUTES) {
+		Some(section) => Ok(bincode::deserialize(&section.data)?),
+		None => Ok(Attributes::new()),
+	}
+}
+
+/// Writes header, sections, an already serialized body and the checksum if requested by the sections.
+pub(crate) fn write_frame<W: std::io::Write>(writer: &mut W, mut header: Header, sections: &[HeaderSection], body: &[u8]) -> IOResult<()> {
+	if !sections.is_empty() {
+		header.element_type |= EXTENDED_HEADER;
+	}
+
+	let mut hasher = crc32fast::Hasher::new();
+	let mut write = |bytes: &[u8]| -> IOResult<()> {
+		hasher.update(bytes);
+		writer.write_all(bytes)?;
+		Ok(())
+	};
+	write(&bincode::serialize(&header)?)?;
+	if header.is_extended() {
+		write(&bincode::serialize(sections)?)?;
+	}
+	write(body)?;
+
+	if find_section(sections, SECTION_CHECKSUM).is_some() {
+		writer.write_all(&hasher.finalize().to_le_bytes())?;
+	}
+	Ok(())
+}
+
+/// Reader keeping track of the consumed bytes and their checksum.
+struct HashingReader<RD> {
+	reader: RD,
+	hasher: crc32fast::Hasher,
+	consumed: u64,
+}
+
+impl<RD: Read> Read for HashingReader<RD> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let n = self.reader.read(buf)?;
+		self.hasher.update(&buf[..n]);
+		self.consumed += n as u64;
+		Ok(n)
+	}
+}
+
+/// Seed deserializing exactly the given number of values written back to back.
+struct Values<B>(usize, PhantomData<B>);
+
+impl<'de, B: DeserializeOwned> DeserializeSeed<'de> for Values<B> {
+	type Value = Vec<B>;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<B>, D::Error> {
+		deserializer.deserialize_tuple(self.0, self)
+	}
+}
+
+impl<'de, B: DeserializeOwned> Visitor<'de> for Values<B> {
+	type Value = Vec<B>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{} values", self.0) }
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<B>, A::Error> {
+		let mut ret = Vec::with_capacity(self.0);
+		for i in 0..self.0 {
+			ret.push(seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?);
+		}
+		Ok(ret)
+	}
+}
+
+/// Reads the parts of serialized storage, validating them against the `ReadLimits` before allocating.
+pub(crate) struct FrameReader<RD> {
+	reader: HashingReader<RD>,
+	limits: ReadLimits,
+	pub header: Header,
+	pub sections: Vec<HeaderSection>,
+}
+
+impl<RD: Read> FrameReader<RD> {
+	/// Reads the header and its sections without validating them.
+	pub fn open_unchecked(reader: RD, limits: &ReadLimits) -> IOResult<Self> {
+		let mut reader = HashingReader { reader, hasher: crc32fast::Hasher::new(), consumed: 0 };
+		let header: Header = bincode::deserialize_from(&mut reader)?;
+
+		let mut ret = Self { reader, limits: *limits, header, sections: Vec::new() };
+		if ret.header.is_extended() {
+			ret.sections = ret.deserialize_limited()?;
+		}
+		Ok(ret)
+	}
+
+	/// Reads and validates the header and its sections.
+	pub fn open(reader: RD, limits: &ReadLimits, element_type: ElementType, element_size: usize) -> IOResult<Self> {
+		let ret = Self::open_unchecked(reader, limits)?;
+		let header = &ret.header;
+
+		if header.element_type() != Some(element_type) {
+			return Err(ErrorKind::ElementType { expected: element_to_byte(element_type), found: header.element_type & !EXTENDED_HEADER }.into());
+		}
+		if header.element_size != element_size as u64 {
+			return Err(ErrorKind::ElementSize { expected: element_size as u64, found: header.element_size }.into());
+		}
+		match header.rows.checked_mul(header.cols) {
+			Some(n) if n <= limits.max_elements => {},
+			n => return Err(ErrorKind::ElementLimit { limit: limits.max_elements, found: n.unwrap_or(u64::MAX) }.into()),
+		}
+		Ok(ret)
+	}
+
+	pub fn element_count(&self) -> usize { (self.header.rows * self.header.cols) as usize }
+
+	fn deserialize_limited<D: DeserializeOwned>(&mut self) -> IOResult<D> {
+		let limit = self.limits.max_bytes.saturating_sub(self.reader.consumed);
+		self.deserialize_seed(PhantomData, limit)
+	}
+
+	/// Deserializes with the seed, reading at most `limit` bytes.
+	fn deserialize_seed<'de, D: DeserializeSeed<'de>>(&mut self, seed: D, limit: u64) -> IOResult<D::Value> {
+		let max_bytes = self.limits.max_bytes;
+		bincode::options()
+			.with_fixint_encoding()
+			.allow_trailing_bytes()
+			.with_limit(limit)
+			.deserialize_from_seed(seed, &mut self.reader)
+			.map_err(|e| match *e {
+				bincode::ErrorKind::SizeLimit => ErrorKind::ByteLimit { limit: max_bytes }.into(),
+				e => Box::new(e).into(),
+			})
+	}
+
+	/// Reads a body of `element_count` values, each taking `element_size` bytes.
+	pub fn read_body<B: DeserializeOwned>(&mut self, element_size: usize) -> IOResult<Vec<B>> {
+		let expected = self.element_count() as u64;
+		// Body is prefixed by its u64 length
+		let body_size = expected.saturating_mul(element_size as u64).saturating_add(8);
+		if self.reader.consumed.saturating_add(body_size) > self.limits.max_bytes {
+			return Err(ErrorKind::ByteLimit { limit: self.limits.max_bytes }.into());
+		}
+
+		// Length is checked before reading any values, so a corrupt prefix can't read past the body
+		let found: u64 = self.deserialize_limited()?;
+		if found != expected {
+			return Err(ErrorKind::BodySize { expected, found }.into());
+		}
+		self.deserialize_seed(Values(expected as usize, PhantomData), body_size - 8)
+	}
+
+	/// Verifies the checksum and that no data follows. Returns the header sections.
+	pub fn finish(mut self) -> IOResult<Vec<HeaderSection>> {
+		if find_section(&self.sections, SECTION_CHECKSUM).is_some() {
+			let found = self.reader.hasher.clone().finalize();
+			let mut footer = [0u8; 4];
+			self.reader.read_exact(&mut footer)?;
+			let expected = u32::from_le_bytes(footer);
+			if expected != found {
+				return Err(ErrorKind::Checksum { expected, found }.into());
+			}
+		}
+		if !self.limits.allow_trailing_data && self.reader.read(&mut [0u8; 1])? > 0 {
+			return Err(ErrorKind::TrailingData.into());
+		}
+		Ok(self.sections)
+	}
+}
+
+
+/// Description of binary serialized storage, read from its header alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryInfo {
+	pub element_type: Option<ElementType>,
+	pub element_size: u64,
+	pub rows: u64,
+	pub cols: u64,
+	pub row_stride: u64,
+	pub col_stride: u64,
+	pub attributes: Attributes,
+	pub quantized: bool,
+	pub checksum: bool,
+}
+
+impl BinaryInfo {
+	/// Reads the header of binary serialized storage of any element type. The body is not read.
+	pub fn read<RD: std::io::Read>(reader: RD) -> IOResult<Self> {
+		let frame = FrameReader::open_unchecked(reader, &ReadLimits::default())?;
+		let header = &frame.header;
+		Ok(Self {
+			element_type: header.element_type(),
+			element_size: header.element_size,
+			rows: header.rows,
+			cols: header.cols,
+			row_stride: header.row_stride,
+			col_stride: header.col_stride,
+			attributes: read_attributes(&frame.sections)?,
+			quantized: find_section(&frame.sections, SECTION_QUANTIZATION).is_some(),
+			checksum: find_section(&frame.sections, SECTION_CHECKSUM).is_some(),
+		})
+	}
+
+	pub fn read_file(path: &Path) -> IOResult<Self> {
+		Self::read(std::fs::File::open(path)?)
 	}
+
+	/// Whether the storage was stored in row major layout.
+	pub fn is_row_major(&self) -> bool { self.row_stride == self.cols && self.col_stride == 1 }
 }
 
 pub struct BinarySerializer<T, S>
@@ -34,29 +282,51 @@ impl<T, S> StorageSerializerLossy<T, S> for BinarySerializer<T, S>
 	where T: NumericElement + SerializableScalar, S: Storage<T>
 {}
 
-/// Responsible for serializing storage into a binary format
-/// Output looks like this:
-impl<T, S> GeneralSerializer<S> for BinarySerializer<T, S>
+impl<T, S> BinarySerializer<T, S>
 	where T: NumericElement + SerializableScalar, S: Storage<T>
 {
-	/// Serializes storage to binary format into a writer
-	fn write<W: std::io::Write>(writer: &mut W, storage: &S) -> IOResult<()> {
-		let header = Header {
+	pub(crate) fn header(storage: &S) -> Header {
+		Header {
 			element_type: element_to_byte(T::element_type()),
 			element_size: T::byte_size() as u64,
 			rows: storage.rows() as u64,
 			cols: storage.cols() as u64,
 			row_stride: storage.row_stride() as u64,
 			col_stride: storage.col_stride() as u64
-		};
+		}
+	}
 
-		let header_bytes = bincode::serialize(&header)?;
-		writer.write(header_bytes.as_slice())?;
+	/// Serializes storage together with the attributes and checksum from the options.
+	pub fn write_with_options<W: std::io::Write>(writer: &mut W, storage: &S, options: &WriteOptions) -> IOResult<()> {
+		let sections = option_sections(&options.attributes, options.checksum)?;
+		let body: Vec<_> = storage.as_iter().cloned().map(|v| ScalarSerializer::new(v)).collect();
+		write_frame(writer, Self::header(storage), &sections, &bincode::serialize(&body)?)
+	}
+}
 
+/// Responsible for serializing storage into a binary format
+/// Output looks like this:
+/// * `Header` with the element type, size and strides
+/// * A list of `HeaderSection`s, only if `EXTENDED_HEADER` is set in the element type
+/// * Body containing all elements in row major order
+/// * CRC32 of all above, only if the checksum section is present
+impl<T, S> GeneralSerializer<S> for BinarySerializer<T, S>
+	where T: NumericElement + SerializableScalar, S: Storage<T>
+{
+	/// Serializes storage to binary format into a writer
+	fn write<W: std::io::Write>(writer: &mut W, storage: &S) -> IOResult<()> {
+		Self::write_with_options(writer, storage, &WriteOptions::new())
+	}
+}
+
+impl<T, S> AttributeSerializer<S> for BinarySerializer<T, S>
+	where T: NumericElement + SerializableScalar, S: Storage<T>
+{
+	/// Serializes storage to binary format into a writer. Attributes are stored in the extended header.
+	fn write_with_attributes<W: std::io::Write>(writer: &mut W, storage: &S, attributes: &Attributes) -> IOResult<()> {
+		let sections = option_sections(attributes, false)?;
 		let body: Vec<_> = storage.as_iter().cloned().map(|v| ScalarSerializer::new(v)).collect();
-		let body_bytes = bincode::serialize(&body)?;
-		writer.write(&body_bytes)?;
-		Ok(())
+		write_frame(writer, Self::header(storage), &sections, &bincode::serialize(&body)?)
 	}
 }
 
@@ -70,31 +340,65 @@ impl<T, S> StorageDeserializerLossy<T, S> for BinaryDeserializer<T, S>
 	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
 {}
 
-impl<T, S> GeneralDeserializer<S> for BinaryDeserializer<T, S>
+impl<T, S> BinaryDeserializer<T, S>
 	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
 {
-	fn read<RD: std::io::Read>(reader: RD) -> IOResult<S> {
-		let mut reader = reader;
+	pub(crate) fn size(header: &Header) -> IOResult<Size<S::Rows, S::Cols>> {
+		let dim_error = || Error::from(ErrorKind::Dimension { rows: header.rows, cols: header.cols });
+		let rows = S::Rows::try_from_usize(header.rows as usize).ok_or_else(dim_error)?;
+		let cols = S::Cols::try_from_usize(header.cols as usize).ok_or_else(dim_error)?;
+		Ok(Size::new(rows, cols))
+	}
 
-		let header: Header = bincode::deserialize_from(&mut reader)?;
-		match header.element_type() {
-			Some(t) if t == T::element_type() => {},
-			_ => return Err(df_error("Invaid element format!"))
+	pub(crate) fn from_body(header: &Header, body: &[T]) -> IOResult<S> {
+		let stride_error = || Error::from(ErrorKind::Stride { row_stride: header.row_stride, col_stride: header.col_stride });
+		let row_stride = S::RowStride::try_from_usize(header.row_stride as usize).ok_or_else(stride_error)?;
+		let col_stride = S::ColStride::try_from_usize(header.col_stride as usize).ok_or_else(stride_error)?;
+
+		let ret = S::from_vec(Self::size(header)?, body);
+		if row_stride.value() != ret.row_stride() || col_stride.value() != ret.col_stride() {
+			return Err(stride_error())
 		}
+		Ok(ret)
+	}
 
-		let rows = S::Rows::try_from_usize(header.rows as usize).ok_or(df_error("Invalid row dimension!"))?;
-		let cols = S::Cols::try_from_usize(header.cols as usize).ok_or(df_error("Invalid col dimension!"))?;
-		let row_stride = S::RowStride::try_from_usize(header.row_stride as usize).ok_or(df_error("Invalid row stride dimension!"))?;
-		let col_stride = S::ColStride::try_from_usize(header.col_stride as usize).ok_or(df_error("Invalid col stride dimension!"))?;
+	fn read_sections<RD: std::io::Read>(reader: RD, limits: &ReadLimits) -> IOResult<(S, Vec<HeaderSection>)> {
+		let mut frame = FrameReader::open(reader, limits, T::element_type(), T::byte_size())?;
+		if find_section(&frame.sections, SECTION_QUANTIZATION).is_some() {
+			return Err(df_error("Data is quantized! Use the QuantizedDeserializer."))
+		}
+		Self::size(&frame.header)?;
 
-		let body: Vec<ScalarDeserializer<T>> = bincode::deserialize_from(&mut reader)?;
+		let body: Vec<ScalarDeserializer<T>> = frame.read_body(T::byte_size())?;
 		let body: Vec<_> = body.into_iter().map(|v| v.data()).collect();
-		let ret = S::from_vec(Size::new(rows, cols), &body);
-		if row_stride.value() != ret.row_stride() || col_stride.value() != ret.col_stride() {
-			return Err(df_error("Invalid storage strides!"))
-		}
+		let ret = Self::from_body(&frame.header, &body)?;
 
-		Ok(ret)
+		Ok((ret, frame.finish()?))
+	}
+
+	/// Deserializes storage and its attributes while enforcing given limits.
+	pub fn read_with_limits<RD: std::io::Read>(reader: RD, limits: &ReadLimits) -> IOResult<(S, Attributes)> {
+		let (ret, sections) = Self::read_sections(reader, limits)?;
+		Ok((ret, read_attributes(&sections)?))
+	}
+}
+
+impl<T, S> GeneralDeserializer<S> for BinaryDeserializer<T, S>
+	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	/// Deserializes storage from a reader with the default `ReadLimits`. Any extended header sections are skipped.
+	fn read<RD: std::io::Read>(reader: RD) -> IOResult<S> {
+		Self::read_sections(reader, &ReadLimits::default()).map(|(ret, _)| ret)
+	}
+}
+
+impl<T, S> AttributeDeserializer<S> for BinaryDeserializer<T, S>
+	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	/// Deserializes storage and its attributes from a reader. Returns empty attributes if the data
+	/// was written without them.
+	fn read_with_attributes<RD: std::io::Read>(reader: RD) -> IOResult<(S, Attributes)> {
+		Self::read_with_limits(reader, &ReadLimits::default())
 	}
 }
 
@@ -110,6 +414,18 @@ pub fn read_binary<T, S, RD>(reader: RD) -> IOResult<S>
 	BinaryDeserializer::read(reader)
 }
 
+pub fn write_binary_with_options<T, S, W>(writer: &mut W, data: &S, options: &WriteOptions) -> IOResult<()>
+	where T: NumericElement + SerializableScalar, S: Storage<T>, W: std::io::Write
+{
+	BinarySerializer::write_with_options(writer, data, options)
+}
+
+pub fn read_binary_with_limits<T, S, RD>(reader: RD, limits: &ReadLimits) -> IOResult<(S, Attributes)>
+	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>, RD: std::io::Read,
+{
+	BinaryDeserializer::read_with_limits(reader, limits)
+}
+
 pub fn write_binary_file<T, S>(path: &Path, data: &S) -> IOResult<()>
 	where T: NumericElement + SerializableScalar, S: Storage<T>,
 {
@@ -120,4 +436,28 @@ pub fn read_binary_file<T, S>(path: &Path) -> IOResult<S>
 	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
 {
 	crate::file::read::<BinaryDeserializer<_, _>, _>(path)
+}
+
+pub fn write_binary_with_attributes<T, S, W>(writer: &mut W, data: &S, attributes: &Attributes) -> IOResult<()>
+	where T: NumericElement + SerializableScalar, S: Storage<T>, W: std::io::Write
+{
+	BinarySerializer::write_with_attributes(writer, data, attributes)
+}
+
+pub fn read_binary_with_attributes<T, S, RD>(reader: RD) -> IOResult<(S, Attributes)>
+	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>, RD: std::io::Read,
+{
+	BinaryDeserializer::read_with_attributes(reader)
+}
+
+pub fn write_binary_file_with_attributes<T, S>(path: &Path, data: &S, attributes: &Attributes) -> IOResult<()>
+	where T: NumericElement + SerializableScalar, S: Storage<T>,
+{
+	crate::file::write_with_attributes::<BinarySerializer<_, _>, _>(path, data, attributes)
+}
+
+pub fn read_binary_file_with_attributes<T, S>(path: &Path) -> IOResult<(S, Attributes)>
+	where T: NumericElement + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	crate::file::read_with_attributes::<BinaryDeserializer<_, _>, _>(path)
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::error::*;
+use crate::{GeneralSerializer, GeneralDeserializer};
+use litcontainers::*;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Serializes storage as comma separated values. Every row of the storage is written on its own line.
+pub struct CsvSerializer<T, S>
+	where T: Scalar + Display, S: Storage<T>,
+{
+	_phantoms: PhantomData<(T, S)>
+}
+
+impl<T, S> GeneralSerializer<S> for CsvSerializer<T, S>
+	where T: Scalar + Display, S: Storage<T>
+{
+	fn write<W: std::io::Write>(writer: &mut W, storage: &S) -> IOResult<()> {
+		for row in storage.as_row_slice_iter() {
+			let line: Vec<_> = row.as_iter().map(|v| v.to_string()).collect();
+			writeln!(writer, "{}", line.join(","))?;
+		}
+		Ok(())
+	}
+}
+
+/// Deserializes comma separated values. Every line is a row, empty lines are skipped.
+pub struct CsvDeserializer<T, S>
+	where T: Scalar + FromStr, S: Storage<T>,
+{
+	_phantoms: PhantomData<(T, S)>
+}
+
+impl<T, S> GeneralDeserializer<S> for CsvDeserializer<T, S>
+	where T: Scalar + FromStr, S: Storage<T> + StorageConstructor<T>
+{
+	fn read<RD: std::io::Read>(reader: RD) -> IOResult<S> {
+		let mut body = Vec::new();
+		let (mut rows, mut cols) = (0, None);
+		for (i, line) in std::io::BufReader::new(reader).lines().enumerate() {
+			let line = line?;
+			if line.trim().is_empty() { continue; }
+
+			let len = body.len();
+			for v in line.split(',') {
+				let v = v.trim();
+				body.push(v.parse().map_err(|_| df_error(&format!("Invalid value '{}' on line {}!", v, i + 1)))?);
+			}
+			match cols {
+				Some(c) if c != body.len() - len => return Err(df_error(&format!("Invalid column count on line {}!", i + 1))),
+				_ => cols = Some(body.len() - len),
+			}
+			rows += 1;
+		}
+
+		let cols = cols.unwrap_or(0);
+		let size = Size::new(
+			S::Rows::try_from_usize(rows).ok_or_else(|| Error::from(ErrorKind::Dimension { rows: rows as u64, cols: cols as u64 }))?,
+			S::Cols::try_from_usize(cols).ok_or_else(|| Error::from(ErrorKind::Dimension { rows: rows as u64, cols: cols as u64 }))?,
+		);
+		Ok(S::from_vec(size, &body))
+	}
+}
+
+pub fn write_csv<T, S, W>(writer: &mut W, data: &S) -> IOResult<()>
+	where T: Scalar + Display, S: Storage<T>, W: std::io::Write
+{
+	CsvSerializer::write(writer, data)
+}
+
+pub fn read_csv<T, S, RD>(reader: RD) -> IOResult<S>
+	where T: Scalar + FromStr, S: Storage<T> + StorageConstructor<T>, RD: std::io::Read,
+{
+	CsvDeserializer::read(reader)
+}
+
+pub fn write_csv_file<T, S>(path: &Path, data: &S) -> IOResult<()>
+	where T: Scalar + Display, S: Storage<T>,
+{
+	crate::file::write::<CsvSerializer<_, _>, _>(path, data)
+}
+
+pub fn read_csv_file<T, S>(path: &Path) -> IOResult<S>
+	where T: Scalar + FromStr, S: Storage<T> + StorageConstructor<T>,
+{
+	crate::file::read::<CsvDeserializer<_, _>, _>(path)
+}
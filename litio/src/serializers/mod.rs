@@ -1,5 +1,11 @@
 pub mod serializer;
+pub mod options;
 pub mod binary;
+pub mod quantized;
+pub mod csv;
 
 pub use serializer::*;
+pub use options::*;
 pub use binary::*;
+pub use quantized::*;
+pub use csv::*;
\ No newline at end of file
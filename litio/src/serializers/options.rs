@@ -0,0 +1,49 @@
+use crate::Attributes;
+
+/// Limits checked while reading, before anything is allocated. Guards against corrupt or hostile
+/// headers claiming huge containers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadLimits {
+	/// Maximum number of elements in the container.
+	pub max_elements: u64,
+	/// Maximum number of bytes read, including the header.
+	pub max_bytes: u64,
+	/// Whether data may continue after the container. Rejected by default, readers of streams holding several
+	/// containers one after another need to allow it.
+	pub allow_trailing_data: bool,
+}
+
+impl ReadLimits {
+	pub fn new(max_elements: u64, max_bytes: u64) -> Self {
+		Self { max_elements, max_bytes, allow_trailing_data: false }
+	}
+
+	pub fn unlimited() -> Self { Self::new(u64::MAX, u64::MAX) }
+
+	pub fn with_max_elements(self, max_elements: u64) -> Self { Self { max_elements, ..self } }
+
+	pub fn with_max_bytes(self, max_bytes: u64) -> Self { Self { max_bytes, ..self } }
+
+	pub fn with_trailing_data(self, allow_trailing_data: bool) -> Self { Self { allow_trailing_data, ..self } }
+}
+
+impl Default for ReadLimits {
+	/// 256M elements and 4GiB
+	fn default() -> Self { Self::new(1 << 28, 1 << 32) }
+}
+
+/// Optional parts written alongside the container.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WriteOptions {
+	pub attributes: Attributes,
+	/// Append a CRC32 of everything written to the end of the data.
+	pub checksum: bool,
+}
+
+impl WriteOptions {
+	pub fn new() -> Self { Self::default() }
+
+	pub fn with_attributes(self, attributes: Attributes) -> Self { Self { attributes, ..self } }
+
+	pub fn with_checksum(self, checksum: bool) -> Self { Self { checksum, ..self } }
+}
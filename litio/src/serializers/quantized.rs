@@ -0,0 +1,348 @@
+use crate::format::*;
+use crate::error::*;
+use crate::{StorageSerializerLossy, GeneralSerializer, GeneralDeserializer, StorageDeserializerLossy, AttributeSerializer, AttributeDeserializer, ReadLimits, WriteOptions};
+use crate::serializers::binary::*;
+use litcontainers::*;
+use num_traits::Float;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Integer type quantized values are stored as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuantizedType {
+	U8,
+	I16,
+}
+
+impl QuantizedType {
+	/// Number of quantization steps between the smallest and largest code.
+	pub fn steps(&self) -> f64 {
+		match self {
+			QuantizedType::U8 => u8::MAX as f64,
+			QuantizedType::I16 => u16::MAX as f64,
+		}
+	}
+
+	fn min_code(&self) -> f64 {
+		match self {
+			QuantizedType::U8 => 0.,
+			QuantizedType::I16 => i16::MIN as f64,
+		}
+	}
+}
+
+/// Mapping of values onto quantization codes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum QuantizationMode {
+	/// Evenly spaced codes between the minimum and maximum.
+	Linear,
+	/// μ-law companding with given μ. Spends more codes on values close to the center of the range.
+	MuLaw(f64),
+}
+
+/// Group of values sharing a scale and offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuantizationScope {
+	Container,
+	Row,
+}
+
+/// Parameters of a lossy quantization.
+///
+/// Maximum absolute error for a group with values in `[min, max]`:
+/// * Linear: `(max - min) / (2 * steps)`
+/// * μ-law: `(max - min) / (2 * steps) * ln(1 + μ) * (1 + μ) / μ`, reached at the range edges
+///
+/// Where `steps` is 255 for `U8` and 65535 for `I16`. Non finite values are not preserved.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quantization {
+	pub target: QuantizedType,
+	pub mode: QuantizationMode,
+	pub scope: QuantizationScope,
+}
+
+impl Quantization {
+	pub fn new(target: QuantizedType, mode: QuantizationMode, scope: QuantizationScope) -> Self {
+		Self { target, mode, scope }
+	}
+
+	pub fn linear(target: QuantizedType) -> Self {
+		Self::new(target, QuantizationMode::Linear, QuantizationScope::Container)
+	}
+
+	pub fn mu_law(target: QuantizedType, mu: f64) -> Self {
+		Self::new(target, QuantizationMode::MuLaw(mu), QuantizationScope::Container)
+	}
+
+	/// Same quantization, but with a scale and offset per row.
+	pub fn per_row(self) -> Self {
+		Self { scope: QuantizationScope::Row, ..self }
+	}
+
+	/// Upper bound of the absolute error for a group of values within `[min, max]`.
+	pub fn max_error(&self, min: f64, max: f64) -> f64 {
+		let half_step = (max - min) / (2. * self.target.steps());
+		match self.mode {
+			QuantizationMode::Linear => half_step,
+			QuantizationMode::MuLaw(mu) => half_step * mu.ln_1p() * (1. + mu) / mu,
+		}
+	}
+
+	fn group_params(&self, values: &[f64]) -> (f64, f64) {
+		let (min, max) = values.iter().filter(|v| v.is_finite())
+			.fold(None, |acc: Option<(f64, f64)>, &v| match acc {
+				Some((min, max)) => Some((min.min(v), max.max(v))),
+				None => Some((v, v)),
+			})
+			.unwrap_or((0., 0.));
+
+		// Returns (offset, scale). Constant groups get a unit scale to avoid dividing by zero
+		let (offset, scale) = match self.mode {
+			QuantizationMode::Linear => (min, (max - min) / self.target.steps()),
+			QuantizationMode::MuLaw(_) => ((max + min) / 2., (max - min) / 2.),
+		};
+		(offset, if scale > 0. { scale } else { 1. })
+	}
+
+	fn encode(&self, v: f64, offset: f64, scale: f64) -> f64 {
+		let steps = self.target.steps();
+		let code = match self.mode {
+			QuantizationMode::Linear => (v - offset) / scale,
+			QuantizationMode::MuLaw(mu) => {
+				let n = ((v - offset) / scale).clamp(-1., 1.);
+				let y = n.signum() * (mu * n.abs()).ln_1p() / mu.ln_1p();
+				(y + 1.) / 2. * steps
+			}
+		};
+		code.round().max(0.).min(steps) + self.target.min_code()
+	}
+
+	fn decode(&self, code: f64, offset: f64, scale: f64) -> f64 {
+		let code = code - self.target.min_code();
+		match self.mode {
+			QuantizationMode::Linear => code * scale + offset,
+			QuantizationMode::MuLaw(mu) => {
+				let y = code / self.target.steps() * 2. - 1.;
+				let n = y.signum() * ((1. + mu).powf(y.abs()) - 1.) / mu;
+				n * scale + offset
+			}
+		}
+	}
+}
+
+/// Quantization parameters and per group scales stored in the extended header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QuantizationSection {
+	quantization: Quantization,
+	offsets: Vec<f64>,
+	scales: Vec<f64>,
+}
+
+/// Quantization known at compile time. Used by `QuantizedSerializer` to implement the serializer traits.
+pub trait QuantizationScheme {
+	fn quantization() -> Quantization;
+}
+
+pub struct LinearU8;
+pub struct LinearI16;
+pub struct MuLawU8;
+pub struct MuLawI16;
+/// Applies the wrapped scheme per row.
+pub struct PerRow<Q: QuantizationScheme>(PhantomData<Q>);
+
+/// μ used by the predefined μ-law schemes.
+pub const DEFAULT_MU: f64 = 255.;
+
+impl QuantizationScheme for LinearU8 {
+	fn quantization() -> Quantization { Quantization::linear(QuantizedType::U8) }
+}
+
+impl QuantizationScheme for LinearI16 {
+	fn quantization() -> Quantization { Quantization::linear(QuantizedType::I16) }
+}
+
+impl QuantizationScheme for MuLawU8 {
+	fn quantization() -> Quantization { Quantization::mu_law(QuantizedType::U8, DEFAULT_MU) }
+}
+
+impl QuantizationScheme for MuLawI16 {
+	fn quantization() -> Quantization { Quantization::mu_law(QuantizedType::I16, DEFAULT_MU) }
+}
+
+impl<Q: QuantizationScheme> QuantizationScheme for PerRow<Q> {
+	fn quantization() -> Quantization { Q::quantization().per_row() }
+}
+
+/// Lossy serializer storing float containers as `u8` or `i16` codes.
+/// Output is the same as `BinarySerializer`, except:
+/// * Extended header contains a quantization section with the offset and scale of every group
+/// * Body contains the quantized codes in row major order
+pub struct QuantizedSerializer<T, S, Q = LinearU8>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>, Q: QuantizationScheme
+{
+	_phantoms: PhantomData<(T, S, Q)>
+}
+
+impl<T, S, Q> StorageSerializerLossy<T, S> for QuantizedSerializer<T, S, Q>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>, Q: QuantizationScheme
+{}
+
+impl<T, S, Q> QuantizedSerializer<T, S, Q>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>, Q: QuantizationScheme
+{
+	fn write_sections<W: std::io::Write>(writer: &mut W, storage: &S, quantization: &Quantization, attributes: &Attributes, checksum: bool) -> IOResult<()> {
+		let values: Vec<f64> = storage.as_iter().map(|v| v.to_f64().unwrap_or(0.)).collect();
+		let group_size = match quantization.scope {
+			QuantizationScope::Container => values.len(),
+			QuantizationScope::Row => storage.cols(),
+		};
+
+		let mut section = QuantizationSection { quantization: *quantization, offsets: Vec::new(), scales: Vec::new() };
+		let mut codes = Vec::with_capacity(values.len());
+		if group_size > 0 {
+			for group in values.chunks(group_size) {
+				let (offset, scale) = quantization.group_params(group);
+				codes.extend(group.iter().map(|v| quantization.encode(*v, offset, scale)));
+				section.offsets.push(offset);
+				section.scales.push(scale);
+			}
+		}
+		let mut sections = option_sections(attributes, checksum)?;
+		sections.push(HeaderSection { kind: SECTION_QUANTIZATION, data: bincode::serialize(&section)? });
+
+		let body = match quantization.target {
+			QuantizedType::U8 => bincode::serialize(&codes.iter().map(|v| *v as u8).collect::<Vec<_>>())?,
+			QuantizedType::I16 => bincode::serialize(&codes.iter().map(|v| *v as i16).collect::<Vec<_>>())?,
+		};
+		write_frame(writer, BinarySerializer::header(storage), &sections, &body)
+	}
+
+	/// Serializes storage with a quantization chosen at runtime.
+	pub fn write_with<W: std::io::Write>(writer: &mut W, storage: &S, quantization: &Quantization) -> IOResult<()> {
+		Self::write_sections(writer, storage, quantization, &Attributes::new(), false)
+	}
+
+	/// Serializes storage with a quantization chosen at runtime, and the attributes and checksum from the options.
+	pub fn write_with_options<W: std::io::Write>(writer: &mut W, storage: &S, quantization: &Quantization, options: &WriteOptions) -> IOResult<()> {
+		Self::write_sections(writer, storage, quantization, &options.attributes, options.checksum)
+	}
+}
+
+impl<T, S, Q> GeneralSerializer<S> for QuantizedSerializer<T, S, Q>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>, Q: QuantizationScheme
+{
+	fn write<W: std::io::Write>(writer: &mut W, storage: &S) -> IOResult<()> {
+		Self::write_with(writer, storage, &Q::quantization())
+	}
+}
+
+impl<T, S, Q> AttributeSerializer<S> for QuantizedSerializer<T, S, Q>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>, Q: QuantizationScheme
+{
+	fn write_with_attributes<W: std::io::Write>(writer: &mut W, storage: &S, attributes: &Attributes) -> IOResult<()> {
+		Self::write_sections(writer, storage, &Q::quantization(), attributes, false)
+	}
+}
+
+/// Deserializer reversing the `QuantizedSerializer`. Data written by the `BinarySerializer` is read as is.
+pub struct QuantizedDeserializer<T, S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T>,
+{
+	_phantoms: PhantomData<(T, S)>
+}
+
+impl<T, S> StorageDeserializerLossy<T, S> for QuantizedDeserializer<T, S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{}
+
+impl<T, S> QuantizedDeserializer<T, S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	fn read_sections<RD: std::io::Read>(reader: RD, limits: &ReadLimits) -> IOResult<(S, Vec<HeaderSection>)> {
+		let mut frame = FrameReader::open(reader, limits, T::element_type(), T::byte_size())?;
+		BinaryDeserializer::<T, S>::size(&frame.header)?;
+		let section: QuantizationSection = match find_section(&frame.sections, SECTION_QUANTIZATION) {
+			Some(section) => bincode::deserialize(&section.data)?,
+			None => {
+				let body: Vec<ScalarDeserializer<T>> = frame.read_body(T::byte_size())?;
+				let body: Vec<_> = body.into_iter().map(|v| v.data()).collect();
+				let ret = BinaryDeserializer::from_body(&frame.header, &body)?;
+				return Ok((ret, frame.finish()?));
+			}
+		};
+
+		let codes: Vec<f64> = match section.quantization.target {
+			QuantizedType::U8 => frame.read_body::<u8>(1)?.into_iter().map(|v| v as f64).collect(),
+			QuantizedType::I16 => frame.read_body::<i16>(2)?.into_iter().map(|v| v as f64).collect(),
+		};
+		let group_size = match section.quantization.scope {
+			QuantizationScope::Container => codes.len(),
+			QuantizationScope::Row => frame.header.cols as usize,
+		};
+		let group_count = codes.len().checked_div(group_size).unwrap_or(0);
+		if section.offsets.len() != group_count || section.scales.len() != group_count {
+			return Err(df_error("Invalid quantization parameters!"));
+		}
+
+		let mut body = Vec::with_capacity(codes.len());
+		if group_size > 0 {
+			for (i, group) in codes.chunks(group_size).enumerate() {
+				let (offset, scale) = (section.offsets[i], section.scales[i]);
+				for code in group {
+					let v = section.quantization.decode(*code, offset, scale);
+					body.push(T::from(v).ok_or(df_error("Dequantized value out of range!"))?);
+				}
+			}
+		}
+
+		let ret = BinaryDeserializer::from_body(&frame.header, &body)?;
+		Ok((ret, frame.finish()?))
+	}
+
+	/// Deserializes storage and its attributes while enforcing given limits.
+	pub fn read_with_limits<RD: std::io::Read>(reader: RD, limits: &ReadLimits) -> IOResult<(S, Attributes)> {
+		let (ret, sections) = Self::read_sections(reader, limits)?;
+		Ok((ret, read_attributes(&sections)?))
+	}
+}
+
+impl<T, S> GeneralDeserializer<S> for QuantizedDeserializer<T, S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	fn read<RD: std::io::Read>(reader: RD) -> IOResult<S> {
+		Self::read_sections(reader, &ReadLimits::default()).map(|(ret, _)| ret)
+	}
+}
+
+impl<T, S> AttributeDeserializer<S> for QuantizedDeserializer<T, S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	fn read_with_attributes<RD: std::io::Read>(reader: RD) -> IOResult<(S, Attributes)> {
+		Self::read_with_limits(reader, &ReadLimits::default())
+	}
+}
+
+pub fn write_quantized<T, S, W>(writer: &mut W, data: &S, quantization: &Quantization) -> IOResult<()>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>, W: std::io::Write
+{
+	QuantizedSerializer::<T, S, LinearU8>::write_with(writer, data, quantization)
+}
+
+pub fn read_quantized<T, S, RD>(reader: RD) -> IOResult<S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T> + StorageConstructor<T>, RD: std::io::Read,
+{
+	QuantizedDeserializer::read(reader)
+}
+
+pub fn write_quantized_file<T, S>(path: &Path, data: &S, quantization: &Quantization) -> IOResult<()>
+	where T: Scalar + Float + SerializableScalar, S: Storage<T>,
+{
+	let mut f = std::fs::File::create(path)?;
+	write_quantized(&mut f, data, quantization)
+}
+
+pub fn read_quantized_file<T, S>(path: &Path) -> IOResult<S>
+	where T: Scalar + Float + DeserializableScalar, S: Storage<T> + StorageConstructor<T>,
+{
+	crate::file::read::<QuantizedDeserializer<_, _>, _>(path)
+}
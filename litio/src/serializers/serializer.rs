@@ -1,5 +1,5 @@
 use litcontainers::*;
-use crate::{IOResult, SerializableScalar, DeserializableScalar};
+use crate::{IOResult, SerializableScalar, DeserializableScalar, Attributes};
 
 pub trait StorageSerializerLossy<T, S>: GeneralSerializer<S>
 	where T: NumericElement + SerializableScalar, S: Storage<T>,
@@ -10,6 +10,12 @@ pub trait GeneralSerializer<T>
 	fn write<W: std::io::Write>(writer: &mut W, storage: &T) -> IOResult<()>;
 }
 
+/// Serializer which can store metadata attributes alongside the data.
+pub trait AttributeSerializer<T>: GeneralSerializer<T>
+{
+	fn write_with_attributes<W: std::io::Write>(writer: &mut W, storage: &T, attributes: &Attributes) -> IOResult<()>;
+}
+
 pub trait StorageDeserializerLossy<T, S>: GeneralDeserializer<S>
 	where T: NumericElement + DeserializableScalar, S: Storage<T>,
 {}
@@ -17,4 +23,10 @@ pub trait StorageDeserializerLossy<T, S>: GeneralDeserializer<S>
 pub trait GeneralDeserializer<T>
 {
 	fn read<'de, RD: std::io::Read>(reader: RD) -> IOResult<T>;
+}
+
+/// Deserializer which returns the stored metadata attributes alongside the data.
+pub trait AttributeDeserializer<T>: GeneralDeserializer<T>
+{
+	fn read_with_attributes<RD: std::io::Read>(reader: RD) -> IOResult<(T, Attributes)>;
 }
\ No newline at end of file
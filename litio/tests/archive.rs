@@ -0,0 +1,99 @@
+use litcontainers::*;
+use litio::*;
+use std::path::PathBuf;
+
+fn tmp_path(name: &str) -> PathBuf {
+	let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tmp");
+	if !path.exists() {
+		std::fs::create_dir_all(&path).unwrap();
+	}
+	path.push(name);
+	path
+}
+
+#[test]
+fn test_archive_read_write() {
+	let path = tmp_path("archive_rw.lita");
+	let mel = ContainerRM::regspace(Size::new(U3, U4), RowAxis, 0.0f32);
+	let ids = ContainerCM::regspace(Size::new(D!(2), U5), ColAxis, 1i32);
+
+	let mut archive = Archive::create(path.as_path()).unwrap();
+	archive.insert("mel", &mel).unwrap();
+	archive.insert_with_attributes("ids", &ids, &Attributes::new().with("label", "speaker")).unwrap();
+	assert!(archive.insert("mel", &mel).is_err());
+	archive.close().unwrap();
+
+	let mut archive = Archive::open(path.as_path()).unwrap();
+	assert_eq!(archive.len(), 2);
+	let entry = archive.entry("ids").unwrap();
+	assert_eq!(entry.element_type(), Some(i32::element_type()));
+	assert_eq!((entry.rows(), entry.cols()), (2, 5));
+
+	let mel2: ContainerRM<f32, U3, U4> = archive.get::<f32, ContainerRM<_, _, _>>("mel").unwrap();
+	assert_eq!(mel.as_slice(), mel2.as_slice());
+	let (ids2, attrs): (ContainerCM<i32, Dynamic, U5>, _) = archive.get_with_attributes("ids").unwrap();
+	assert_eq!(ids.as_slice(), ids2.as_slice());
+	assert_eq!(attrs.get("label").and_then(|v| v.as_str()), Some("speaker"));
+
+	assert!(archive.get::<f64, ContainerRM<f64, U3, U4>>("mel").is_err());
+	assert!(archive.get::<f32, ContainerRM<f32, U3, U4>>("missing").is_err());
+}
+
+#[test]
+fn test_archive_append() {
+	let path = tmp_path("archive_append.lita");
+	let _ = std::fs::remove_file(&path);
+	let a = ContainerRM::regspace(Size::new(U2, U2), RowAxis, 0.);
+	let b = ContainerRM::regspace(Size::new(U1, U3), RowAxis, 5.);
+
+	{
+		let mut archive = Archive::open_append(path.as_path()).unwrap();
+		archive.insert("a", &a).unwrap();
+	}
+	{
+		let mut archive = Archive::open_append(path.as_path()).unwrap();
+		assert!(archive.contains("a"));
+		archive.insert("b", &b).unwrap();
+	}
+
+	let mut archive = Archive::open(path.as_path()).unwrap();
+	let names: Vec<_> = archive.entries().iter().map(|e| e.name().to_string()).collect();
+	assert_eq!(names, vec!["a", "b"]);
+	let a2: ContainerRM<f64, U2, U2> = archive.get("a").unwrap();
+	let b2: ContainerRM<f64, U1, U3> = archive.get("b").unwrap();
+	assert_eq!(a.as_slice(), a2.as_slice());
+	assert_eq!(b.as_slice(), b2.as_slice());
+}
+
+
+#[test]
+fn test_archive_unflushed_insert() {
+	let path = tmp_path("archive_unflushed.lita");
+	let a = ContainerRM::regspace(Size::new(U2, U2), RowAxis, 0.);
+	{
+		let mut archive = Archive::create(path.as_path()).unwrap();
+		archive.insert("a", &a).unwrap();
+	}
+
+	// Simulates a crash between insert and flush
+	let mut archive = Archive::open_append(path.as_path()).unwrap();
+	archive.insert("b", &a).unwrap();
+	std::mem::forget(archive);
+
+	let mut archive = Archive::open_append(path.as_path()).unwrap();
+	assert_eq!(archive.len(), 1);
+	let a2: ContainerRM<f64, U2, U2> = archive.get("a").unwrap();
+	assert_eq!(a.as_slice(), a2.as_slice());
+
+	archive.insert("b", &a).unwrap();
+	archive.close().unwrap();
+	let mut archive = Archive::open(path.as_path()).unwrap();
+	assert_eq!(archive.len(), 2);
+	let b2: ContainerRM<f64, U2, U2> = archive.get("b").unwrap();
+	assert_eq!(a.as_slice(), b2.as_slice());
+
+	// A freshly created archive is readable before its first flush
+	let empty_path = tmp_path("archive_unflushed_empty.lita");
+	std::mem::forget(Archive::create(empty_path.as_path()).unwrap());
+	assert!(Archive::open(empty_path.as_path()).unwrap().is_empty());
+}
\ No newline at end of file
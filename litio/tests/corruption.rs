@@ -0,0 +1,171 @@
+use litcontainers::*;
+use litio::*;
+use std::path::PathBuf;
+
+type Data = ContainerRM<f64, U3, Dynamic>;
+
+fn sample() -> Data {
+	ContainerRM::regspace(Size::new(U3, D!(4)), RowAxis, 0.)
+}
+
+/// Serialized variants of the same container the corpus is derived from.
+fn corpus() -> Vec<(&'static str, Vec<u8>, bool)> {
+	let s = sample();
+	let attributes = Attributes::new().with("name", "sample");
+	let mut ret = Vec::new();
+
+	let mut plain = Vec::new();
+	write_binary(&mut plain, &s).unwrap();
+	ret.push(("plain", plain, false));
+
+	let mut checked = Vec::new();
+	write_binary_with_options(&mut checked, &s, &WriteOptions::new().with_attributes(attributes.clone()).with_checksum(true)).unwrap();
+	ret.push(("checked", checked, true));
+
+	let mut quantized = Vec::new();
+	let options = WriteOptions::new().with_attributes(attributes).with_checksum(true);
+	QuantizedSerializer::<_, _>::write_with_options(&mut quantized, &s, &Quantization::linear(QuantizedType::I16), &options).unwrap();
+	ret.push(("quantized", quantized, true));
+	ret
+}
+
+fn read(data: &[u8]) -> IOResult<Data> {
+	QuantizedDeserializer::read(data)
+}
+
+fn header_bytes(element_type: u8, element_size: u64, rows: u64, cols: u64) -> Vec<u8> {
+	let mut ret = vec![element_type];
+	for v in &[element_size, rows, cols, cols, 1] {
+		ret.extend_from_slice(&v.to_le_bytes());
+	}
+	ret
+}
+
+#[test]
+fn test_corpus_valid() {
+	for (name, data, _) in corpus() {
+		let s = read(&data).unwrap_or_else(|e| panic!("{}: {}", name, e));
+		assert_eq!(s.size(), sample().size(), "{}", name);
+	}
+}
+
+#[test]
+fn test_corpus_truncated() {
+	for (name, data, _) in corpus() {
+		for len in 0..data.len() {
+			match read(&data[..len]) {
+				Err(e) => assert_eq!(e.kind(), &ErrorKind::Truncated, "{} truncated to {}", name, len),
+				Ok(_) => panic!("{} truncated to {} was accepted", name, len),
+			}
+		}
+	}
+}
+
+#[test]
+fn test_corpus_corrupted() {
+	for (name, data, checked) in corpus() {
+		for i in 0..data.len() {
+			for mask in &[0x01u8, 0x80, 0xFF] {
+				let mut corrupted = data.clone();
+				corrupted[i] ^= mask;
+				// Unchecked data may still decode to garbage, but must never panic
+				let ret = read(&corrupted);
+				if checked {
+					assert!(ret.is_err(), "{} corrupted at {} with {:x} was accepted", name, i, mask);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn test_checksum_mismatch() {
+	let (_, mut data, _) = corpus().swap_remove(1);
+	let last = data.len() - 1;
+	data[last] ^= 1;
+	match read(&data).unwrap_err().kind() {
+		ErrorKind::Checksum { .. } => {},
+		e => panic!("Unexpected error {:?}", e),
+	}
+}
+
+#[test]
+fn test_trailing_data() {
+	let (_, mut data, _) = corpus().swap_remove(0);
+	data.push(0);
+	assert_eq!(read(&data).unwrap_err().kind(), &ErrorKind::TrailingData);
+
+	assert_eq!(read_binary::<f64, Data, _>(&data[..]).unwrap_err().kind(), &ErrorKind::TrailingData);
+
+	let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tmp");
+	std::fs::create_dir_all(&dir).unwrap();
+	let path = dir.join("trailing_data.lit");
+	std::fs::write(&path, &data).unwrap();
+	assert_eq!(read_binary_file::<f64, Data>(&path).unwrap_err().kind(), &ErrorKind::TrailingData);
+
+	let limits = ReadLimits::default().with_trailing_data(true);
+	let (s, _): (Data, _) = read_binary_with_limits(&data[..], &limits).unwrap();
+	assert_eq!(s.as_slice(), sample().as_slice());
+}
+
+#[test]
+fn test_concatenated_stream() {
+	let mut data = Vec::new();
+	for (_, bytes, _) in corpus().into_iter().take(2) {
+		data.extend_from_slice(&bytes);
+	}
+
+	let mut reader = &data[..];
+	let limits = ReadLimits::default().with_trailing_data(true);
+	let (a, _): (Data, _) = read_binary_with_limits(&mut reader, &limits).unwrap();
+	let (b, _): (Data, _) = read_binary_with_limits(&mut reader, &limits).unwrap();
+	assert_eq!(a.as_slice(), sample().as_slice());
+	assert_eq!(b.as_slice(), sample().as_slice());
+	assert!(reader.is_empty());
+}
+
+#[test]
+fn test_body_length_prefix() {
+	let (_, data, _) = corpus().swap_remove(0);
+	let prefix = header_bytes(element_to_byte(f64::element_type()), 8, 3, 4).len();
+	assert_eq!(data[prefix..prefix + 8], 12u64.to_le_bytes());
+
+	for found in &[5u64, 1 << 40] {
+		let mut corrupted = data.clone();
+		corrupted[prefix..prefix + 8].copy_from_slice(&found.to_le_bytes());
+		assert_eq!(read(&corrupted).unwrap_err().kind(), &ErrorKind::BodySize { expected: 12, found: *found });
+	}
+}
+
+#[test]
+fn test_hostile_header() {
+	let f64_type = element_to_byte(f64::element_type());
+
+	let huge = header_bytes(f64_type, 8, 3, u64::MAX / 2);
+	match read(&huge).unwrap_err().kind() {
+		ErrorKind::ElementLimit { .. } => {},
+		e => panic!("Unexpected error {:?}", e),
+	}
+
+	// Within the element limit, but claims more data than allowed
+	let large = header_bytes(f64_type, 8, 3, 1 << 20);
+	let limits = ReadLimits::default().with_max_bytes(1 << 20);
+	assert_eq!(read_binary_with_limits::<f64, Data, _>(&large[..], &limits).unwrap_err().kind(), &ErrorKind::ByteLimit { limit: 1 << 20 });
+	let limits = ReadLimits::default().with_max_elements(1000);
+	match read_binary_with_limits::<f64, Data, _>(&large[..], &limits).unwrap_err().kind() {
+		ErrorKind::ElementLimit { limit: 1000, found } => assert_eq!(*found, 3 << 20),
+		e => panic!("Unexpected error {:?}", e),
+	}
+
+	let wrong_size = header_bytes(f64_type, 4, 3, 4);
+	assert_eq!(read(&wrong_size).unwrap_err().kind(), &ErrorKind::ElementSize { expected: 8, found: 4 });
+
+	let wrong_rows = header_bytes(f64_type, 8, 2, 4);
+	assert_eq!(read(&wrong_rows).unwrap_err().kind(), &ErrorKind::Dimension { rows: 2, cols: 4 });
+
+	let wrong_type = header_bytes(element_to_byte(f32::element_type()), 4, 3, 4);
+	match read(&wrong_type).unwrap_err().kind() {
+		ErrorKind::ElementType { .. } => {},
+		e => panic!("Unexpected error {:?}", e),
+	}
+}
@@ -29,4 +29,70 @@ fn test_to_file() {
 
 	assert_eq!(s1.size(), s2.size());
 	assert_eq!(s1.as_slice(), s2.as_slice());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_attributes_read_write() {
+	let s1 = ContainerRM::regspace(Size::new(U2, U3), RowAxis, 0.);
+	let attributes = Attributes::new()
+		.with("sample_rate", 16000)
+		.with("hop_size", 0.01)
+		.with("unit", "dB")
+		.with("freqs", vec![0., 100., 200.]);
+
+	let mut tmp: Vec<u8> = Vec::new();
+	write_binary_with_attributes(&mut tmp, &s1, &attributes).unwrap();
+	let (s2, attributes2): (ContainerRM<f64, U2, U3>, _) = read_binary_with_attributes(&tmp[..]).unwrap();
+
+	assert_eq!(s1.as_slice(), s2.as_slice());
+	assert_eq!(attributes, attributes2);
+	assert_eq!(attributes2.get("sample_rate").and_then(|v| v.as_int()), Some(16000));
+	assert_eq!(attributes2.get("unit").and_then(|v| v.as_str()), Some("dB"));
+
+	// Readers that don't care about attributes skip them
+	let s3: ContainerRM<f64, U2, U3> = read_binary(&tmp[..]).unwrap();
+	assert_eq!(s1.as_slice(), s3.as_slice());
+}
+
+#[test]
+fn test_attributes_missing() {
+	let s1 = ContainerRM::regspace(Size::new(U2, U2), RowAxis, 0.);
+
+	let mut tmp: Vec<u8> = Vec::new();
+	write_binary(&mut tmp, &s1).unwrap();
+	let (s2, attributes): (ContainerRM<f64, U2, U2>, _) = read_binary_with_attributes(&tmp[..]).unwrap();
+
+	assert_eq!(s1.as_slice(), s2.as_slice());
+	assert!(attributes.is_empty());
+}
+
+
+#[test]
+fn test_csv_read_write() {
+	let s1 = ContainerRM::regspace(Size::new(U2, D!(3)), RowAxis, 0.5f32);
+
+	let mut tmp: Vec<u8> = Vec::new();
+	write_csv(&mut tmp, &s1).unwrap();
+	assert_eq!(String::from_utf8(tmp.clone()).unwrap(), "0.5,1.5,2.5\n0.5,1.5,2.5\n");
+	let s2: ContainerRM<f32, U2, Dynamic> = read_csv(&tmp[..]).unwrap();
+	assert_eq!(s1.as_slice(), s2.as_slice());
+
+	assert!(read_csv::<f32, ContainerRM<f32, Dynamic, Dynamic>, _>(&b"1,2\n3"[..]).is_err());
+	assert!(read_csv::<f32, ContainerRM<f32, Dynamic, Dynamic>, _>(&b"1,x\n"[..]).is_err());
+}
+
+#[test]
+fn test_binary_info() {
+	let s1 = ContainerCM::regspace(Size::new(U2, U3), RowAxis, 0i32);
+	let attributes = Attributes::new().with("unit", "dB");
+
+	let mut tmp: Vec<u8> = Vec::new();
+	write_binary_with_attributes(&mut tmp, &s1, &attributes).unwrap();
+	let info = BinaryInfo::read(&tmp[..]).unwrap();
+
+	assert_eq!(info.element_type, Some(i32::element_type()));
+	assert_eq!((info.rows, info.cols), (2, 3));
+	assert!(!info.is_row_major());
+	assert!(!info.quantized && !info.checksum);
+	assert_eq!(info.attributes, attributes);
+}
@@ -1 +1,4 @@
-mod io;
\ No newline at end of file
+mod io;
+mod archive;
+mod quantized;
+mod corruption;
\ No newline at end of file
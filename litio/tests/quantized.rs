@@ -0,0 +1,55 @@
+use litcontainers::*;
+use litio::*;
+
+fn max_abs_diff<S1: Storage<f32>, S2: Storage<f32>>(a: &S1, b: &S2) -> f64 {
+	a.as_iter().zip(b.as_iter()).map(|(x, y)| (*x as f64 - *y as f64).abs()).fold(0., f64::max)
+}
+
+#[test]
+fn test_quantized_linear() {
+	let s1 = ContainerRM::linspace(Size::new(U3, D!(50)), RowAxis, -2.0f32, 6.0f32);
+	for q in &[Quantization::linear(QuantizedType::U8), Quantization::linear(QuantizedType::I16)] {
+		let mut tmp: Vec<u8> = Vec::new();
+		write_quantized(&mut tmp, &s1, q).unwrap();
+		let s2: ContainerRM<f32, U3, Dynamic> = read_quantized(&tmp[..]).unwrap();
+
+		assert_eq!(s1.size(), s2.size());
+		assert!(max_abs_diff(&s1, &s2) <= q.max_error(-2., 6.) + 1e-5);
+	}
+}
+
+#[test]
+fn test_quantized_mu_law_per_row() {
+	let mut s1 = ContainerRM::zeros(Size::new(U2, D!(64)));
+	for (i, v) in s1.as_row_slice_mut(0).iter_mut().enumerate() { *v = (i as f32 * 0.3).sin(); }
+	for (i, v) in s1.as_row_slice_mut(1).iter_mut().enumerate() { *v = 100. + i as f32; }
+
+	let mut tmp: Vec<u8> = Vec::new();
+	QuantizedSerializer::<_, _, PerRow<MuLawU8>>::write(&mut tmp, &s1).unwrap();
+	let s2: ContainerRM<f32, U2, Dynamic> = QuantizedDeserializer::read(&tmp[..]).unwrap();
+
+	let q = Quantization::mu_law(QuantizedType::U8, DEFAULT_MU);
+	let row_err = |r: usize| (0..s1.cols())
+		.map(|c| (s1.get(r, c) as f64 - s2.get(r, c) as f64).abs()).fold(0., f64::max);
+	assert!(row_err(0) <= q.max_error(-1., 1.));
+	assert!(row_err(1) <= q.max_error(100., 163.));
+}
+
+#[test]
+fn test_quantized_size_and_attributes() {
+	let s1 = ContainerRM::regspace(Size::new(U4, D!(100)), RowAxis, 0.0f64);
+	let attrs = Attributes::new().with("rate", 16000);
+
+	let mut plain: Vec<u8> = Vec::new();
+	BinarySerializer::write(&mut plain, &s1).unwrap();
+	let mut quantized: Vec<u8> = Vec::new();
+	QuantizedSerializer::<_, _, LinearI16>::write_with_attributes(&mut quantized, &s1, &attrs).unwrap();
+	assert!(quantized.len() * 3 < plain.len());
+
+	let (_, attrs2): (ContainerRM<f64, U4, Dynamic>, _) = QuantizedDeserializer::read_with_attributes(&quantized[..]).unwrap();
+	assert_eq!(attrs, attrs2);
+	assert!(BinaryDeserializer::<f64, ContainerRM<f64, U4, Dynamic>>::read(&quantized[..]).is_err());
+
+	let s2: ContainerRM<f64, U4, Dynamic> = QuantizedDeserializer::read(&plain[..]).unwrap();
+	assert_eq!(s1.as_slice(), s2.as_slice());
+}